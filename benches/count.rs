@@ -0,0 +1,33 @@
+use std::hint::black_box;
+use std::io::Cursor;
+
+use criterion::{
+    criterion_group, criterion_main, Criterion, Throughput,
+};
+use marc21::MarcReader;
+
+const DATA: &[u8] = include_bytes!("../tests/data/arithmetic.mrc");
+
+fn bench_count(c: &mut Criterion) {
+    let data = DATA.repeat(1000);
+
+    let mut group = c.benchmark_group("count");
+    group.throughput(Throughput::Bytes(data.len() as u64));
+    group.bench_function("count_records", |b| {
+        b.iter(|| {
+            marc21::count_records(Cursor::new(black_box(&data)))
+                .unwrap()
+        })
+    });
+    group.bench_function("reader", |b| {
+        b.iter(|| {
+            MarcReader::new(Cursor::new(black_box(&data)))
+                .map(Result::unwrap)
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_count);
+criterion_main!(benches);
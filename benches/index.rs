@@ -0,0 +1,35 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use marc21::Record;
+
+const DATA: &[u8] = include_bytes!("../tests/data/arithmetic.mrc");
+const TAGS: [&[u8]; 5] = [b"020", b"245", b"650", b"700", b"999"];
+
+fn bench_index(c: &mut Criterion) {
+    let record = Record::from_bytes(DATA).unwrap();
+
+    let mut group = c.benchmark_group("index");
+    group.bench_function("linear", |b| {
+        b.iter(|| {
+            TAGS.iter()
+                .map(|tag| record.fields(black_box(tag)).count())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("indexed", |b| {
+        let index = record.index();
+        b.iter(|| {
+            TAGS.iter()
+                .map(|tag| index.fields(black_box(tag)).count())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("build", |b| {
+        b.iter(|| black_box(&record).index())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_index);
+criterion_main!(benches);
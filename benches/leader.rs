@@ -0,0 +1,24 @@
+use std::hint::black_box;
+
+use criterion::{
+    criterion_group, criterion_main, Criterion, Throughput,
+};
+use marc21::Leader;
+
+const LEADER: &[u8; 24] = b"00827nam a2200241 c 4500";
+
+fn bench_leader(c: &mut Criterion) {
+    let mut group = c.benchmark_group("leader");
+    group.throughput(Throughput::Bytes(LEADER.len() as u64));
+    group.bench_function("from_bytes", |b| {
+        b.iter(|| Leader::from_bytes(black_box(LEADER)).unwrap())
+    });
+    group.bench_function("to_bytes", |b| {
+        let leader = Leader::from_bytes(LEADER).unwrap();
+        b.iter(|| black_box(leader).to_bytes())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_leader);
+criterion_main!(benches);
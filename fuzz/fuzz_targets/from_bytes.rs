@@ -0,0 +1,30 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use marc21::{ParseOptions, Record, RecordRef};
+
+fuzz_target!(|data: &[u8]| {
+    // Parsing arbitrary bytes must never panic, only return `Err`.
+    if let Ok(record) = Record::from_bytes(data) {
+        let _ = record.to_bytes();
+    }
+
+    let options = ParseOptions {
+        lenient: true,
+        ..Default::default()
+    };
+    if let Ok((record, _)) = Record::from_bytes_with(data, &options) {
+        let _ = record.to_bytes();
+    }
+
+    if let Ok(record) = RecordRef::from_bytes(data) {
+        for field in record.fields() {
+            let _ = field.data();
+        }
+        let _ = record.to_record();
+    }
+
+    for result in marc21::records(data) {
+        let _ = result;
+    }
+});
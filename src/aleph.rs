@@ -0,0 +1,355 @@
+//! Import of the Aleph sequential format.
+//!
+//! Each line of an Aleph sequential export holds a single field: the
+//! 9-digit system number of the record, the tag and indicators, the
+//! `L` (language) marker and the field data, e.g.
+//! `000000001 24510 L $$aTitle`. Subfields are introduced by `$$`
+//! and blanks within fixed fields are written as `^`.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::record::is_control_tag;
+use crate::{
+    ControlField, DataField, Leader, ParseLeaderError, Record, Subfield,
+};
+
+/// An error that can occur when parsing the Aleph sequential format.
+///
+/// Errors carry the (1-based) number of the offending line.
+#[derive(Debug)]
+pub enum ParseAlephError {
+    Leader {
+        source: ParseLeaderError,
+        line: usize,
+    },
+    MissingLeader {
+        line: usize,
+    },
+    InvalidLine {
+        line: usize,
+    },
+    InvalidSubfield {
+        line: usize,
+    },
+}
+
+impl fmt::Display for ParseAlephError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Leader { source, line } => {
+                write!(f, "invalid leader on line {line}: {source}")
+            }
+            Self::MissingLeader { line } => write!(
+                f,
+                "missing leader of record ending on line {line}"
+            ),
+            Self::InvalidLine { line } => {
+                write!(f, "invalid line {line}")
+            }
+            Self::InvalidSubfield { line } => {
+                write!(f, "invalid subfield on line {line}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseAlephError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Leader { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A record whose lines are being collected.
+struct Builder<'a> {
+    system_number: &'a str,
+    leader: Option<Leader>,
+    control_fields: Vec<ControlField>,
+    data_fields: Vec<DataField>,
+}
+
+impl Builder<'_> {
+    fn build(self, line: usize) -> Result<Record, ParseAlephError> {
+        let mut record = Record {
+            leader: self
+                .leader
+                .ok_or(ParseAlephError::MissingLeader { line })?,
+            control_fields: self.control_fields,
+            data_fields: self.data_fields,
+            length_overflowed: false,
+            missing_terminator: false,
+            original: Default::default(),
+            directory: Default::default(),
+            field_order: Default::default(),
+        };
+
+        record.update_leader();
+        Ok(record)
+    }
+}
+
+/// Parses all records of an Aleph sequential export.
+///
+/// Consecutive lines with the same system number form a record. The
+/// `LDR` line becomes the leader, whose record length and base
+/// address are recomputed; `00X` lines become control fields and all
+/// other lines data fields. The Aleph-specific `FMT` line is skipped.
+/// The tag and the indicators may be separated by a blank.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::aleph::parse_aleph_sequential;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let records = parse_aleph_sequential(
+///         "000000001 LDR   L ^^^^^nam^^2200000^c^4500\n\
+///         000000001 001   L 123456789\n\
+///         000000001 24510 L $$aArithmetic /$$cCarl Sandburg.\n",
+///     )?;
+///
+///     assert_eq!(records.len(), 1);
+///     assert_eq!(
+///         records[0].control_number(),
+///         Some(&b"123456789"[..])
+///     );
+///     assert_eq!(
+///         records[0].first_subfield_value(b"245", b'a'),
+///         Some(&b"Arithmetic /"[..])
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+pub fn parse_aleph_sequential(
+    data: &str,
+) -> Result<Vec<Record>, ParseAlephError> {
+    let mut records = vec![];
+    let mut current: Option<Builder> = None;
+    let mut last_line = 0;
+
+    for (line, content) in data.lines().enumerate() {
+        let line = line + 1;
+        if content.trim().is_empty() {
+            continue;
+        }
+
+        let invalid = || ParseAlephError::InvalidLine { line };
+        let (system_number, rest) = content
+            .split_at_checked(9)
+            .filter(|(number, _)| {
+                number.bytes().all(|b| b.is_ascii_digit())
+            })
+            .ok_or_else(invalid)?;
+        let (spec, value) = rest
+            .strip_prefix(' ')
+            .and_then(|rest| rest.split_once(" L "))
+            .ok_or_else(invalid)?;
+
+        if current.as_ref().is_some_and(|builder| {
+            builder.system_number != system_number
+        }) {
+            records.push(current.take().unwrap().build(last_line)?);
+        }
+
+        let builder = current.get_or_insert_with(|| Builder {
+            system_number,
+            leader: None,
+            control_fields: vec![],
+            data_fields: vec![],
+        });
+
+        let (tag, indicators) =
+            spec.split_at_checked(3).ok_or_else(invalid)?;
+        match tag {
+            "LDR" => {
+                builder.leader = Some(parse_leader(value, line)?);
+            }
+            "FMT" => (),
+            _ => {
+                let tag: [u8; 3] =
+                    tag.as_bytes().try_into().map_err(|_| invalid())?;
+
+                if is_control_tag(&tag) {
+                    builder.control_fields.push(ControlField::new(
+                        tag,
+                        value.replace('^', " "),
+                    ));
+                } else {
+                    builder.data_fields.push(parse_data_field(
+                        tag, indicators, value, line,
+                    )?);
+                }
+            }
+        }
+
+        last_line = line;
+    }
+
+    if let Some(builder) = current {
+        records.push(builder.build(last_line)?);
+    }
+
+    Ok(records)
+}
+
+/// Parses the leader, whose record length and base address are
+/// commonly left blank.
+fn parse_leader(
+    value: &str,
+    line: usize,
+) -> Result<Leader, ParseAlephError> {
+    let mut data = value.replace('^', " ").into_bytes();
+
+    for range in [0..5, 12..17] {
+        if let Some(number) = data.get_mut(range) {
+            if !number.iter().all(u8::is_ascii_digit) {
+                number.fill(b'0');
+            }
+        }
+    }
+
+    Leader::from_bytes(&data)
+        .map_err(|source| ParseAlephError::Leader { source, line })
+}
+
+/// Parses the indicators and subfields of a data field.
+fn parse_data_field(
+    tag: [u8; 3],
+    indicators: &str,
+    value: &str,
+    line: usize,
+) -> Result<DataField, ParseAlephError> {
+    // The indicators may be separated from the tag by a blank.
+    let indicators = match indicators.len() {
+        3 => indicators.strip_prefix(' ').unwrap_or(indicators),
+        _ => indicators,
+    };
+
+    let indicator = |ch: Option<u8>| match ch {
+        None | Some(b'^') => Ok(b' '),
+        Some(ch) if ch.is_ascii() => Ok(ch),
+        _ => Err(ParseAlephError::InvalidLine { line }),
+    };
+
+    let mut bytes = indicators.bytes();
+    let ind1 = indicator(bytes.next())?;
+    let ind2 = indicator(bytes.next())?;
+    if bytes.next().is_some() {
+        return Err(ParseAlephError::InvalidLine { line });
+    }
+
+    let mut parts = value.split("$$");
+    if parts.next() != Some("") {
+        return Err(ParseAlephError::InvalidSubfield { line });
+    }
+
+    let subfields = parts
+        .map(|part| match part.as_bytes().first() {
+            Some(code) if code.is_ascii() => {
+                Ok(Subfield::new(*code, &part[1..]))
+            }
+            _ => Err(ParseAlephError::InvalidSubfield { line }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(DataField {
+        tag,
+        ind1,
+        ind2,
+        subfields,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: &str = "\
+        000000001 FMT   L BK\n\
+        000000001 LDR   L ^^^^^nam^^2200000^c^4500\n\
+        000000001 001   L 123456789\n\
+        000000001 008   L 751101s1975^^^^nyu^^^^^^^^^^^000^0^eng^^\n\
+        000000001 24510 L $$aArithmetic /$$cCarl Sandburg.\n\
+        000000001 650 0 L $$aArithmetic$$vJuvenile poetry.\n\
+        \n\
+        000000002 LDR   L 00000cam^a2200000^c^4500\n\
+        000000002 001   L 987654321\n\
+        000000002 245 00 L $$aPrice: $10\n\
+        000000002 500   L $$aNote\n";
+
+    #[test]
+    fn test_parse_aleph_sequential() -> anyhow::Result<()> {
+        let records = parse_aleph_sequential(DATA)?;
+        assert_eq!(records.len(), 2);
+
+        let record = &records[0];
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        assert_eq!(
+            record.control_fields_by_tag(b"008").next().unwrap().data(),
+            b"751101s1975    nyu           000 0 eng  "
+        );
+        assert_eq!(record.fields(b"FMT").count(), 0);
+
+        let field = record.fields(b"245").next().unwrap();
+        assert_eq!((field.ind1(), field.ind2()), (b'1', b'0'));
+        assert_eq!(
+            field.subfield(b'c').unwrap().value(),
+            b"Carl Sandburg."
+        );
+
+        let field = record.fields(b"650").next().unwrap();
+        assert_eq!((field.ind1(), field.ind2()), (b' ', b'0'));
+        assert_eq!(field.subfields().len(), 2);
+
+        let data = record.to_bytes();
+        assert_eq!(
+            record.leader().record_length() as usize,
+            data.len()
+        );
+        assert_eq!(&Record::from_bytes(&data)?, record);
+
+        let record = &records[1];
+        assert_eq!(record.control_number(), Some(&b"987654321"[..]));
+        assert_eq!(
+            record.first_subfield_value(b"245", b'a'),
+            Some(&b"Price: $10"[..])
+        );
+        let field = record.fields(b"500").next().unwrap();
+        assert_eq!((field.ind1(), field.ind2()), (b' ', b' '));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_aleph_sequential_invalid() {
+        assert!(parse_aleph_sequential("").unwrap().is_empty());
+        assert!(matches!(
+            parse_aleph_sequential("000000001 001   L 123\n"),
+            Err(ParseAlephError::MissingLeader { line: 1 })
+        ));
+        assert!(matches!(
+            parse_aleph_sequential(
+                "000000001 LDR   L ^^^^^nam^^2200000^c^4500\n\
+                00000001 001   L 123\n"
+            ),
+            Err(ParseAlephError::InvalidLine { line: 2 })
+        ));
+        assert!(matches!(
+            parse_aleph_sequential(
+                "000000001 LDR   L ^^^^^nam^^2200000^c^4500\n\
+                000000001 24510 L aTitle\n"
+            ),
+            Err(ParseAlephError::InvalidSubfield { line: 2 })
+        ));
+        assert!(matches!(
+            parse_aleph_sequential("000000001 LDR   L ^^^^^nam\n"),
+            Err(ParseAlephError::Leader { line: 1, .. })
+        ));
+    }
+}
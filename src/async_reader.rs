@@ -0,0 +1,204 @@
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use futures_core::Stream;
+use nom::Finish;
+use tokio::io::{AsyncRead, ReadBuf};
+
+use crate::error::ParseRecordError;
+use crate::leader::parse_record_len;
+use crate::reader::RECORD_LEN_LEN;
+use crate::{ParseLeaderError, Record};
+
+/// An asynchronous reader that reads a sequence of records from an
+/// underlying [AsyncRead] one at a time.
+///
+/// This is the async counterpart of [MarcReader](crate::MarcReader)
+/// and follows the same semantics: the stream ends if the input ends
+/// before the first byte of a record, and yields a
+/// [ParseRecordError::Truncated] error if it ends within a record.
+///
+/// # Example
+///
+/// ```rust
+/// use futures::StreamExt;
+/// use marc21::AsyncMarcReader;
+///
+/// # fn main() { example().unwrap(); }
+/// # #[tokio::main(flavor = "current_thread")]
+/// async fn example() -> anyhow::Result<()> {
+///     let data: &[u8] = b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d";
+///
+///     let mut reader = AsyncMarcReader::new(data);
+///     let record = reader.next().await.unwrap()?;
+///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+///     assert!(reader.next().await.is_none());
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AsyncMarcReader<R: AsyncRead + Unpin> {
+    inner: R,
+    buf: Vec<u8>,
+    filled: usize,
+    record_len: Option<usize>,
+    position: usize,
+}
+
+impl<R: AsyncRead + Unpin> AsyncMarcReader<R> {
+    /// Creates a new reader over the given [AsyncRead].
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            buf: vec![0; RECORD_LEN_LEN],
+            filled: 0,
+            record_len: None,
+            position: 0,
+        }
+    }
+
+    /// Discards the partially read record, such that the next poll
+    /// starts with the record length again.
+    fn reset(&mut self) {
+        self.buf.clear();
+        self.buf.resize(RECORD_LEN_LEN, 0);
+        self.filled = 0;
+        self.record_len = None;
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for AsyncMarcReader<R> {
+    type Item = Result<Record, ParseRecordError>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            while this.filled < this.buf.len() {
+                let mut buf =
+                    ReadBuf::new(&mut this.buf[this.filled..]);
+                match ready!(
+                    Pin::new(&mut this.inner).poll_read(cx, &mut buf)
+                ) {
+                    Ok(()) if buf.filled().is_empty() => {
+                        let empty = this.filled == 0;
+                        this.reset();
+
+                        return Poll::Ready(if empty {
+                            None
+                        } else {
+                            Some(Err(ParseRecordError::Truncated))
+                        });
+                    }
+                    Ok(()) => this.filled += buf.filled().len(),
+                    Err(e)
+                        if e.kind() == io::ErrorKind::Interrupted =>
+                    {
+                        continue
+                    }
+                    Err(e) => {
+                        this.reset();
+                        return Poll::Ready(Some(Err(e.into())));
+                    }
+                }
+            }
+
+            if this.record_len.is_none() {
+                let Ok((_, record_len)) =
+                    parse_record_len(&this.buf).finish()
+                else {
+                    this.reset();
+                    return Poll::Ready(Some(Err(
+                        ParseRecordError::Leader {
+                            source:
+                                ParseLeaderError::InvalidRecordLength,
+                            position: this.position,
+                        },
+                    )));
+                };
+
+                let record_len = record_len as usize;
+                this.record_len = Some(record_len);
+                if record_len > RECORD_LEN_LEN {
+                    this.buf.resize(record_len, 0);
+                    continue;
+                }
+            }
+
+            let data = std::mem::take(&mut this.buf);
+            let position = this.position;
+            this.position += data.len();
+            this.reset();
+
+            return Poll::Ready(Some(
+                Record::from_bytes(&data)
+                    .map_err(|e| e.offset(position)),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use tokio::io::AsyncWriteExt;
+
+    use super::*;
+
+    const DATA: &[u8] = include_bytes!("../tests/data/two_records.mrc");
+
+    #[tokio::test]
+    async fn test_async_marc_reader() -> anyhow::Result<()> {
+        // A small buffer forces the records to be read in chunks.
+        let (mut writer, reader) = tokio::io::duplex(16);
+        tokio::spawn(async move {
+            writer.write_all(DATA).await.unwrap();
+        });
+
+        let mut reader = AsyncMarcReader::new(reader);
+
+        let record = reader.next().await.unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+
+        let record = reader.next().await.unwrap()?;
+        assert_eq!(record.leader().record_length(), 53);
+        assert_eq!(record.fields(b"245").count(), 1);
+
+        assert!(reader.next().await.is_none());
+        assert!(reader.next().await.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_async_marc_reader_truncated() {
+        let mut reader = AsyncMarcReader::new(&DATA[..60]);
+        assert!(reader.next().await.unwrap().is_ok());
+        assert!(matches!(
+            reader.next().await.unwrap(),
+            Err(ParseRecordError::Truncated)
+        ));
+        assert!(reader.next().await.is_none());
+
+        let mut reader = AsyncMarcReader::new(&DATA[..3]);
+        assert!(matches!(
+            reader.next().await.unwrap(),
+            Err(ParseRecordError::Truncated)
+        ));
+
+        let mut reader = AsyncMarcReader::new(&b"0x048nam"[..]);
+        assert!(matches!(
+            reader.next().await.unwrap(),
+            Err(ParseRecordError::Leader {
+                source: ParseLeaderError::InvalidRecordLength,
+                position: 0
+            })
+        ));
+    }
+}
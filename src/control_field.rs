@@ -0,0 +1,134 @@
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+use nom::bytes::complete::{tag, take_till};
+use nom::combinator::{all_consuming, map};
+use nom::sequence::terminated;
+
+use crate::error::ParseRecordError;
+use crate::leader::ParseResult;
+use crate::FIELD_TERMINATOR;
+
+/// A control field (`001`-`009`).
+///
+/// Control fields have no indicators or subfields; they consist of
+/// the tag and the field data only.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct ControlField {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_impl::tag")
+    )]
+    pub(crate) tag: [u8; 3],
+    pub(crate) data: Vec<u8>,
+}
+
+impl ControlField {
+    /// Creates a new control field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::ControlField;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let field = ControlField::new(b"001", "123456789");
+    ///     assert_eq!(field.tag(), b"001");
+    ///     assert_eq!(field.data(), b"123456789");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(
+        tag: impl Borrow<[u8; 3]>,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        Self {
+            tag: *tag.borrow(),
+            data: data.into(),
+        }
+    }
+
+    /// Returns the tag of the control field.
+    pub fn tag(&self) -> &[u8; 3] {
+        &self.tag
+    }
+
+    /// Returns the data of the control field, without the field
+    /// terminator. The data of an empty field is an empty slice.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the number of bytes written by [Self::write_to].
+    pub(crate) fn encoded_len(&self) -> usize {
+        self.data.len() + 1
+    }
+
+    /// Writes the field data, followed by the field terminator.
+    pub(crate) fn write_to(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.data);
+        out.push(FIELD_TERMINATOR);
+    }
+}
+
+/// Parse a control field, which consists of the field data up to the
+/// field terminator.
+///
+/// The field terminator is consumed, but isn't part of the data. The
+/// whole input must be consumed.
+pub(crate) fn parse_control_field<'a>(
+    field_tag: [u8; 3],
+) -> impl FnMut(&'a [u8]) -> ParseResult<'a, ControlField, ParseRecordError>
+{
+    all_consuming(map(
+        terminated(
+            take_till(|b| b == FIELD_TERMINATOR),
+            tag(&[FIELD_TERMINATOR][..]),
+        ),
+        move |data: &[u8]| ControlField {
+            tag: field_tag,
+            data: data.to_vec(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom_test_helpers::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_control_field() {
+        assert_finished_and_eq!(
+            parse_control_field(*b"008")(
+                b"020805s2002    nyu    j      000 1 eng  \x1e"
+            ),
+            ControlField::new(
+                b"008",
+                "020805s2002    nyu    j      000 1 eng  "
+            )
+        );
+
+        assert_finished_and_eq!(
+            parse_control_field(*b"001")(b"123456789\x1e"),
+            ControlField::new(b"001", "123456789")
+        );
+
+        assert_finished_and_eq!(
+            parse_control_field(*b"001")(b"\x1e"),
+            ControlField::new(b"001", "")
+        );
+
+        assert_error!(parse_control_field(*b"001")(b"123456789"));
+        assert_error!(parse_control_field(*b"001")(
+            b"1234\x1e56789\x1e"
+        ));
+    }
+}
@@ -0,0 +1,794 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::fmt;
+
+use nom::bytes::complete::{tag, take};
+use nom::combinator::{all_consuming, map, verify};
+use nom::multi::many0;
+use nom::sequence::{terminated, tuple};
+
+use crate::error::ParseRecordError;
+use crate::leader::ParseResult;
+use crate::subfield::parse_subfield;
+use crate::validation::{
+    is_valid_data_field_tag, is_valid_indicator, is_valid_subfield_code,
+};
+use crate::{Subfield, FIELD_TERMINATOR};
+
+/// An error that occurs when setting an indicator which is neither a
+/// blank nor an ASCII alphanumeric character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidIndicator(pub u8);
+
+impl fmt::Display for InvalidIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid indicator {:#04x}", self.0)
+    }
+}
+
+impl core::error::Error for InvalidIndicator {}
+
+/// An error that occurs when constructing a data field from invalid
+/// bytes, see [DataField::new], or when editing the data field of a
+/// record, see [Record::append_subfield].
+///
+/// [Record::append_subfield]: crate::Record::append_subfield
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataFieldError {
+    InvalidTag([u8; 3]),
+    InvalidIndicator(u8),
+    InvalidSubfieldCode(u8),
+    MissingField,
+}
+
+impl fmt::Display for DataFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTag(tag) => write!(
+                f,
+                "invalid data field tag {}",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::InvalidIndicator(indicator) => {
+                write!(f, "invalid indicator {indicator:#04x}")
+            }
+            Self::InvalidSubfieldCode(code) => {
+                write!(f, "invalid subfield code {code:#04x}")
+            }
+            Self::MissingField => f.write_str("missing data field"),
+        }
+    }
+}
+
+impl core::error::Error for DataFieldError {}
+
+/// A data field (`010`-`999`).
+///
+/// A data field consists of a tag, two indicators and a list of
+/// subfields.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct DataField {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_impl::tag")
+    )]
+    pub(crate) tag: [u8; 3],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_impl::code")
+    )]
+    pub(crate) ind1: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_impl::code")
+    )]
+    pub(crate) ind2: u8,
+    pub(crate) subfields: Vec<Subfield>,
+}
+
+impl DataField {
+    /// Creates a new data field without subfields, which are added
+    /// with [DataField::with_subfield].
+    ///
+    /// Fails if the tag isn't a data field tag (`010`-`999`) or an
+    /// indicator is neither a blank nor an ASCII alphanumeric
+    /// character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{DataField, DataFieldError};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let field = DataField::new(b"245", b'1', b'0')?
+    ///         .with_subfield(b'a', "Arithmetic /")?
+    ///         .with_subfield(b'c', "Carl Sandburg.")?;
+    ///
+    ///     assert_eq!(field.tag(), b"245");
+    ///     assert_eq!(field.subfields().len(), 2);
+    ///
+    ///     assert_eq!(
+    ///         DataField::new(b"001", b' ', b' '),
+    ///         Err(DataFieldError::InvalidTag(*b"001"))
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(
+        tag: impl Borrow<[u8; 3]>,
+        ind1: u8,
+        ind2: u8,
+    ) -> Result<Self, DataFieldError> {
+        let tag = tag.borrow();
+        if !is_valid_data_field_tag(tag) {
+            return Err(DataFieldError::InvalidTag(*tag));
+        }
+
+        for indicator in [ind1, ind2] {
+            if !is_valid_indicator(indicator) {
+                return Err(DataFieldError::InvalidIndicator(
+                    indicator,
+                ));
+            }
+        }
+
+        Ok(Self {
+            tag: *tag,
+            ind1,
+            ind2,
+            subfields: Vec::new(),
+        })
+    }
+
+    /// Appends a subfield to the data field and returns the field.
+    ///
+    /// Fails if the code is neither a lowercase letter nor a digit.
+    /// See [DataField::new] for an example.
+    pub fn with_subfield(
+        mut self,
+        code: u8,
+        value: impl Into<Vec<u8>>,
+    ) -> Result<Self, DataFieldError> {
+        if !is_valid_subfield_code(code) {
+            return Err(DataFieldError::InvalidSubfieldCode(code));
+        }
+
+        self.subfields.push(Subfield::new(code, value));
+        Ok(self)
+    }
+
+    /// Returns the tag of the data field.
+    pub fn tag(&self) -> &[u8; 3] {
+        &self.tag
+    }
+
+    /// Returns the first indicator of the data field.
+    pub fn ind1(&self) -> u8 {
+        self.ind1
+    }
+
+    /// Returns the second indicator of the data field.
+    pub fn ind2(&self) -> u8 {
+        self.ind2
+    }
+
+    /// Returns both indicators of the data field.
+    pub fn indicators(&self) -> (u8, u8) {
+        (self.ind1, self.ind2)
+    }
+
+    /// Sets both indicators of the data field.
+    ///
+    /// Fails if an indicator is neither a blank nor an ASCII
+    /// alphanumeric character; the field is left unchanged then.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{InvalidIndicator, Record};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00052nam a2200037 c 4500245001400000\x1e\
+    ///         00\x1faThe Title\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let mut field = record.fields(b"245").next().unwrap().clone();
+    ///     field.set_indicators(b'1', b'4')?;
+    ///     assert_eq!(field.indicators(), (b'1', b'4'));
+    ///
+    ///     assert_eq!(
+    ///         field.set_indicators(b'1', b'#'),
+    ///         Err(InvalidIndicator(b'#'))
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_indicators(
+        &mut self,
+        ind1: u8,
+        ind2: u8,
+    ) -> Result<(), InvalidIndicator> {
+        for indicator in [ind1, ind2] {
+            if !is_valid_indicator(indicator) {
+                return Err(InvalidIndicator(indicator));
+            }
+        }
+
+        self.ind1 = ind1;
+        self.ind2 = ind2;
+        Ok(())
+    }
+
+    /// Returns the subfields of the data field, which are empty if the
+    /// field terminator immediately follows the indicators.
+    pub fn subfields(&self) -> &[Subfield] {
+        &self.subfields
+    }
+
+    /// Returns the number of bytes written by [Self::write_to].
+    pub(crate) fn encoded_len(&self) -> usize {
+        self.subfields
+            .iter()
+            .map(|subfield| subfield.value.len() + 2)
+            .sum::<usize>()
+            + 3
+    }
+
+    /// Writes the indicators and the subfields, followed by the field
+    /// terminator.
+    pub(crate) fn write_to(&self, out: &mut Vec<u8>) {
+        out.push(self.ind1);
+        out.push(self.ind2);
+        for subfield in self.subfields.iter() {
+            subfield.write_to(out);
+        }
+        out.push(FIELD_TERMINATOR);
+    }
+
+    /// Returns the first subfield with the given code, or `None` if
+    /// the field has no such subfield.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500245001000000\x1e\
+    ///         00\x1faTitle\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let field = record.fields(b"245").next().unwrap();
+    ///     assert_eq!(field.subfield(b'a').unwrap().value(), b"Title");
+    ///     assert!(field.subfield(b'b').is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn subfield(&self, code: u8) -> Option<&Subfield> {
+        self.subfields.iter().find(|subfield| subfield.code == code)
+    }
+
+    /// Returns an iterator over all subfields with the given code, in
+    /// field order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500245001000000\x1e\
+    ///         00\x1faTitle\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let field = record.fields(b"245").next().unwrap();
+    ///     assert_eq!(field.subfields_by_code(b'a').count(), 1);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn subfields_by_code(
+        &self,
+        code: u8,
+    ) -> impl Iterator<Item = &Subfield> {
+        self.subfields
+            .iter()
+            .filter(move |subfield| subfield.code == code)
+    }
+
+    /// Joins the values of all subfields with `sep`, e.g. to display
+    /// the whole title of a `245` field.
+    ///
+    /// Control subfields with a numeric code (such as `$6` linkage or
+    /// `$8` field link) are skipped; see
+    /// [DataField::to_display_string_with] to select the subfields
+    /// explicitly. Bytes which aren't valid UTF-8 are replaced by
+    /// `U+FFFD`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00068nam a2200037 c 4500245003000000\x1e\
+    ///         00\x1f6880-01\x1faTitle :\x1fbsubtitle\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let field = record.fields(b"245").next().unwrap();
+    ///     assert_eq!(field.to_display_string(" "), "Title : subtitle");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_display_string(&self, sep: &str) -> String {
+        self.join_subfields(sep, |code| !code.is_ascii_digit())
+    }
+
+    /// Joins the values of all subfields whose code is contained in
+    /// `codes` with `sep`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00068nam a2200037 c 4500245003000000\x1e\
+    ///         00\x1f6880-01\x1faTitle :\x1fbsubtitle\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let field = record.fields(b"245").next().unwrap();
+    ///     assert_eq!(field.to_display_string_with(" ", b"a"), "Title :");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_display_string_with(
+        &self,
+        sep: &str,
+        codes: &[u8],
+    ) -> String {
+        self.join_subfields(sep, |code| codes.contains(&code))
+    }
+
+    /// Returns a key for sorting (filing) by the value of the first
+    /// subfield with the given code, or an empty key if there is no
+    /// such subfield.
+    ///
+    /// The key is lowercased. For the `$a` subfield of a `245` field,
+    /// the number of non-filing characters given by the second
+    /// indicator (e.g. `4` for a leading `The `) is skipped; a blank
+    /// or non-digit indicator skips nothing. Bytes which aren't valid
+    /// UTF-8 are replaced by `U+FFFD`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00052nam a2200037 c 4500245001400000\x1e\
+    ///         14\x1faThe Title\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let field = record.fields(b"245").next().unwrap();
+    ///     assert_eq!(field.sort_key(b'a'), "title");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn sort_key(&self, code: u8) -> String {
+        let Some(subfield) = self.subfield(code) else {
+            return String::new();
+        };
+
+        let skip = match (&self.tag, code, self.ind2) {
+            (b"245", b'a', ind2 @ b'0'..=b'9') => {
+                (ind2 - b'0') as usize
+            }
+            _ => 0,
+        };
+
+        String::from_utf8_lossy(&subfield.value)
+            .chars()
+            .skip(skip)
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+
+    fn join_subfields<F>(&self, sep: &str, predicate: F) -> String
+    where
+        F: Fn(u8) -> bool,
+    {
+        self.subfields
+            .iter()
+            .filter(|subfield| predicate(subfield.code))
+            .map(|subfield| String::from_utf8_lossy(&subfield.value))
+            .collect::<Vec<_>>()
+            .join(sep)
+    }
+}
+
+impl fmt::Display for DataField {
+    /// Formats the data field for humans as the tag, the indicators
+    /// and the subfields, each introduced by `$` and its code. Blank
+    /// indicators are rendered as `\`, as in MARCMaker, and the values
+    /// are decoded lossily as UTF-8. Unlike the [mnemonic
+    /// format](crate::Record::to_mrk), the output isn't escaped and
+    /// can't be parsed back.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{DataField, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let field = DataField::new(b"245", b'1', b'0')?
+    ///         .with_subfield(b'a', "Title")?;
+    ///     assert_eq!(field.to_string(), "245 10 $aTitle");
+    ///
+    ///     let field = DataField::new(b"650", b' ', b'0')?
+    ///         .with_subfield(b'a', "Poetry")?;
+    ///     assert_eq!(field.to_string(), "650 \\0 $aPoetry");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let indicator = |ind: u8| match ind {
+            b' ' => '\\',
+            ind => ind as char,
+        };
+
+        write!(
+            f,
+            "{} {}{} ",
+            String::from_utf8_lossy(&self.tag),
+            indicator(self.ind1),
+            indicator(self.ind2)
+        )?;
+
+        for subfield in self.subfields.iter() {
+            write!(
+                f,
+                "${}{}",
+                subfield.code as char,
+                String::from_utf8_lossy(&subfield.value)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parse a data field, which consists of the indicators followed by
+/// the subfields and the field terminator.
+///
+/// The number of indicators and the length of the subfield codes
+/// (including the delimiter) are taken from the leader (positions 10
+/// and 11), which are `2` in MARC 21 records. Missing indicators
+/// default to a blank and more than two indicators are rejected, since
+/// a data field holds exactly two. See [parse_subfield] for longer
+/// subfield codes.
+///
+/// The whole input must be consumed.
+pub(crate) fn parse_data_field<'a>(
+    field_tag: [u8; 3],
+    indicator_count: u8,
+    subfield_code_count: u8,
+) -> impl FnMut(&'a [u8]) -> ParseResult<'a, DataField, ParseRecordError>
+{
+    let code_len = subfield_code_count.saturating_sub(1) as usize;
+
+    all_consuming(map(
+        terminated(
+            tuple((
+                verify(take(indicator_count), |ind: &[u8]| {
+                    ind.len() <= 2
+                }),
+                many0(parse_subfield(code_len)),
+            )),
+            tag(&[FIELD_TERMINATOR][..]),
+        ),
+        move |(indicators, subfields): (&[u8], _)| DataField {
+            tag: field_tag,
+            ind1: indicators.first().copied().unwrap_or(b' '),
+            ind2: indicators.get(1).copied().unwrap_or(b' '),
+            subfields,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom_test_helpers::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_data_field() {
+        assert_finished_and_eq!(
+            parse_data_field(*b"245", 2, 2)(
+                b"  \x1faArithmetic :\x1fbpoems /\x1fcCarl Sandburg.\x1e"
+            ),
+            DataField {
+                tag: *b"245",
+                ind1: b' ',
+                ind2: b' ',
+                subfields: vec![
+                    Subfield::new(b'a', "Arithmetic :"),
+                    Subfield::new(b'b', "poems /"),
+                    Subfield::new(b'c', "Carl Sandburg."),
+                ]
+            }
+        );
+
+        assert_finished_and_eq!(
+            parse_data_field(*b"020", 2, 2)(
+                b"  \x1fa123\x1fz456\x1fz789\x1e"
+            ),
+            DataField {
+                tag: *b"020",
+                ind1: b' ',
+                ind2: b' ',
+                subfields: vec![
+                    Subfield::new(b'a', "123"),
+                    Subfield::new(b'z', "456"),
+                    Subfield::new(b'z', "789"),
+                ]
+            }
+        );
+
+        assert_finished_and_eq!(
+            parse_data_field(*b"650", 2, 2)(b" 0\x1e"),
+            DataField {
+                tag: *b"650",
+                ind1: b' ',
+                ind2: b'0',
+                subfields: vec![]
+            }
+        );
+
+        assert_error!(parse_data_field(*b"245", 2, 2)(b"  \x1faTitle"));
+        assert_error!(parse_data_field(*b"245", 2, 2)(b"  aTitle\x1e"));
+        assert_error!(parse_data_field(*b"245", 2, 2)(b" \x1e"));
+    }
+
+    #[test]
+    fn test_parse_data_field_counts() {
+        assert_finished_and_eq!(
+            parse_data_field(*b"245", 1, 2)(b"1\x1faTitle\x1e"),
+            DataField {
+                tag: *b"245",
+                ind1: b'1',
+                ind2: b' ',
+                subfields: vec![Subfield::new(b'a', "Title")]
+            }
+        );
+
+        assert_finished_and_eq!(
+            parse_data_field(*b"245", 0, 3)(
+                b"\x1fa1Title\x1fc2Author\x1e"
+            ),
+            DataField {
+                tag: *b"245",
+                ind1: b' ',
+                ind2: b' ',
+                subfields: vec![
+                    Subfield::new(b'a', "1Title"),
+                    Subfield::new(b'c', "2Author"),
+                ]
+            }
+        );
+
+        assert_error!(parse_data_field(*b"245", 3, 2)(b"10\x1e"));
+        assert_error!(parse_data_field(*b"245", 3, 2)(b"102\x1e"));
+    }
+
+    #[test]
+    fn test_subfield() {
+        let field = DataField {
+            tag: *b"245",
+            ind1: b'1',
+            ind2: b'0',
+            subfields: vec![
+                Subfield::new(b'a', "Arithmetic /"),
+                Subfield::new(b'c', "Carl Sandburg."),
+            ],
+        };
+
+        assert_eq!(
+            field.subfield(b'a'),
+            Some(&Subfield::new(b'a', "Arithmetic /"))
+        );
+        assert_eq!(field.subfields_by_code(b'a').count(), 1);
+        assert_eq!(field.subfield(b'b'), None);
+        assert_eq!(field.subfields_by_code(b'b').count(), 0);
+    }
+
+    #[test]
+    fn test_subfields_by_code() {
+        let field = DataField {
+            tag: *b"020",
+            ind1: b' ',
+            ind2: b' ',
+            subfields: vec![
+                Subfield::new(b'z', "0152038655"),
+                Subfield::new(b'a', "0152038663"),
+                Subfield::new(b'z', "9780152038656"),
+            ],
+        };
+
+        assert_eq!(
+            field.subfields_by_code(b'z').collect::<Vec<_>>(),
+            vec![
+                &Subfield::new(b'z', "0152038655"),
+                &Subfield::new(b'z', "9780152038656"),
+            ]
+        );
+        assert_eq!(
+            field.subfield(b'z'),
+            Some(&Subfield::new(b'z', "0152038655"))
+        );
+    }
+
+    #[test]
+    fn test_to_display_string() {
+        let field = DataField {
+            tag: *b"245",
+            ind1: b'1',
+            ind2: b'0',
+            subfields: vec![
+                Subfield::new(b'6', "880-01"),
+                Subfield::new(b'a', "Arithmetic :"),
+                Subfield::new(b'b', "poems /"),
+                Subfield::new(b'c', "Carl Sandburg."),
+            ],
+        };
+
+        assert_eq!(
+            field.to_display_string(" "),
+            "Arithmetic : poems / Carl Sandburg."
+        );
+        assert_eq!(
+            field.to_display_string_with(" -- ", b"ac"),
+            "Arithmetic : -- Carl Sandburg."
+        );
+        assert_eq!(field.to_display_string_with(" ", b"z"), "");
+    }
+
+    #[test]
+    fn test_sort_key() {
+        let field = DataField {
+            tag: *b"245",
+            ind1: b'1',
+            ind2: b'4',
+            subfields: vec![
+                Subfield::new(b'a', "The Title"),
+                Subfield::new(b'b', "The Subtitle"),
+            ],
+        };
+
+        assert_eq!(field.sort_key(b'a'), "title");
+        assert_eq!(field.sort_key(b'b'), "the subtitle");
+        assert_eq!(field.sort_key(b'c'), "");
+
+        let field = DataField {
+            ind2: b' ',
+            ..field
+        };
+        assert_eq!(field.sort_key(b'a'), "the title");
+
+        let field = DataField {
+            tag: *b"246",
+            ind2: b'4',
+            ..field
+        };
+        assert_eq!(field.sort_key(b'a'), "the title");
+    }
+
+    #[test]
+    fn test_set_indicators() {
+        let mut field = DataField {
+            tag: *b"245",
+            ind1: b'0',
+            ind2: b'0',
+            subfields: vec![Subfield::new(b'a', "The Title")],
+        };
+
+        assert_eq!(field.set_indicators(b'1', b'4'), Ok(()));
+        assert_eq!(field.indicators(), (b'1', b'4'));
+        assert_eq!(field.set_indicators(b' ', b'a'), Ok(()));
+        assert_eq!(field.indicators(), (b' ', b'a'));
+
+        assert_eq!(
+            field.set_indicators(b'1', 0x1f),
+            Err(InvalidIndicator(0x1f))
+        );
+        assert_eq!(
+            field.set_indicators(b'\n', b'0'),
+            Err(InvalidIndicator(b'\n'))
+        );
+        assert_eq!(field.indicators(), (b' ', b'a'));
+    }
+
+    #[test]
+    fn test_new() -> anyhow::Result<()> {
+        let field = DataField::new(b"245", b'1', b'0')?
+            .with_subfield(b'a', "Arithmetic :")?
+            .with_subfield(b'b', "poems /")?
+            .with_subfield(b'c', "Carl Sandburg.")?;
+
+        let mut data = Vec::new();
+        field.write_to(&mut data);
+        assert_eq!(
+            data,
+            b"10\x1faArithmetic :\x1fbpoems /\x1fcCarl Sandburg.\x1e"
+        );
+        assert_finished_and_eq!(
+            parse_data_field(*b"245", 2, 2)(&data),
+            field
+        );
+
+        let field = DataField::new(b"700", b' ', b' ')?;
+        assert!(field.subfields().is_empty());
+
+        assert_eq!(
+            DataField::new(b"24a", b'1', b'0'),
+            Err(DataFieldError::InvalidTag(*b"24a"))
+        );
+        assert_eq!(
+            DataField::new(b"245", b'1', b'#'),
+            Err(DataFieldError::InvalidIndicator(b'#'))
+        );
+        assert_eq!(
+            DataField::new(b"245", b'1', b'0')?
+                .with_subfield(b'A', "x"),
+            Err(DataFieldError::InvalidSubfieldCode(b'A'))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> anyhow::Result<()> {
+        let field = DataField::new(b"245", b'1', b'0')?
+            .with_subfield(b'a', "Arithmetic :")?
+            .with_subfield(b'b', "poems /")?
+            .with_subfield(b'c', "Carl Sandburg.")?;
+        assert_eq!(
+            field.to_string(),
+            "245 10 $aArithmetic :$bpoems /$cCarl Sandburg."
+        );
+
+        let field = DataField::new(b"100", b'1', b' ')?
+            .with_subfield(b'a', &b"Tolsto\xff"[..])?;
+        assert_eq!(format!("{field}"), "100 1\\ $aTolsto\u{fffd}");
+
+        let field = DataField::new(b"700", b' ', b' ')?;
+        assert_eq!(field.to_string(), "700 \\\\ ");
+
+        Ok(())
+    }
+}
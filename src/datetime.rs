@@ -0,0 +1,138 @@
+use chrono::{NaiveDate, NaiveDateTime};
+
+use crate::Record;
+
+/// Parses the ASCII digits as a decimal number, or returns `None` if
+/// a byte isn't a digit.
+fn parse_digits(digits: &[u8]) -> Option<u32> {
+    digits.iter().try_fold(0u32, |value, b| {
+        b.is_ascii_digit().then(|| value * 10 + (b - b'0') as u32)
+    })
+}
+
+/// Parses a timestamp of the form `YYYYMMDDHHMMSS.F`, where the
+/// fraction of a second is optional and may have up to nine digits.
+fn parse_timestamp(value: &[u8]) -> Option<NaiveDateTime> {
+    let (datetime, fraction) = match value.split_at_checked(14)? {
+        (datetime, []) => (datetime, 0),
+        (datetime, [b'.', digits @ ..])
+            if (1..=9).contains(&digits.len()) =>
+        {
+            let scale = 10u32.pow(9 - digits.len() as u32);
+            (datetime, parse_digits(digits)? * scale)
+        }
+        _ => return None,
+    };
+
+    let field =
+        |start: usize, end: usize| parse_digits(&datetime[start..end]);
+
+    NaiveDate::from_ymd_opt(
+        field(0, 4)? as i32,
+        field(4, 6)?,
+        field(6, 8)?,
+    )?
+    .and_hms_nano_opt(
+        field(8, 10)?,
+        field(10, 12)?,
+        field(12, 14)?,
+        fraction,
+    )
+}
+
+impl Record {
+    /// Returns the date and time of the latest transaction, i.e. the
+    /// value of the `005` control field (`YYYYMMDDHHMMSS.F`).
+    ///
+    /// Returns `None` if the record has no `005` field or its value
+    /// isn't a valid timestamp. The fraction of a second is optional.
+    ///
+    /// This method requires the `chrono` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use marc21::RecordBuilder;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .control_field(b"005", "19940223151047.0")
+    ///         .build();
+    ///
+    ///     let expected = NaiveDate::from_ymd_opt(1994, 2, 23)
+    ///         .and_then(|date| date.and_hms_opt(15, 10, 47));
+    ///     assert_eq!(record.last_modified(), expected);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn last_modified(&self) -> Option<NaiveDateTime> {
+        self.control_fields_by_tag(b"005")
+            .next()
+            .and_then(|field| parse_timestamp(field.data()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RecordBuilder;
+
+    fn datetime(
+        (y, m, d): (i32, u32, u32),
+        (h, mi, s, ms): (u32, u32, u32, u32),
+    ) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .and_then(|date| date.and_hms_milli_opt(h, mi, s, ms))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_last_modified() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .control_field(b"005", "20230815093005.4")
+            .build();
+        assert_eq!(
+            record.last_modified(),
+            Some(datetime((2023, 8, 15), (9, 30, 5, 400)))
+        );
+
+        assert_eq!(
+            parse_timestamp(b"19940223151047"),
+            Some(datetime((1994, 2, 23), (15, 10, 47, 0)))
+        );
+        assert_eq!(
+            parse_timestamp(b"19940223151047.125"),
+            Some(datetime((1994, 2, 23), (15, 10, 47, 125)))
+        );
+
+        assert_eq!(RecordBuilder::new().build().last_modified(), None);
+    }
+
+    #[test]
+    fn test_last_modified_malformed() {
+        for value in [
+            &b""[..],
+            b"2023",
+            b"20231315093005.0",
+            b"20230230093005.0",
+            b"20230815253005.0",
+            b"2023-08-15T09:30",
+            b"20230815093005.",
+            b"20230815093005,0",
+            b"20230815093005.0x",
+            b"20230815093005.0123456789",
+            b"2023081509300\xff.0",
+        ] {
+            assert_eq!(parse_timestamp(value), None);
+        }
+
+        let record = RecordBuilder::new()
+            .control_field(b"005", "not a timestamp")
+            .build();
+        assert_eq!(record.last_modified(), None);
+    }
+}
@@ -0,0 +1,256 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::leader::is_volatile_position;
+use crate::{Field, Leader, Record};
+
+/// A difference between the fields of two records, see
+/// [Record::diff].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldChange<'a> {
+    /// A field which only exists in the other record.
+    Added(Field<'a>),
+    /// A field which only exists in the original record.
+    Removed(Field<'a>),
+    /// A field which exists in both records, but with different
+    /// contents.
+    Modified {
+        tag: [u8; 3],
+        old: Field<'a>,
+        new: Field<'a>,
+    },
+}
+
+/// A difference between two leaders at a single position, see
+/// [Leader::diff].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaderChange {
+    /// The (0-based) character position within the leader.
+    pub position: usize,
+    /// The value of the original leader.
+    pub old: u8,
+    /// The value of the other leader.
+    pub new: u8,
+}
+
+impl Record {
+    /// Compares the fields of the record with those of another
+    /// record, e.g. an edited copy, and returns the differences.
+    ///
+    /// Fields are matched by tag and position: the n-th field with a
+    /// tag is compared to the n-th field with the same tag of the
+    /// other record. Surplus fields are reported as added or removed.
+    /// The changes are ordered by tag. The leader isn't compared; see
+    /// [Leader::diff].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{FieldChange, RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let old = RecordBuilder::new()
+    ///         .control_field(b"001", "123456789")
+    ///         .build();
+    ///     let new = RecordBuilder::new()
+    ///         .control_field(b"001", "123456789")
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let changes = old.diff(&new);
+    ///     assert_eq!(changes.len(), 1);
+    ///     assert!(matches!(changes[0], FieldChange::Added(_)));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn diff<'a>(
+        &'a self,
+        other: &'a Record,
+    ) -> Vec<FieldChange<'a>> {
+        let mut fields: BTreeMap<[u8; 3], (Vec<Field>, Vec<Field>)> =
+            BTreeMap::new();
+
+        for field in self.all_fields() {
+            fields.entry(*field.tag()).or_default().0.push(field);
+        }
+
+        for field in other.all_fields() {
+            fields.entry(*field.tag()).or_default().1.push(field);
+        }
+
+        let mut changes = vec![];
+        for (tag, (old, new)) in fields {
+            let mut old = old.into_iter();
+            let mut new = new.into_iter();
+
+            loop {
+                match (old.next(), new.next()) {
+                    (Some(old), Some(new)) if old == new => (),
+                    (Some(old), Some(new)) => changes
+                        .push(FieldChange::Modified { tag, old, new }),
+                    (Some(old), None) => {
+                        changes.push(FieldChange::Removed(old))
+                    }
+                    (None, Some(new)) => {
+                        changes.push(FieldChange::Added(new))
+                    }
+                    (None, None) => break,
+                }
+            }
+        }
+
+        changes
+    }
+}
+
+impl Leader {
+    /// Compares the leader with another leader and returns the
+    /// positions which differ. The record length and the base address
+    /// of data are ignored (see [Leader::semantic_eq]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, LeaderChange};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let old = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     let new = Leader::from_bytes(b"00048cam a2200037 c 4500")?;
+    ///
+    ///     assert_eq!(
+    ///         old.diff(&new),
+    ///         [LeaderChange {
+    ///             position: 5,
+    ///             old: b'n',
+    ///             new: b'c'
+    ///         }]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn diff(&self, other: &Leader) -> Vec<LeaderChange> {
+        let old = self.to_bytes();
+        let new = other.to_bytes();
+
+        (0..old.len())
+            .filter(|position| !is_volatile_position(*position))
+            .filter(|position| old[*position] != new[*position])
+            .map(|position| LeaderChange {
+                position,
+                old: old[position],
+                new: new[position],
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RecordBuilder, RecordStatus, Subfield};
+
+    #[test]
+    fn test_diff() -> anyhow::Result<()> {
+        let old = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Title")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .data_field(
+                b"700",
+                b'1',
+                b' ',
+                [Subfield::new(b'a', "Rand")],
+            )
+            .build();
+
+        let mut new = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Title")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Mathematics")],
+            )
+            .data_field(
+                b"710",
+                b'2',
+                b' ',
+                [Subfield::new(b'a', "Harcourt")],
+            )
+            .build();
+        new.leader_mut()
+            .set_record_status(RecordStatus::Corrected)?;
+
+        let mut fields = old.data_fields().skip(2);
+        let old_650 = fields.next().unwrap();
+        let old_700 = fields.next().unwrap();
+        let mut fields = new.data_fields().skip(2);
+        let new_650 = fields.next().unwrap();
+        let new_710 = fields.next().unwrap();
+
+        assert_eq!(
+            old.diff(&new),
+            [
+                FieldChange::Modified {
+                    tag: *b"650",
+                    old: Field::Data(old_650),
+                    new: Field::Data(new_650),
+                },
+                FieldChange::Removed(Field::Data(old_700)),
+                FieldChange::Added(Field::Data(new_710)),
+            ]
+        );
+
+        assert_eq!(
+            old.leader().diff(new.leader()),
+            [LeaderChange {
+                position: 5,
+                old: b'n',
+                new: b'c'
+            }]
+        );
+
+        assert!(old.diff(&old).is_empty());
+        assert!(old.leader().diff(old.leader()).is_empty());
+
+        Ok(())
+    }
+}
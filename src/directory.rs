@@ -0,0 +1,343 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use nom::bytes::complete::take;
+use nom::character::complete::satisfy;
+use nom::combinator::{map, verify};
+use nom::multi::many0;
+use nom::sequence::tuple;
+use nom::Finish;
+
+use crate::error::ParseRecordError;
+use crate::leader::{
+    parse_number, write_number, ParseResult, LEADER_LEN,
+};
+use crate::{EntryMap, Leader, FIELD_TERMINATOR};
+
+/// An entry of the directory.
+///
+/// Each entry consists of the tag of the field, the length of the
+/// field and the starting character position of the field, relative
+/// to the base address of data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectoryEntry {
+    pub(crate) tag: [u8; 3],
+    pub(crate) length: u32,
+    pub(crate) start: u32,
+}
+
+impl DirectoryEntry {
+    /// Returns the tag of the field.
+    pub fn tag(&self) -> &[u8; 3] {
+        &self.tag
+    }
+
+    /// Returns the length of the field, including the indicators,
+    /// subfield codes, data and the field terminator.
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+
+    /// Returns the starting character position of the field, relative
+    /// to the base address of data.
+    pub fn start(&self) -> u32 {
+        self.start
+    }
+
+    /// Writes the directory entry, using the widths of the given entry
+    /// map. The implementation-defined portion is filled with zeros.
+    pub(crate) fn write_to(
+        &self,
+        out: &mut Vec<u8>,
+        entry_map: &EntryMap,
+    ) {
+        let length_width = entry_map.length_of_field_portion as usize;
+        let start_width =
+            entry_map.starting_character_position_portion as usize;
+        let impl_width =
+            entry_map.implementation_defined_portion as usize;
+
+        let offset = out.len();
+        out.extend_from_slice(&self.tag);
+        out.resize(
+            offset + 3 + length_width + start_width + impl_width,
+            b'0',
+        );
+
+        write_number(
+            &mut out[offset + 3..][..length_width],
+            self.length,
+        );
+        write_number(
+            &mut out[offset + 3 + length_width..][..start_width],
+            self.start,
+        );
+    }
+}
+
+/// Directory entries are ordered by their tag, then by their starting
+/// character position (and finally by their length).
+impl Ord for DirectoryEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.tag
+            .cmp(&other.tag)
+            .then(self.start.cmp(&other.start))
+            .then(self.length.cmp(&other.length))
+    }
+}
+
+impl PartialOrd for DirectoryEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The directory of a record.
+///
+/// The directory is a series of fixed-length entries, one per
+/// variable field, and is terminated by a field terminator.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Directory {
+    pub(crate) entries: Vec<DirectoryEntry>,
+}
+
+impl Directory {
+    /// Parses the directory of a record, following the given leader.
+    ///
+    /// The widths of the length-of-field and
+    /// starting-character-position portions of each entry are taken
+    /// from the leader's entry map (positions 20-22), e.g. `4500`
+    /// for standard MARC 21 records.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Directory, Leader};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00061nam a2200037 c 4500")?;
+    ///     let directory =
+    ///         Directory::from_bytes(b"001001100000\x1e", &leader)?;
+    ///     assert_eq!(directory.entries().len(), 1);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_bytes(
+        data: &[u8],
+        leader: &Leader,
+    ) -> Result<Self, ParseRecordError> {
+        parse_directory(data, leader)
+            .finish()
+            .map(|(_, directory)| directory)
+    }
+
+    /// Returns the entries of the directory.
+    pub fn entries(&self) -> &[DirectoryEntry] {
+        &self.entries
+    }
+}
+
+/// Parse the tag of a directory entry.
+#[inline]
+fn parse_tag(i: &[u8]) -> ParseResult<'_, [u8; 3], ParseRecordError> {
+    map(
+        verify(take(3usize), |tag: &[u8]| {
+            tag.iter().all(u8::is_ascii_alphanumeric)
+        }),
+        |tag: &[u8]| [tag[0], tag[1], tag[2]],
+    )(i)
+}
+
+/// Parse a single directory entry.
+pub(crate) fn parse_directory_entry<'a>(
+    entry_map: &EntryMap,
+) -> impl FnMut(&'a [u8]) -> ParseResult<'a, DirectoryEntry, ParseRecordError>
+{
+    let length_width = entry_map.length_of_field_portion as usize;
+    let start_width =
+        entry_map.starting_character_position_portion as usize;
+    let impl_width = entry_map.implementation_defined_portion as usize;
+
+    map(
+        tuple((
+            parse_tag,
+            parse_number(length_width),
+            parse_number(start_width),
+            take(impl_width),
+        )),
+        |(tag, length, start, _)| DirectoryEntry { tag, length, start },
+    )
+}
+
+/// Parse the directory, which immediately follows the leader.
+///
+/// The length of the leader and the directory (incl. the field
+/// terminator) must be equal to the base address of data.
+pub(crate) fn parse_directory<'a>(
+    i: &'a [u8],
+    leader: &Leader,
+) -> ParseResult<'a, Directory, ParseRecordError> {
+    // The position of the first entry that could not be parsed.
+    let invalid = |rest: &[u8]| {
+        nom::Err::Error(ParseRecordError::InvalidDirectory {
+            position: LEADER_LEN + i.len() - rest.len(),
+        })
+    };
+
+    let (rest, entries) =
+        many0(parse_directory_entry(&leader.entry_map))(i)
+            .map_err(|_| invalid(i))?;
+    let (rest, _) = satisfy(|ch| ch as u8 == FIELD_TERMINATOR)(rest)
+        .map_err(|_: nom::Err<ParseRecordError>| invalid(rest))?;
+
+    let actual = (LEADER_LEN + i.len() - rest.len()) as u32;
+    if actual != leader.base_address {
+        return Err(ParseRecordError::BaseAddressMismatch {
+            declared: leader.base_address,
+            actual,
+        }
+        .into());
+    }
+
+    Ok((rest, Directory { entries }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_directory() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00123nam a2200049 c 4500")?;
+        let (rest, directory) = parse_directory(
+            b"001001100000245002000011\x1eabc",
+            &leader,
+        )
+        .finish()?;
+
+        assert_eq!(rest, b"abc");
+        assert_eq!(
+            directory.entries(),
+            &[
+                DirectoryEntry {
+                    tag: *b"001",
+                    length: 11,
+                    start: 0,
+                },
+                DirectoryEntry {
+                    tag: *b"245",
+                    length: 20,
+                    start: 11,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_directory_invalid() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00123nam a2200049 c 4500")?;
+
+        assert!(matches!(
+            Directory::from_bytes(b"001001100000245002000011", &leader),
+            Err(ParseRecordError::InvalidDirectory { position: 48 })
+        ));
+        assert!(matches!(
+            Directory::from_bytes(
+                b"00100110000024500200011\x1e",
+                &leader
+            ),
+            Err(ParseRecordError::InvalidDirectory { position: 36 })
+        ));
+        assert!(matches!(
+            Directory::from_bytes(b"001001100000\x1e", &leader),
+            Err(ParseRecordError::BaseAddressMismatch {
+                declared: 49,
+                actual: 37
+            })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_directory_entry_map() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00123nam a2200051 c 5500")?;
+        let directory = Directory::from_bytes(
+            b"00100011000002450002000011\x1e",
+            &leader,
+        )?;
+
+        assert_eq!(
+            directory.entries(),
+            &[
+                DirectoryEntry {
+                    tag: *b"001",
+                    length: 11,
+                    start: 0,
+                },
+                DirectoryEntry {
+                    tag: *b"245",
+                    length: 20,
+                    start: 11,
+                },
+            ]
+        );
+
+        let leader = Leader::from_bytes(b"00123nam a2200040 c 4620")?;
+        let directory =
+            Directory::from_bytes(b"0010011000000ab\x1e", &leader)?;
+        assert_eq!(
+            directory.entries(),
+            &[DirectoryEntry {
+                tag: *b"001",
+                length: 11,
+                start: 0,
+            }]
+        );
+
+        // Standard entries don't match a widened entry map.
+        let leader = Leader::from_bytes(b"00123nam a2200049 c 5500")?;
+        assert!(matches!(
+            Directory::from_bytes(
+                b"001001100000245002000011\x1e",
+                &leader
+            ),
+            Err(ParseRecordError::InvalidDirectory { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_directory_entry_ord() {
+        let entry = |tag: &[u8; 3], start| DirectoryEntry {
+            tag: *tag,
+            length: 10,
+            start,
+        };
+
+        let mut entries = vec![
+            entry(b"650", 40),
+            entry(b"001", 0),
+            entry(b"245", 20),
+            entry(b"650", 30),
+            entry(b"008", 10),
+        ];
+        entries.sort();
+
+        assert_eq!(
+            entries,
+            vec![
+                entry(b"001", 0),
+                entry(b"008", 10),
+                entry(b"245", 20),
+                entry(b"650", 30),
+                entry(b"650", 40),
+            ]
+        );
+    }
+}
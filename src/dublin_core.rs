@@ -0,0 +1,331 @@
+//! A crosswalk from MARC 21 to (simple, unqualified) Dublin Core.
+//!
+//! The mapping is deliberately conservative and loosely follows the
+//! Library of Congress MARC to Dublin Core crosswalk:
+//!
+//! | Element       | Source                                         |
+//! |---------------|------------------------------------------------|
+//! | `title`       | `245 $a $b`                                    |
+//! | `creator`     | `100`, `110`, `111` (`$a $b $c $d $q`)         |
+//! | `contributor` | `700`, `710`, `711` (`$a $b $c $d $q`)         |
+//! | `subject`     | `600`, `610`, `611`, `630`, `650`, `651`       |
+//! | `description` | `520 $a`                                       |
+//! | `publisher`   | `260 $b`, `264 $b` (second indicator `1`)      |
+//! | `date`        | `260 $c`, `264 $c` (second indicator `1`)      |
+//! | `type`        | leader position 06                             |
+//! | `identifier`  | `020 $a`, `022 $a`, `856 $u`                   |
+//! | `language`    | `008/35-37`, `041 $a $d`                       |
+//!
+//! The subdivisions of a subject heading are joined by `--`. Trailing
+//! ISBD punctuation is removed from all values except the
+//! identifiers, and bytes which aren't valid UTF-8 are replaced by
+//! `U+FFFD`.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{DataField, Record, TypeOfRecord};
+
+/// The Dublin Core elements of a record, see [Record::to_dublin_core]
+/// and the [module documentation](self) for the mapping.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DublinCore {
+    /// The titles of the resource.
+    pub title: Vec<String>,
+    /// The names of the entities primarily responsible for the
+    /// resource.
+    pub creator: Vec<String>,
+    /// The names of further contributing entities.
+    pub contributor: Vec<String>,
+    /// The subject headings.
+    pub subject: Vec<String>,
+    /// The summaries of the content.
+    pub description: Vec<String>,
+    /// The names of the publishers.
+    pub publisher: Vec<String>,
+    /// The dates of publication, as transcribed.
+    pub date: Vec<String>,
+    /// The DCMI types (e.g. `Text`) of the resource.
+    pub r#type: Vec<String>,
+    /// The ISBNs, ISSNs and URLs of the resource.
+    pub identifier: Vec<String>,
+    /// The MARC language codes (e.g. `eng`).
+    pub language: Vec<String>,
+}
+
+/// The subfield codes of a name (name, numeration, titles, dates and
+/// fuller form).
+const NAME_CODES: &[u8] = b"abcdq";
+
+/// The subfield codes of a subject heading, including the form,
+/// general, chronological and geographic subdivisions.
+const SUBJECT_CODES: &[u8] = b"abcdfgklmnpqrstvxyz";
+
+/// The tags of the subject added entries.
+const SUBJECT_TAGS: [&[u8; 3]; 6] =
+    [b"600", b"610", b"611", b"630", b"650", b"651"];
+
+/// Joins the trimmed values of the subfields with the given codes, or
+/// returns `None` if there are no such (non-empty) subfields.
+fn join(field: &DataField, codes: &[u8], sep: &str) -> Option<String> {
+    let values: Vec<_> = field
+        .subfields
+        .iter()
+        .filter(|subfield| codes.contains(&subfield.code))
+        .map(|subfield| subfield.trimmed_value())
+        .filter(|value| !value.is_empty())
+        .map(String::from_utf8_lossy)
+        .collect();
+
+    (!values.is_empty()).then(|| values.join(sep))
+}
+
+/// Returns the trimmed, non-empty values of the subfields with the
+/// given code.
+fn values(
+    field: &DataField,
+    code: u8,
+) -> impl Iterator<Item = String> + '_ {
+    field
+        .subfields_by_code(code)
+        .map(|subfield| subfield.trimmed_value())
+        .filter(|value| !value.is_empty())
+        .map(|value| String::from_utf8_lossy(value).into_owned())
+}
+
+/// Returns the DCMI type term of the type of record.
+fn dcmi_type(type_of_record: TypeOfRecord) -> Option<&'static str> {
+    use TypeOfRecord::*;
+
+    match type_of_record {
+        LanguageMaterial
+        | ManuscriptLanguageMaterial
+        | NotatedMusic
+        | ManuscriptNotatedMusic => Some("Text"),
+        CartographicMaterial
+        | ManuscriptCartographicMaterial
+        | TwoDimensionalNonprojectableGraphic => Some("StillImage"),
+        ProjectedMedium => Some("MovingImage"),
+        NonmusicalSoundRecording | MusicalSoundRecording => {
+            Some("Sound")
+        }
+        ComputerFile => Some("Software"),
+        MixedMaterials => Some("Collection"),
+        ThreeDimensionalArtifact => Some("PhysicalObject"),
+        _ => None,
+    }
+}
+
+impl Record {
+    /// Crosswalks the record to Dublin Core; see the
+    /// [dublin_core](crate::dublin_core) module for the mapping.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"100",
+    ///             b'1',
+    ///             b' ',
+    ///             [Subfield::new(b'a', "Sandburg, Carl,")],
+    ///         )
+    ///         .data_field(
+    ///             b"245",
+    ///             b'1',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Arithmetic /")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let dc = record.to_dublin_core();
+    ///     assert_eq!(dc.title, ["Arithmetic"]);
+    ///     assert_eq!(dc.creator, ["Sandburg, Carl"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_dublin_core(&self) -> DublinCore {
+        let mut dc = DublinCore::default();
+
+        for field in self.data_fields.iter() {
+            let publication = &field.tag == b"260"
+                || (&field.tag == b"264" && field.ind2 == b'1');
+
+            match &field.tag {
+                b"245" => dc.title.extend(join(field, b"ab", " ")),
+                b"100" | b"110" | b"111" => {
+                    dc.creator.extend(join(field, NAME_CODES, " "))
+                }
+                b"700" | b"710" | b"711" => {
+                    dc.contributor.extend(join(field, NAME_CODES, " "))
+                }
+                b"520" => dc.description.extend(values(field, b'a')),
+                tag if SUBJECT_TAGS.contains(&tag) => {
+                    dc.subject.extend(join(field, SUBJECT_CODES, "--"))
+                }
+                _ if publication => {
+                    dc.publisher.extend(values(field, b'b'));
+                    dc.date.extend(values(field, b'c'));
+                }
+                _ => (),
+            }
+        }
+
+        if let Some(term) = dcmi_type(self.leader.type_of_record()) {
+            dc.r#type.push(term.into());
+        }
+
+        dc.identifier = self
+            .isbns()
+            .chain(self.issns())
+            .chain(self.subfield_values(b"856", b'u'))
+            .map(|value| String::from_utf8_lossy(value).into_owned())
+            .collect();
+
+        dc.language = self
+            .languages()
+            .iter()
+            .map(|code| String::from_utf8_lossy(code).into_owned())
+            .collect();
+
+        dc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LeaderBuilder, RecordBuilder, Subfield};
+
+    #[test]
+    fn test_to_dublin_core() -> anyhow::Result<()> {
+        let leader = LeaderBuilder::new()
+            .type_of_record(TypeOfRecord::LanguageMaterial)
+            .build()?;
+        let record = RecordBuilder::new()
+            .leader(leader)
+            .control_field(b"001", "12883376")
+            .control_field(
+                b"008",
+                "030520s1993    nyua   j      000 1 eng  ",
+            )
+            .data_field(
+                b"020",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "0152038655 (pbk.)")],
+            )
+            .data_field(
+                b"100",
+                b'1',
+                b' ',
+                [
+                    Subfield::new(b'a', "Sandburg, Carl,"),
+                    Subfield::new(b'd', "1878-1967."),
+                    Subfield::new(b'e', "author."),
+                ],
+            )
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [
+                    Subfield::new(b'a', "Arithmetic /"),
+                    Subfield::new(b'c', "Carl Sandburg."),
+                ],
+            )
+            .data_field(
+                b"260",
+                b' ',
+                b' ',
+                [
+                    Subfield::new(b'a', "San Diego :"),
+                    Subfield::new(b'b', "Harcourt Brace Jovanovich,"),
+                    Subfield::new(b'c', "c1993."),
+                ],
+            )
+            .data_field(
+                b"520",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "A poem about numbers.")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [
+                    Subfield::new(b'a', "Arithmetic"),
+                    Subfield::new(b'v', "Juvenile poetry."),
+                ],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'1',
+                [Subfield::new(b'a', "American poetry.")],
+            )
+            .data_field(
+                b"700",
+                b'1',
+                b' ',
+                [
+                    Subfield::new(b'a', "Rand, Ted,"),
+                    Subfield::new(b'e', "illustrator."),
+                ],
+            )
+            .build();
+
+        let dc = record.to_dublin_core();
+        assert_eq!(dc.title, ["Arithmetic"]);
+        assert_eq!(dc.creator, ["Sandburg, Carl 1878-1967"]);
+        assert_eq!(dc.contributor, ["Rand, Ted"]);
+        assert_eq!(
+            dc.subject,
+            ["Arithmetic--Juvenile poetry", "American poetry"]
+        );
+        assert_eq!(dc.description, ["A poem about numbers"]);
+        assert_eq!(dc.publisher, ["Harcourt Brace Jovanovich"]);
+        assert_eq!(dc.date, ["c1993"]);
+        assert_eq!(dc.r#type, ["Text"]);
+        assert_eq!(dc.identifier, ["0152038655"]);
+        assert_eq!(dc.language, ["eng"]);
+
+        assert_eq!(
+            RecordBuilder::new().build().to_dublin_core(),
+            DublinCore::default()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_publication_statement() {
+        let record = RecordBuilder::new()
+            .data_field(
+                b"264",
+                b' ',
+                b'1',
+                [
+                    Subfield::new(b'b', "Publisher,"),
+                    Subfield::new(b'c', "2020."),
+                ],
+            )
+            .data_field(
+                b"264",
+                b' ',
+                b'4',
+                [Subfield::new(b'c', "\u{a9}2019")],
+            )
+            .build();
+
+        let dc = record.to_dublin_core();
+        assert_eq!(dc.publisher, ["Publisher"]);
+        assert_eq!(dc.date, ["2020"]);
+    }
+}
@@ -0,0 +1,433 @@
+use alloc::string::String;
+use core::fmt;
+
+use nom::error::{FromExternalError, ParseError};
+
+use crate::ParseLeaderError;
+
+/// An error that can occur when parsing a record.
+///
+/// This is the single error type of the binary parsers, covering the
+/// leader, the directory, the variable fields and the overall record
+/// structure. Errors of the leader are wrapped in
+/// [`ParseRecordError::Leader`].
+///
+/// Errors that can be attributed to a specific location carry the
+/// byte offset (`position`) where the error occurred; see
+/// [`ParseRecordError::position`].
+#[derive(Debug)]
+pub enum ParseRecordError {
+    Leader {
+        source: ParseLeaderError,
+        position: usize,
+    },
+    InvalidDirectory {
+        position: usize,
+    },
+    BaseAddressMismatch {
+        declared: u32,
+        actual: u32,
+    },
+    LengthMismatch {
+        declared: u32,
+        actual: u32,
+    },
+    InvalidIndicator {
+        tag: [u8; 3],
+        indicator: u8,
+        position: usize,
+    },
+    InvalidSubfieldCode {
+        tag: [u8; 3],
+        code: u8,
+        position: usize,
+    },
+    InvalidField {
+        position: usize,
+    },
+    InvalidUtf8 {
+        tag: [u8; 3],
+        code: u8,
+        position: usize,
+    },
+    DirectoryOutOfBounds {
+        tag: [u8; 3],
+        start: u32,
+        length: u32,
+    },
+    MissingTerminator {
+        position: usize,
+    },
+    RecordTooLarge {
+        declared: u32,
+        max: u32,
+        position: usize,
+    },
+    TrailingData {
+        position: usize,
+    },
+    Truncated,
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+    Nom(nom::error::ErrorKind),
+}
+
+impl fmt::Display for ParseRecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Leader { source, position } => write!(
+                f,
+                "invalid leader at position {position}: {source}"
+            ),
+            Self::InvalidDirectory { position } => {
+                write!(f, "invalid directory at position {position}")
+            }
+            Self::BaseAddressMismatch { declared, actual } => write!(
+                f,
+                "base address mismatch (declared {declared}, actual \
+                 {actual})"
+            ),
+            Self::LengthMismatch { declared, actual } => write!(
+                f,
+                "record length mismatch (declared {declared}, actual \
+                 {actual})"
+            ),
+            Self::InvalidIndicator {
+                tag,
+                indicator,
+                position,
+            } => write!(
+                f,
+                "invalid indicator {indicator:#04x} in field {} at \
+                 position {position}",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::InvalidSubfieldCode {
+                tag,
+                code,
+                position,
+            } => write!(
+                f,
+                "invalid subfield code {code:#04x} in field {} at \
+                 position {position}",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::InvalidField { position } => {
+                write!(f, "invalid field at position {position}")
+            }
+            Self::InvalidUtf8 {
+                tag,
+                code,
+                position,
+            } => write!(
+                f,
+                "invalid UTF-8 in subfield ${} of field {} at position \
+                 {position}",
+                *code as char,
+                String::from_utf8_lossy(tag)
+            ),
+            Self::DirectoryOutOfBounds { tag, start, length } => {
+                write!(
+                    f,
+                    "directory entry of field {} (start {start}, \
+                     length {length}) exceeds the record",
+                    String::from_utf8_lossy(tag)
+                )
+            }
+            Self::MissingTerminator { position } => write!(
+                f,
+                "missing record terminator at position {position}"
+            ),
+            Self::RecordTooLarge {
+                declared,
+                max,
+                position,
+            } => write!(
+                f,
+                "record length {declared} at position {position} \
+                 exceeds the maximum of {max}"
+            ),
+            Self::TrailingData { position } => write!(
+                f,
+                "unexpected data after the record terminator at \
+                 position {position}"
+            ),
+            Self::Truncated => f.write_str("truncated record"),
+            #[cfg(feature = "std")]
+            Self::Io(e) => fmt::Display::fmt(e, f),
+            Self::Nom(kind) => write!(f, "parse error: {kind:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for ParseRecordError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl core::error::Error for ParseRecordError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Leader { source, .. } => Some(source),
+            #[cfg(feature = "std")]
+            Self::Io(e) => e.source(),
+            _ => None,
+        }
+    }
+}
+
+/// An error that can occur when serializing a record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SerializeError {
+    EmbeddedDelimiter { tag: [u8; 3], code: Option<u8> },
+    FieldTooLong { tag: [u8; 3], length: usize },
+    StartTooLarge { tag: [u8; 3], start: usize },
+    RecordTooLong { length: usize },
+}
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmbeddedDelimiter { tag, code } => {
+                write!(
+                    f,
+                    "reserved delimiter byte in field {}",
+                    String::from_utf8_lossy(tag)
+                )?;
+
+                if let Some(code) = code {
+                    write!(f, " ${}", *code as char)?;
+                }
+
+                Ok(())
+            }
+            Self::FieldTooLong { tag, length } => write!(
+                f,
+                "field {} too long for the directory ({length} bytes)",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::StartTooLarge { tag, start } => write!(
+                f,
+                "start of field {} too large for the directory \
+                 ({start})",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::RecordTooLong { length } => write!(
+                f,
+                "record too long ({length} bytes, maximum 99999)"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for SerializeError {}
+
+impl ParseRecordError {
+    /// Returns the byte offset where the error occurred, if known.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let result = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e",
+    ///     );
+    ///
+    ///     assert_eq!(result.unwrap_err().position(), Some(47));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn position(&self) -> Option<usize> {
+        match self {
+            Self::Leader { position, .. }
+            | Self::InvalidDirectory { position }
+            | Self::InvalidIndicator { position, .. }
+            | Self::InvalidSubfieldCode { position, .. }
+            | Self::InvalidField { position }
+            | Self::InvalidUtf8 { position, .. }
+            | Self::RecordTooLarge { position, .. }
+            | Self::TrailingData { position }
+            | Self::MissingTerminator { position } => Some(*position),
+            _ => None,
+        }
+    }
+
+    /// Shifts the position of the error by `offset` bytes, e.g. to
+    /// make it relative to the start of a stream instead of the
+    /// record.
+    pub(crate) fn offset(mut self, offset: usize) -> Self {
+        match &mut self {
+            Self::Leader { position, .. }
+            | Self::InvalidDirectory { position }
+            | Self::InvalidIndicator { position, .. }
+            | Self::InvalidSubfieldCode { position, .. }
+            | Self::InvalidField { position }
+            | Self::InvalidUtf8 { position, .. }
+            | Self::RecordTooLarge { position, .. }
+            | Self::TrailingData { position }
+            | Self::MissingTerminator { position } => {
+                *position += offset
+            }
+            _ => (),
+        }
+
+        self
+    }
+}
+
+impl From<ParseLeaderError> for ParseRecordError {
+    fn from(source: ParseLeaderError) -> Self {
+        Self::Leader {
+            source,
+            position: 0,
+        }
+    }
+}
+
+impl<'a> ParseError<&'a [u8]> for ParseRecordError {
+    fn from_error_kind(
+        _: &'a [u8],
+        kind: nom::error::ErrorKind,
+    ) -> Self {
+        Self::Nom(kind)
+    }
+
+    fn append(
+        _: &'a [u8],
+        kind: nom::error::ErrorKind,
+        _: Self,
+    ) -> Self {
+        Self::Nom(kind)
+    }
+}
+
+impl From<ParseRecordError> for nom::Err<ParseRecordError> {
+    fn from(e: ParseRecordError) -> Self {
+        nom::Err::Error(e)
+    }
+}
+
+impl<I, E> FromExternalError<I, E> for ParseRecordError {
+    fn from_external_error(
+        _: I,
+        kind: nom::error::ErrorKind,
+        _: E,
+    ) -> Self {
+        Self::Nom(kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::Record;
+
+    const RECORD: &[u8; 48] =
+        b"00048nam a2200037 c 4500001001000000\x1e123456789\x1e\x1d";
+
+    fn parse_with(f: impl FnOnce(&mut Vec<u8>)) -> ParseRecordError {
+        let mut data = RECORD.to_vec();
+        f(&mut data);
+        Record::from_bytes(&data).unwrap_err()
+    }
+
+    #[test]
+    fn test_parse_record_error() {
+        assert!(matches!(
+            parse_with(|data| data[3] = b'x'),
+            ParseRecordError::Leader {
+                source: ParseLeaderError::InvalidRecordLength,
+                position: 0,
+            }
+        ));
+        assert!(matches!(
+            parse_with(|data| data[28] = b'x'),
+            ParseRecordError::InvalidDirectory { position: 24 }
+        ));
+        assert!(matches!(
+            parse_with(|data| data[4] = b'9'),
+            ParseRecordError::LengthMismatch {
+                declared: 49,
+                actual: 48
+            }
+        ));
+        assert!(matches!(
+            parse_with(|data| data[16] = b'8'),
+            ParseRecordError::BaseAddressMismatch {
+                declared: 38,
+                actual: 37
+            }
+        ));
+        assert!(matches!(
+            parse_with(|data| data.truncate(47)),
+            ParseRecordError::MissingTerminator { position: 47 }
+        ));
+        assert!(matches!(
+            parse_with(|data| data[34] = b'9'),
+            ParseRecordError::DirectoryOutOfBounds {
+                tag: [b'0', b'0', b'1'],
+                start: 90,
+                length: 10,
+            }
+        ));
+        assert!(matches!(
+            parse_with(|data| data[41] = 0x1e),
+            ParseRecordError::InvalidField { position: 37 }
+        ));
+    }
+
+    #[test]
+    fn test_position() {
+        let err = parse_with(|data| data.truncate(47));
+        assert_eq!(err.position(), Some(47));
+        assert_eq!(err.offset(100).position(), Some(147));
+
+        let err = parse_with(|data| data[4] = b'9');
+        assert_eq!(err.position(), None);
+        assert_eq!(err.offset(100).position(), None);
+    }
+
+    #[test]
+    fn test_from_leader_error() {
+        let err: ParseRecordError =
+            ParseLeaderError::InvalidRecordLength.into();
+
+        assert!(matches!(
+            err,
+            ParseRecordError::Leader {
+                source: ParseLeaderError::InvalidRecordLength,
+                position: 0,
+            }
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_serialize_error() {
+        let err = SerializeError::EmbeddedDelimiter {
+            tag: *b"245",
+            code: Some(b'a'),
+        };
+        assert_eq!(
+            err.to_string(),
+            "reserved delimiter byte in field 245 $a"
+        );
+
+        let err = SerializeError::EmbeddedDelimiter {
+            tag: *b"001",
+            code: None,
+        };
+        assert_eq!(
+            err.to_string(),
+            "reserved delimiter byte in field 001"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use core::iter::FusedIterator;
+use core::slice;
+
+use crate::{ControlField, DataField, Record};
+
+/// A (control or data) field of a [Record](crate::Record).
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{Field, RecordBuilder, Subfield};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let record = RecordBuilder::new()
+///         .control_field(b"001", "123456789")
+///         .data_field(
+///             b"245",
+///             b'0',
+///             b'0',
+///             [Subfield::new(b'a', "Title")],
+///         )
+///         .build();
+///
+///     let mut fields = record.all_fields();
+///     assert!(matches!(fields.next(), Some(Field::Control(_))));
+///     assert_eq!(fields.next().unwrap().tag(), b"245");
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Field<'a> {
+    /// A control field (`00X`).
+    Control(&'a ControlField),
+    /// A data field.
+    Data(&'a DataField),
+}
+
+impl<'a> Field<'a> {
+    /// Returns the tag of the field.
+    pub fn tag(&self) -> &'a [u8; 3] {
+        match self {
+            Self::Control(field) => field.tag(),
+            Self::Data(field) => field.tag(),
+        }
+    }
+
+    /// Returns `true` if the field is a control field.
+    pub fn is_control_field(&self) -> bool {
+        matches!(self, Self::Control(_))
+    }
+
+    /// Returns the control field, or `None` for data fields.
+    pub fn as_control_field(&self) -> Option<&'a ControlField> {
+        match self {
+            Self::Control(field) => Some(field),
+            Self::Data(_) => None,
+        }
+    }
+
+    /// Returns the data field, or `None` for control fields.
+    pub fn as_data_field(&self) -> Option<&'a DataField> {
+        match self {
+            Self::Control(_) => None,
+            Self::Data(field) => Some(field),
+        }
+    }
+}
+
+/// An iterator over all (control and data) fields of a [Record], in
+/// directory order.
+///
+/// The iterator is created by [Record::all_fields] or by iterating
+/// over a `&Record`.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::Record;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let record = Record::from_bytes(
+///         b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+///         123456789\x1e00\x1faTitle\x1e\x1d",
+///     )?;
+///
+///     let mut tags = vec![];
+///     for field in &record {
+///         tags.push(field.tag());
+///     }
+///     assert_eq!(tags, [b"001", b"245"]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Fields<'a> {
+    control_fields: slice::Iter<'a, ControlField>,
+    data_fields: slice::Iter<'a, DataField>,
+    /// The interleaving of the fields, or `None` if the control fields
+    /// precede the data fields.
+    order: Option<slice::Iter<'a, bool>>,
+}
+
+impl<'a> Fields<'a> {
+    pub(crate) fn new(record: &'a Record) -> Self {
+        let order = &record.field_order.0;
+
+        Self {
+            control_fields: record.control_fields.iter(),
+            data_fields: record.data_fields.iter(),
+            order: (!order.is_empty()).then(|| order.iter()),
+        }
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = Field<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.order {
+            Some(ref mut order) => {
+                if *order.next()? {
+                    self.control_fields.next().map(Field::Control)
+                } else {
+                    self.data_fields.next().map(Field::Data)
+                }
+            }
+            None => match self.control_fields.next() {
+                Some(field) => Some(Field::Control(field)),
+                None => self.data_fields.next().map(Field::Data),
+            },
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Fields<'_> {
+    fn len(&self) -> usize {
+        self.control_fields.len() + self.data_fields.len()
+    }
+}
+
+impl FusedIterator for Fields<'_> {}
+
+impl<'a> IntoIterator for &'a Record {
+    type Item = Field<'a>;
+    type IntoIter = Fields<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Fields::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RecordBuilder, Subfield};
+
+    #[test]
+    fn test_field() {
+        let control = ControlField::new(b"001", "123456789");
+        let data = DataField {
+            tag: *b"245",
+            ind1: b'0',
+            ind2: b'0',
+            subfields: vec![],
+        };
+
+        let field = Field::Control(&control);
+        assert_eq!(field.tag(), b"001");
+        assert!(field.is_control_field());
+        assert_eq!(field.as_control_field(), Some(&control));
+        assert_eq!(field.as_data_field(), None);
+
+        let field = Field::Data(&data);
+        assert_eq!(field.tag(), b"245");
+        assert!(!field.is_control_field());
+        assert_eq!(field.as_control_field(), None);
+        assert_eq!(field.as_data_field(), Some(&data));
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Title")],
+            )
+            .control_field(b"003", "DE-101")
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .build();
+
+        let mut count = 0;
+        for field in &record {
+            assert_eq!(field.is_control_field(), count < 2);
+            count += 1;
+        }
+        assert_eq!(count, record.field_count());
+
+        let tags: Vec<_> =
+            (&record).into_iter().map(|field| field.tag()).collect();
+        assert_eq!(tags, [b"001", b"003", b"245", b"650"]);
+        assert!(record.all_fields().eq(&record));
+
+        let mut fields = record.all_fields();
+        assert_eq!(fields.len(), 4);
+        fields.nth(2);
+        assert_eq!(fields.size_hint(), (1, Some(1)));
+        assert!(fields.next().is_some());
+        assert!(fields.next().is_none());
+
+        let record = RecordBuilder::new().build();
+        assert_eq!((&record).into_iter().count(), 0);
+    }
+}
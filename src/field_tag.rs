@@ -0,0 +1,258 @@
+use core::borrow::Borrow;
+use core::fmt;
+use core::str::FromStr;
+
+/// An error that can occur when parsing a field tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseFieldTagError {
+    InvalidLength(usize),
+    InvalidCharacter(u8),
+}
+
+impl fmt::Display for ParseFieldTagError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(len) => {
+                write!(f, "invalid tag length {len}, expected 3")
+            }
+            Self::InvalidCharacter(b) => {
+                write!(f, "invalid tag character {b:#04x}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseFieldTagError {}
+
+/// The three-character tag of a field, e.g. `245`.
+///
+/// A tag consists of ASCII digits or letters; the tags of MARC 21
+/// fields are numeric, but alphabetic tags are used locally.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{FieldTag, Record};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let tag: FieldTag = "245".parse()?;
+///     assert_eq!(tag.to_string(), "245");
+///
+///     let record = Record::from_bytes(
+///         b"00048nam a2200037 c 4500245001000000\x1e\
+///         00\x1faTitle\x1e\x1d",
+///     )?;
+///     assert_eq!(record.fields(tag).count(), 1);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FieldTag([u8; 3]);
+
+impl FieldTag {
+    /// Creates a tag from a byte slice, which must consist of exactly
+    /// three ASCII digits or letters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{FieldTag, ParseFieldTagError};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     assert_eq!(FieldTag::new(b"245")?.as_bytes(), b"245");
+    ///     assert_eq!(
+    ///         FieldTag::new(b"24"),
+    ///         Err(ParseFieldTagError::InvalidLength(2))
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(tag: &[u8]) -> Result<Self, ParseFieldTagError> {
+        let tag: [u8; 3] = tag.try_into().map_err(|_| {
+            ParseFieldTagError::InvalidLength(tag.len())
+        })?;
+
+        if let Some(b) = tag.iter().find(|b| !b.is_ascii_alphanumeric())
+        {
+            return Err(ParseFieldTagError::InvalidCharacter(*b));
+        }
+
+        Ok(Self(tag))
+    }
+
+    /// Returns the bytes of the tag.
+    pub fn as_bytes(&self) -> &[u8; 3] {
+        &self.0
+    }
+
+    /// Returns `true` if the tag belongs to a control field (`00X`).
+    pub fn is_control_field(&self) -> bool {
+        self.0.starts_with(b"00")
+    }
+}
+
+impl AsRef<[u8]> for FieldTag {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl Borrow<[u8; 3]> for FieldTag {
+    fn borrow(&self) -> &[u8; 3] {
+        &self.0
+    }
+}
+
+impl From<FieldTag> for [u8; 3] {
+    fn from(tag: FieldTag) -> Self {
+        tag.0
+    }
+}
+
+impl TryFrom<&[u8]> for FieldTag {
+    type Error = ParseFieldTagError;
+
+    fn try_from(tag: &[u8]) -> Result<Self, Self::Error> {
+        Self::new(tag)
+    }
+}
+
+impl TryFrom<[u8; 3]> for FieldTag {
+    type Error = ParseFieldTagError;
+
+    fn try_from(tag: [u8; 3]) -> Result<Self, Self::Error> {
+        Self::new(&tag)
+    }
+}
+
+impl FromStr for FieldTag {
+    type Err = ParseFieldTagError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.as_bytes())
+    }
+}
+
+impl fmt::Display for FieldTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The tag consists of ASCII characters only.
+        for b in self.0 {
+            fmt::Write::write_char(f, b as char)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PartialEq<[u8; 3]> for FieldTag {
+    fn eq(&self, other: &[u8; 3]) -> bool {
+        self.0 == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::{
+        ControlField, DataField, DataFieldError, Record, RecordBuilder,
+        RecordRef, Subfield,
+    };
+
+    #[test]
+    fn test_field_tag() -> anyhow::Result<()> {
+        let tag = FieldTag::new(b"245")?;
+        assert_eq!(tag, *b"245");
+        assert_eq!(tag.to_string(), "245");
+        assert_eq!("245".parse::<FieldTag>()?, tag);
+        assert_eq!(FieldTag::try_from(*b"245")?, tag);
+        assert!(!tag.is_control_field());
+        assert!(FieldTag::new(b"001")?.is_control_field());
+        assert_eq!(FieldTag::new(b"CAT")?.to_string(), "CAT");
+
+        assert_eq!(
+            FieldTag::new(b"24"),
+            Err(ParseFieldTagError::InvalidLength(2))
+        );
+        assert_eq!(
+            "2450".parse::<FieldTag>(),
+            Err(ParseFieldTagError::InvalidLength(4))
+        );
+        assert_eq!(
+            FieldTag::new(b"2 5"),
+            Err(ParseFieldTagError::InvalidCharacter(b' '))
+        );
+        assert_eq!(
+            FieldTag::new(b"24\x1e"),
+            Err(ParseFieldTagError::InvalidCharacter(0x1e))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_field_tag_lookup() -> anyhow::Result<()> {
+        let tag = FieldTag::new(b"500")?;
+        let mut record = Record::from_bytes(include_bytes!(
+            "../tests/data/sandburg.mrc"
+        ))?;
+
+        record.add_field(
+            DataField::new(tag, b' ', b' ')?
+                .with_subfield(b'a', "Note")?,
+        );
+        assert_eq!(record.fields(tag).count(), 1);
+        assert_eq!(record.get(tag).unwrap().tag(), b"500");
+        assert_eq!(
+            record.first_subfield_value(tag, b'a'),
+            Some(&b"Note"[..])
+        );
+        assert_eq!(record.subfield_values(tag, b'a').count(), 1);
+        assert!(record.matches(tag, Some(b'a'), Some(b"Note")));
+        assert!(record.index().contains(tag));
+        assert_eq!(record.index().fields(tag).count(), 1);
+        assert_eq!(record.extract(&[tag]).field_count(), 1);
+
+        record.append_subfield(tag, b'5', "DLC")?;
+        record.transcode_field(tag, |value| value.push(b'.'));
+        assert_eq!(
+            record.first_subfield_value(tag, b'5'),
+            Some(&b"DLC."[..])
+        );
+
+        let control = FieldTag::new(b"001")?;
+        assert_eq!(record.control_fields_by_tag(control).count(), 1);
+        assert_eq!(
+            RecordRef::from_bytes(&record.to_bytes())?
+                .fields_by_tag(control)
+                .count(),
+            1
+        );
+
+        let mut other = RecordBuilder::new()
+            .control_field(FieldTag::new(b"003")?, "DLC")
+            .data_field(tag, b' ', b' ', [Subfield::new(b'a', "Other")])
+            .build();
+        other.merge(&record, &[tag]);
+        assert_eq!(other.fields(tag).count(), 1);
+        assert_eq!(
+            ControlField::new(control, "123").tag(),
+            control.as_bytes()
+        );
+
+        assert_eq!(record.remove_fields(tag), 1);
+        assert!(record.get(tag).is_none());
+
+        assert_eq!(
+            DataField::new(FieldTag::new(b"001")?, b' ', b' '),
+            Err(DataFieldError::InvalidTag(*b"001"))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1142 @@
+use core::fmt;
+
+use crate::{BibliographicLevel, Leader, TypeOfRecord};
+
+/// An error that can occur when decoding a fixed-length field.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseFixedFieldError {
+    InvalidLength { expected: usize, actual: usize },
+    MissingCategory,
+}
+
+impl fmt::Display for ParseFixedFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength { expected, actual } => write!(
+                f,
+                "invalid field length {actual}, expected {expected}"
+            ),
+            Self::MissingCategory => {
+                f.write_str("missing category of material")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseFixedFieldError {}
+
+/// The length of the `008` field.
+const OO8_LEN: usize = 40;
+
+/// The length of the `006` field.
+const OO6_LEN: usize = 18;
+
+/// The offset of the type-specific positions of the `006` field
+/// (01-17) relative to those of the `008` field (18-34).
+const OO6_OFFSET: usize = 17;
+
+/// The material type, which determines the meaning of the
+/// type-specific positions 18-34 of the `008` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaterialType {
+    /// Books (`BK`).
+    Books,
+    /// Computer files (`CF`).
+    ComputerFiles,
+    /// Maps (`MP`).
+    Maps,
+    /// Music (`MU`).
+    Music,
+    /// Continuing resources (`CR`).
+    ContinuingResources,
+    /// Visual materials (`VM`).
+    VisualMaterials,
+    /// Mixed materials (`MX`).
+    MixedMaterials,
+    /// Any other type of record (e.g. authority or holdings data).
+    Other,
+}
+
+impl MaterialType {
+    /// Determines the material type from the type of record (leader
+    /// position 06) and the bibliographic level (leader position 07).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, MaterialType};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nas a2200241 c 4500")?;
+    ///     assert_eq!(
+    ///         MaterialType::from_leader(&leader),
+    ///         MaterialType::ContinuingResources
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_leader(leader: &Leader) -> Self {
+        use BibliographicLevel::*;
+        use TypeOfRecord::*;
+
+        match (leader.type_of_record(), leader.bibliographic_level()) {
+            (
+                LanguageMaterial,
+                SerialComponentPart | IntegratingResource | Serial,
+            ) => Self::ContinuingResources,
+            (LanguageMaterial | ManuscriptLanguageMaterial, _) => {
+                Self::Books
+            }
+            (ComputerFile, _) => Self::ComputerFiles,
+            (
+                CartographicMaterial | ManuscriptCartographicMaterial,
+                _,
+            ) => Self::Maps,
+            (
+                NotatedMusic
+                | ManuscriptNotatedMusic
+                | NonmusicalSoundRecording
+                | MusicalSoundRecording,
+                _,
+            ) => Self::Music,
+            (
+                ProjectedMedium
+                | TwoDimensionalNonprojectableGraphic
+                | Kit
+                | ThreeDimensionalArtifact,
+                _,
+            ) => Self::VisualMaterials,
+            (MixedMaterials, _) => Self::MixedMaterials,
+            _ => Self::Other,
+        }
+    }
+
+    /// Determines the material type from the form of material
+    /// (position 00 of the `006` field).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::MaterialType;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     assert_eq!(
+    ///         MaterialType::from_form_of_material('m'),
+    ///         MaterialType::ComputerFiles
+    ///     );
+    ///     assert_eq!(
+    ///         MaterialType::from_form_of_material('x'),
+    ///         MaterialType::Other
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_form_of_material(code: char) -> Self {
+        match code {
+            'a' | 't' => Self::Books,
+            'm' => Self::ComputerFiles,
+            'e' | 'f' => Self::Maps,
+            'c' | 'd' | 'i' | 'j' => Self::Music,
+            's' => Self::ContinuingResources,
+            'g' | 'k' | 'o' | 'r' => Self::VisualMaterials,
+            'p' => Self::MixedMaterials,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// The `008` fixed-length data elements field.
+///
+/// Positions 00-17 and 35-39 are defined for all materials, whereas
+/// the meaning of positions 18-34 depends on the [MaterialType] of
+/// the record. Accessors of type-specific positions return `None` if
+/// the position isn't defined for the material type.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{Leader, MaterialType, Oo8};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+///     let field = Oo8::new(
+///         b"850423s1985    nyua   j      000 1 eng d",
+///         &leader,
+///     )?;
+///
+///     assert_eq!(field.material_type(), MaterialType::Books);
+///     assert_eq!(field.date1(), b"1985");
+///     assert_eq!(field.language(), b"eng");
+///     assert_eq!(field.literary_form(), Some('1'));
+///     assert_eq!(field.frequency(), None);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Oo8<'a> {
+    data: &'a [u8],
+    material_type: MaterialType,
+    /// The position of `data[0]` within the `008` field, which is
+    /// non-zero if `data` is an `006` field (see [Oo6]).
+    offset: usize,
+}
+
+impl<'a> Oo8<'a> {
+    /// Creates a new `008` field from its data and the leader of the
+    /// record, which determines the material type.
+    pub fn new(
+        data: &'a [u8],
+        leader: &Leader,
+    ) -> Result<Self, ParseFixedFieldError> {
+        if data.len() != OO8_LEN {
+            return Err(ParseFixedFieldError::InvalidLength {
+                expected: OO8_LEN,
+                actual: data.len(),
+            });
+        }
+
+        Ok(Self {
+            data,
+            material_type: MaterialType::from_leader(leader),
+            offset: 0,
+        })
+    }
+
+    /// Returns the material type of the field.
+    pub fn material_type(&self) -> MaterialType {
+        self.material_type
+    }
+
+    /// Returns the code at the given position.
+    #[inline]
+    fn code(&self, pos: usize) -> char {
+        self.data[pos - self.offset] as char
+    }
+
+    /// Returns the codes at the positions `start..end`.
+    #[inline]
+    fn codes(&self, start: usize, end: usize) -> &'a [u8] {
+        &self.data[start - self.offset..end - self.offset]
+    }
+
+    /// Returns the code at the given position, if the material type
+    /// is one of `types`.
+    #[inline]
+    fn code_for(
+        &self,
+        pos: usize,
+        types: &[MaterialType],
+    ) -> Option<char> {
+        types.contains(&self.material_type).then(|| self.code(pos))
+    }
+
+    /// Returns the date entered on file (positions 00-05).
+    pub fn date_entered(&self) -> &'a [u8] {
+        &self.data[0..6]
+    }
+
+    /// Returns the type of date/publication status (position 06).
+    pub fn date_type(&self) -> char {
+        self.code(6)
+    }
+
+    /// Returns the first date (positions 07-10).
+    pub fn date1(&self) -> &'a [u8] {
+        &self.data[7..11]
+    }
+
+    /// Returns the second date (positions 11-14).
+    pub fn date2(&self) -> &'a [u8] {
+        &self.data[11..15]
+    }
+
+    /// Returns the place of publication, production, or execution
+    /// (positions 15-17).
+    pub fn place_of_publication(&self) -> &'a [u8] {
+        &self.data[15..18]
+    }
+
+    /// Returns the language (positions 35-37).
+    pub fn language(&self) -> &'a [u8] {
+        &self.data[35..38]
+    }
+
+    /// Returns the modified record code (position 38).
+    pub fn modified_record(&self) -> char {
+        self.code(38)
+    }
+
+    /// Returns the cataloging source (position 39).
+    pub fn cataloging_source(&self) -> char {
+        self.code(39)
+    }
+
+    /// Returns the illustrations codes (books, positions 18-21).
+    pub fn illustrations(&self) -> Option<&'a [u8]> {
+        (self.material_type == MaterialType::Books)
+            .then(|| self.codes(18, 22))
+    }
+
+    /// Returns the target audience (books, computer files, music and
+    /// visual materials, position 22).
+    pub fn target_audience(&self) -> Option<char> {
+        use MaterialType::*;
+        self.code_for(
+            22,
+            &[Books, ComputerFiles, Music, VisualMaterials],
+        )
+    }
+
+    /// Returns the form of item (position 23, or position 29 for maps
+    /// and visual materials).
+    pub fn form_of_item(&self) -> Option<char> {
+        use MaterialType::*;
+        match self.material_type {
+            Books | ComputerFiles | Music | ContinuingResources
+            | MixedMaterials => Some(self.code(23)),
+            Maps | VisualMaterials => Some(self.code(29)),
+            Other => None,
+        }
+    }
+
+    /// Returns the nature of contents codes (books, positions 24-27;
+    /// continuing resources, positions 25-27).
+    pub fn nature_of_contents(&self) -> Option<&'a [u8]> {
+        match self.material_type {
+            MaterialType::Books => Some(self.codes(24, 28)),
+            MaterialType::ContinuingResources => {
+                Some(self.codes(25, 28))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the government publication code (books, computer
+    /// files, maps, continuing resources and visual materials,
+    /// position 28).
+    pub fn government_publication(&self) -> Option<char> {
+        use MaterialType::*;
+        self.code_for(
+            28,
+            &[
+                Books,
+                ComputerFiles,
+                Maps,
+                ContinuingResources,
+                VisualMaterials,
+            ],
+        )
+    }
+
+    /// Returns the conference publication code (books and continuing
+    /// resources, position 29).
+    pub fn conference_publication(&self) -> Option<char> {
+        use MaterialType::*;
+        self.code_for(29, &[Books, ContinuingResources])
+    }
+
+    /// Returns the festschrift code (books, position 30).
+    pub fn festschrift(&self) -> Option<char> {
+        self.code_for(30, &[MaterialType::Books])
+    }
+
+    /// Returns the index code (books and maps, position 31).
+    pub fn index(&self) -> Option<char> {
+        use MaterialType::*;
+        self.code_for(31, &[Books, Maps])
+    }
+
+    /// Returns the literary form (books, position 33).
+    pub fn literary_form(&self) -> Option<char> {
+        self.code_for(33, &[MaterialType::Books])
+    }
+
+    /// Returns the biography code (books, position 34).
+    pub fn biography(&self) -> Option<char> {
+        self.code_for(34, &[MaterialType::Books])
+    }
+
+    /// Returns the frequency (continuing resources, position 18).
+    pub fn frequency(&self) -> Option<char> {
+        self.code_for(18, &[MaterialType::ContinuingResources])
+    }
+
+    /// Returns the regularity (continuing resources, position 19).
+    pub fn regularity(&self) -> Option<char> {
+        self.code_for(19, &[MaterialType::ContinuingResources])
+    }
+
+    /// Returns the type of continuing resource (continuing
+    /// resources, position 21).
+    pub fn type_of_continuing_resource(&self) -> Option<char> {
+        self.code_for(21, &[MaterialType::ContinuingResources])
+    }
+
+    /// Returns the type of computer file (computer files, position
+    /// 26).
+    pub fn type_of_computer_file(&self) -> Option<char> {
+        self.code_for(26, &[MaterialType::ComputerFiles])
+    }
+
+    /// Returns the form of composition (music, positions 18-19).
+    pub fn form_of_composition(&self) -> Option<&'a [u8]> {
+        (self.material_type == MaterialType::Music)
+            .then(|| self.codes(18, 20))
+    }
+
+    /// Returns the type of visual material (visual materials,
+    /// position 33).
+    pub fn type_of_visual_material(&self) -> Option<char> {
+        self.code_for(33, &[MaterialType::VisualMaterials])
+    }
+}
+
+/// The `006` additional material characteristics field.
+///
+/// The form of material (position 00) determines the [MaterialType],
+/// and positions 01-17 have the same meaning as the type-specific
+/// positions 18-34 of the [Oo8] field for that material type.
+/// Accessors return `None` if the position isn't defined for the
+/// material type; the raw data of unknown forms is available via
+/// [Oo6::data].
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{MaterialType, Oo6};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let field = Oo6::new(b"smr p       0   a0")?;
+///
+///     assert_eq!(
+///         field.material_type(),
+///         MaterialType::ContinuingResources
+///     );
+///     assert_eq!(field.frequency(), Some('m'));
+///     assert_eq!(field.literary_form(), None);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Oo6<'a> {
+    inner: Oo8<'a>,
+}
+
+impl<'a> Oo6<'a> {
+    /// Creates a new `006` field from its data.
+    pub fn new(data: &'a [u8]) -> Result<Self, ParseFixedFieldError> {
+        if data.len() != OO6_LEN {
+            return Err(ParseFixedFieldError::InvalidLength {
+                expected: OO6_LEN,
+                actual: data.len(),
+            });
+        }
+
+        Ok(Self {
+            inner: Oo8 {
+                data,
+                material_type: MaterialType::from_form_of_material(
+                    data[0] as char,
+                ),
+                offset: OO6_OFFSET,
+            },
+        })
+    }
+
+    /// Returns the raw data of the field.
+    pub fn data(&self) -> &'a [u8] {
+        self.inner.data
+    }
+
+    /// Returns the form of material (position 00).
+    pub fn form_of_material(&self) -> char {
+        self.inner.data[0] as char
+    }
+
+    /// Returns the material type of the field.
+    pub fn material_type(&self) -> MaterialType {
+        self.inner.material_type
+    }
+
+    /// Returns the illustrations codes (books, positions 01-04).
+    pub fn illustrations(&self) -> Option<&'a [u8]> {
+        self.inner.illustrations()
+    }
+
+    /// Returns the target audience (books, computer files, music and
+    /// visual materials, position 05).
+    pub fn target_audience(&self) -> Option<char> {
+        self.inner.target_audience()
+    }
+
+    /// Returns the form of item (position 06, or position 12 for maps
+    /// and visual materials).
+    pub fn form_of_item(&self) -> Option<char> {
+        self.inner.form_of_item()
+    }
+
+    /// Returns the nature of contents codes (books, positions 07-10;
+    /// continuing resources, positions 08-10).
+    pub fn nature_of_contents(&self) -> Option<&'a [u8]> {
+        self.inner.nature_of_contents()
+    }
+
+    /// Returns the government publication code (books, computer
+    /// files, maps, continuing resources and visual materials,
+    /// position 11).
+    pub fn government_publication(&self) -> Option<char> {
+        self.inner.government_publication()
+    }
+
+    /// Returns the conference publication code (books and continuing
+    /// resources, position 12).
+    pub fn conference_publication(&self) -> Option<char> {
+        self.inner.conference_publication()
+    }
+
+    /// Returns the festschrift code (books, position 13).
+    pub fn festschrift(&self) -> Option<char> {
+        self.inner.festschrift()
+    }
+
+    /// Returns the index code (books and maps, position 14).
+    pub fn index(&self) -> Option<char> {
+        self.inner.index()
+    }
+
+    /// Returns the literary form (books, position 16).
+    pub fn literary_form(&self) -> Option<char> {
+        self.inner.literary_form()
+    }
+
+    /// Returns the biography code (books, position 17).
+    pub fn biography(&self) -> Option<char> {
+        self.inner.biography()
+    }
+
+    /// Returns the frequency (continuing resources, position 01).
+    pub fn frequency(&self) -> Option<char> {
+        self.inner.frequency()
+    }
+
+    /// Returns the regularity (continuing resources, position 02).
+    pub fn regularity(&self) -> Option<char> {
+        self.inner.regularity()
+    }
+
+    /// Returns the type of continuing resource (continuing
+    /// resources, position 04).
+    pub fn type_of_continuing_resource(&self) -> Option<char> {
+        self.inner.type_of_continuing_resource()
+    }
+
+    /// Returns the type of computer file (computer files, position
+    /// 09).
+    pub fn type_of_computer_file(&self) -> Option<char> {
+        self.inner.type_of_computer_file()
+    }
+
+    /// Returns the form of composition (music, positions 01-02).
+    pub fn form_of_composition(&self) -> Option<&'a [u8]> {
+        self.inner.form_of_composition()
+    }
+
+    /// Returns the type of visual material (visual materials,
+    /// position 16).
+    pub fn type_of_visual_material(&self) -> Option<char> {
+        self.inner.type_of_visual_material()
+    }
+}
+
+/// The category of material of a `007` field (position 00).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CategoryOfMaterial {
+    /// Map (`a`).
+    Map,
+    /// Electronic resource (`c`).
+    ElectronicResource,
+    /// Globe (`d`).
+    Globe,
+    /// Tactile material (`f`).
+    TactileMaterial,
+    /// Projected graphic (`g`).
+    ProjectedGraphic,
+    /// Microform (`h`).
+    Microform,
+    /// Nonprojected graphic (`k`).
+    NonprojectedGraphic,
+    /// Motion picture (`m`).
+    MotionPicture,
+    /// Kit (`o`).
+    Kit,
+    /// Notated music (`q`).
+    NotatedMusic,
+    /// Remote-sensing image (`r`).
+    RemoteSensingImage,
+    /// Sound recording (`s`).
+    SoundRecording,
+    /// Text (`t`).
+    Text,
+    /// Videorecording (`v`).
+    Videorecording,
+    /// Unspecified (`z`).
+    Unspecified,
+    /// Any other (non-standard) category code.
+    Other(char),
+}
+
+impl From<char> for CategoryOfMaterial {
+    fn from(code: char) -> Self {
+        match code {
+            'a' => Self::Map,
+            'c' => Self::ElectronicResource,
+            'd' => Self::Globe,
+            'f' => Self::TactileMaterial,
+            'g' => Self::ProjectedGraphic,
+            'h' => Self::Microform,
+            'k' => Self::NonprojectedGraphic,
+            'm' => Self::MotionPicture,
+            'o' => Self::Kit,
+            'q' => Self::NotatedMusic,
+            'r' => Self::RemoteSensingImage,
+            's' => Self::SoundRecording,
+            't' => Self::Text,
+            'v' => Self::Videorecording,
+            'z' => Self::Unspecified,
+            _ => Self::Other(code),
+        }
+    }
+}
+
+impl From<CategoryOfMaterial> for char {
+    fn from(category: CategoryOfMaterial) -> Self {
+        match category {
+            CategoryOfMaterial::Map => 'a',
+            CategoryOfMaterial::ElectronicResource => 'c',
+            CategoryOfMaterial::Globe => 'd',
+            CategoryOfMaterial::TactileMaterial => 'f',
+            CategoryOfMaterial::ProjectedGraphic => 'g',
+            CategoryOfMaterial::Microform => 'h',
+            CategoryOfMaterial::NonprojectedGraphic => 'k',
+            CategoryOfMaterial::MotionPicture => 'm',
+            CategoryOfMaterial::Kit => 'o',
+            CategoryOfMaterial::NotatedMusic => 'q',
+            CategoryOfMaterial::RemoteSensingImage => 'r',
+            CategoryOfMaterial::SoundRecording => 's',
+            CategoryOfMaterial::Text => 't',
+            CategoryOfMaterial::Videorecording => 'v',
+            CategoryOfMaterial::Unspecified => 'z',
+            CategoryOfMaterial::Other(code) => code,
+        }
+    }
+}
+
+/// The `007` physical description fixed field.
+///
+/// The layout of the field depends on its first byte, the
+/// [CategoryOfMaterial]. Accessors of category-specific positions
+/// return `None` if the position isn't defined for the category or
+/// if the field is too short; the raw bytes are always available via
+/// [Oo7::data].
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{CategoryOfMaterial, Oo7};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let field = Oo7::new(b"sd fsngnnmmned")?;
+///
+///     assert_eq!(
+///         field.category(),
+///         CategoryOfMaterial::SoundRecording
+///     );
+///     assert_eq!(field.specific_material_designation(), Some('d'));
+///     assert_eq!(field.speed(), Some('f'));
+///     assert_eq!(field.videorecording_format(), None);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Oo7<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Oo7<'a> {
+    /// Creates a new `007` field from its data.
+    pub fn new(data: &'a [u8]) -> Result<Self, ParseFixedFieldError> {
+        if data.is_empty() {
+            return Err(ParseFixedFieldError::MissingCategory);
+        }
+
+        Ok(Self { data })
+    }
+
+    /// Returns the raw data of the field.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns the category of material (position 00).
+    pub fn category(&self) -> CategoryOfMaterial {
+        CategoryOfMaterial::from(self.data[0] as char)
+    }
+
+    /// Returns the code at the given position, if the category is
+    /// `category`.
+    #[inline]
+    fn code_for(
+        &self,
+        pos: usize,
+        category: CategoryOfMaterial,
+    ) -> Option<char> {
+        if self.category() != category {
+            return None;
+        }
+
+        self.data.get(pos).map(|&code| code as char)
+    }
+
+    /// Returns the specific material designation (position 01).
+    pub fn specific_material_designation(&self) -> Option<char> {
+        self.data.get(1).map(|&code| code as char)
+    }
+
+    /// Returns the dimensions (microforms, position 04; sound
+    /// recordings, position 06; videorecordings, position 07).
+    pub fn dimensions(&self) -> Option<char> {
+        use CategoryOfMaterial::*;
+        match self.category() {
+            Microform => self.code_for(4, Microform),
+            SoundRecording => self.code_for(6, SoundRecording),
+            Videorecording => self.code_for(7, Videorecording),
+            _ => None,
+        }
+    }
+
+    /// Returns the color (microforms, position 09; videorecordings,
+    /// position 03).
+    pub fn color(&self) -> Option<char> {
+        use CategoryOfMaterial::*;
+        match self.category() {
+            Microform => self.code_for(9, Microform),
+            Videorecording => self.code_for(3, Videorecording),
+            _ => None,
+        }
+    }
+
+    /// Returns the configuration of playback channels (sound
+    /// recordings, position 04; videorecordings, position 08).
+    pub fn playback_channels(&self) -> Option<char> {
+        use CategoryOfMaterial::*;
+        match self.category() {
+            SoundRecording => self.code_for(4, SoundRecording),
+            Videorecording => self.code_for(8, Videorecording),
+            _ => None,
+        }
+    }
+
+    /// Returns the positive/negative aspect (microforms, position
+    /// 03).
+    pub fn positive_negative_aspect(&self) -> Option<char> {
+        self.code_for(3, CategoryOfMaterial::Microform)
+    }
+
+    /// Returns the reduction ratio range (microforms, position 05).
+    pub fn reduction_ratio_range(&self) -> Option<char> {
+        self.code_for(5, CategoryOfMaterial::Microform)
+    }
+
+    /// Returns the reduction ratio (microforms, positions 06-08).
+    pub fn reduction_ratio(&self) -> Option<&'a [u8]> {
+        if self.category() != CategoryOfMaterial::Microform {
+            return None;
+        }
+
+        self.data.get(6..9)
+    }
+
+    /// Returns the emulsion on film (microforms, position 10).
+    pub fn emulsion_on_film(&self) -> Option<char> {
+        self.code_for(10, CategoryOfMaterial::Microform)
+    }
+
+    /// Returns the generation (microforms, position 11).
+    pub fn generation(&self) -> Option<char> {
+        self.code_for(11, CategoryOfMaterial::Microform)
+    }
+
+    /// Returns the base of film (microforms, position 12).
+    pub fn base_of_film(&self) -> Option<char> {
+        self.code_for(12, CategoryOfMaterial::Microform)
+    }
+
+    /// Returns the speed (sound recordings, position 03).
+    pub fn speed(&self) -> Option<char> {
+        self.code_for(3, CategoryOfMaterial::SoundRecording)
+    }
+
+    /// Returns the groove width/groove pitch (sound recordings,
+    /// position 05).
+    pub fn groove_width(&self) -> Option<char> {
+        self.code_for(5, CategoryOfMaterial::SoundRecording)
+    }
+
+    /// Returns the tape width (sound recordings, position 07).
+    pub fn tape_width(&self) -> Option<char> {
+        self.code_for(7, CategoryOfMaterial::SoundRecording)
+    }
+
+    /// Returns the tape configuration (sound recordings, position
+    /// 08).
+    pub fn tape_configuration(&self) -> Option<char> {
+        self.code_for(8, CategoryOfMaterial::SoundRecording)
+    }
+
+    /// Returns the kind of disc, cylinder, or tape (sound
+    /// recordings, position 09).
+    pub fn kind_of_disc(&self) -> Option<char> {
+        self.code_for(9, CategoryOfMaterial::SoundRecording)
+    }
+
+    /// Returns the kind of material (sound recordings, position 10).
+    pub fn kind_of_material(&self) -> Option<char> {
+        self.code_for(10, CategoryOfMaterial::SoundRecording)
+    }
+
+    /// Returns the kind of cutting (sound recordings, position 11).
+    pub fn kind_of_cutting(&self) -> Option<char> {
+        self.code_for(11, CategoryOfMaterial::SoundRecording)
+    }
+
+    /// Returns the special playback characteristics (sound
+    /// recordings, position 12).
+    pub fn special_playback_characteristics(&self) -> Option<char> {
+        self.code_for(12, CategoryOfMaterial::SoundRecording)
+    }
+
+    /// Returns the capture and storage technique (sound recordings,
+    /// position 13).
+    pub fn capture_and_storage_technique(&self) -> Option<char> {
+        self.code_for(13, CategoryOfMaterial::SoundRecording)
+    }
+
+    /// Returns the videorecording format (videorecordings, position
+    /// 04).
+    pub fn videorecording_format(&self) -> Option<char> {
+        self.code_for(4, CategoryOfMaterial::Videorecording)
+    }
+
+    /// Returns whether the sound is on the medium or separate
+    /// (videorecordings, position 05).
+    pub fn sound_on_medium(&self) -> Option<char> {
+        self.code_for(5, CategoryOfMaterial::Videorecording)
+    }
+
+    /// Returns the medium for sound (videorecordings, position 06).
+    pub fn medium_for_sound(&self) -> Option<char> {
+        self.code_for(6, CategoryOfMaterial::Videorecording)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_material_type() -> anyhow::Result<()> {
+        for (data, expected) in [
+            (b"00827nam a2200241 c 4500", MaterialType::Books),
+            (b"00827naa a2200241 c 4500", MaterialType::Books),
+            (b"00827ntm a2200241 c 4500", MaterialType::Books),
+            (
+                b"00827nas a2200241 c 4500",
+                MaterialType::ContinuingResources,
+            ),
+            (
+                b"00827nai a2200241 c 4500",
+                MaterialType::ContinuingResources,
+            ),
+            (b"00827nmm a2200241 c 4500", MaterialType::ComputerFiles),
+            (b"00827nem a2200241 c 4500", MaterialType::Maps),
+            (b"00827njm a2200241 c 4500", MaterialType::Music),
+            (
+                b"00827ngm a2200241 c 4500",
+                MaterialType::VisualMaterials,
+            ),
+            (b"00827npc a2200241 c 4500", MaterialType::MixedMaterials),
+            (b"00827nz  a2200241 c 4500", MaterialType::Other),
+        ] {
+            let leader = Leader::from_bytes(data)?;
+            assert_eq!(MaterialType::from_leader(&leader), expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oo8_books() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        let field = Oo8::new(
+            b"850423s1985    nyua   j      000 1 eng d",
+            &leader,
+        )?;
+
+        assert_eq!(field.material_type(), MaterialType::Books);
+        assert_eq!(field.date_entered(), b"850423");
+        assert_eq!(field.date_type(), 's');
+        assert_eq!(field.date1(), b"1985");
+        assert_eq!(field.date2(), b"    ");
+        assert_eq!(field.place_of_publication(), b"nyu");
+        assert_eq!(field.language(), b"eng");
+        assert_eq!(field.modified_record(), ' ');
+        assert_eq!(field.cataloging_source(), 'd');
+
+        assert_eq!(field.illustrations(), Some(&b"a   "[..]));
+        assert_eq!(field.target_audience(), Some('j'));
+        assert_eq!(field.form_of_item(), Some(' '));
+        assert_eq!(field.nature_of_contents(), Some(&b"    "[..]));
+        assert_eq!(field.government_publication(), Some(' '));
+        assert_eq!(field.conference_publication(), Some('0'));
+        assert_eq!(field.festschrift(), Some('0'));
+        assert_eq!(field.index(), Some('0'));
+        assert_eq!(field.literary_form(), Some('1'));
+        assert_eq!(field.biography(), Some(' '));
+
+        assert_eq!(field.frequency(), None);
+        assert_eq!(field.regularity(), None);
+        assert_eq!(field.type_of_continuing_resource(), None);
+        assert_eq!(field.form_of_composition(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oo8_serial() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00827cas a2200241 c 4500")?;
+        let field = Oo8::new(
+            b"751101c19759999nyumr p       0   a0eng d",
+            &leader,
+        )?;
+
+        assert_eq!(
+            field.material_type(),
+            MaterialType::ContinuingResources
+        );
+        assert_eq!(field.date_type(), 'c');
+        assert_eq!(field.date1(), b"1975");
+        assert_eq!(field.date2(), b"9999");
+        assert_eq!(field.language(), b"eng");
+
+        assert_eq!(field.frequency(), Some('m'));
+        assert_eq!(field.regularity(), Some('r'));
+        assert_eq!(field.type_of_continuing_resource(), Some('p'));
+        assert_eq!(field.form_of_item(), Some(' '));
+        assert_eq!(field.nature_of_contents(), Some(&b"   "[..]));
+        assert_eq!(field.conference_publication(), Some('0'));
+
+        assert_eq!(field.illustrations(), None);
+        assert_eq!(field.target_audience(), None);
+        assert_eq!(field.festschrift(), None);
+        assert_eq!(field.literary_form(), None);
+        assert_eq!(field.biography(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oo8_invalid_length() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        assert_eq!(
+            Oo8::new(b"850423s1985", &leader),
+            Err(ParseFixedFieldError::InvalidLength {
+                expected: 40,
+                actual: 11
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oo6_continuing_resource() -> anyhow::Result<()> {
+        let field = Oo6::new(b"smr p       0   a0")?;
+
+        assert_eq!(field.form_of_material(), 's');
+        assert_eq!(
+            field.material_type(),
+            MaterialType::ContinuingResources
+        );
+        assert_eq!(field.frequency(), Some('m'));
+        assert_eq!(field.regularity(), Some('r'));
+        assert_eq!(field.type_of_continuing_resource(), Some('p'));
+        assert_eq!(field.form_of_item(), Some(' '));
+        assert_eq!(field.nature_of_contents(), Some(&b"   "[..]));
+        assert_eq!(field.conference_publication(), Some('0'));
+
+        assert_eq!(field.illustrations(), None);
+        assert_eq!(field.target_audience(), None);
+        assert_eq!(field.literary_form(), None);
+
+        // The same positions as in the corresponding `008` field.
+        let leader = Leader::from_bytes(b"00827cas a2200241 c 4500")?;
+        let oo8 = Oo8::new(
+            b"751101c19759999nyumr p       0   a0eng d",
+            &leader,
+        )?;
+        assert_eq!(field.frequency(), oo8.frequency());
+        assert_eq!(
+            field.nature_of_contents(),
+            oo8.nature_of_contents()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oo6_computer_file() -> anyhow::Result<()> {
+        let field = Oo6::new(b"m    go  d f      ")?;
+
+        assert_eq!(field.material_type(), MaterialType::ComputerFiles);
+        assert_eq!(field.target_audience(), Some('g'));
+        assert_eq!(field.form_of_item(), Some('o'));
+        assert_eq!(field.type_of_computer_file(), Some('d'));
+        assert_eq!(field.government_publication(), Some('f'));
+
+        assert_eq!(field.frequency(), None);
+        assert_eq!(field.illustrations(), None);
+        assert_eq!(field.form_of_composition(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oo6_other() -> anyhow::Result<()> {
+        let field = Oo6::new(b"xabcdefghijklmnopq")?;
+        assert_eq!(field.material_type(), MaterialType::Other);
+        assert_eq!(field.data(), b"xabcdefghijklmnopq");
+        assert_eq!(field.form_of_item(), None);
+        assert_eq!(field.target_audience(), None);
+
+        assert_eq!(
+            Oo6::new(b"m"),
+            Err(ParseFixedFieldError::InvalidLength {
+                expected: 18,
+                actual: 1
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_category_of_material() {
+        for code in "acdfghkmoqrstvz".chars() {
+            assert_eq!(
+                char::from(CategoryOfMaterial::from(code)),
+                code
+            );
+        }
+
+        assert_eq!(
+            CategoryOfMaterial::from('x'),
+            CategoryOfMaterial::Other('x')
+        );
+    }
+
+    #[test]
+    fn test_oo7_sound_recording() -> anyhow::Result<()> {
+        let field = Oo7::new(b"sd fsngnnmmned")?;
+
+        assert_eq!(
+            field.category(),
+            CategoryOfMaterial::SoundRecording
+        );
+        assert_eq!(field.specific_material_designation(), Some('d'));
+        assert_eq!(field.speed(), Some('f'));
+        assert_eq!(field.playback_channels(), Some('s'));
+        assert_eq!(field.groove_width(), Some('n'));
+        assert_eq!(field.dimensions(), Some('g'));
+        assert_eq!(field.tape_width(), Some('n'));
+        assert_eq!(field.tape_configuration(), Some('n'));
+        assert_eq!(field.kind_of_disc(), Some('m'));
+        assert_eq!(field.kind_of_material(), Some('m'));
+        assert_eq!(field.kind_of_cutting(), Some('n'));
+        assert_eq!(field.special_playback_characteristics(), Some('e'));
+        assert_eq!(field.capture_and_storage_technique(), Some('d'));
+
+        assert_eq!(field.color(), None);
+        assert_eq!(field.videorecording_format(), None);
+        assert_eq!(field.reduction_ratio(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oo7_videorecording() -> anyhow::Result<()> {
+        let field = Oo7::new(b"vd cvaizq")?;
+
+        assert_eq!(
+            field.category(),
+            CategoryOfMaterial::Videorecording
+        );
+        assert_eq!(field.specific_material_designation(), Some('d'));
+        assert_eq!(field.color(), Some('c'));
+        assert_eq!(field.videorecording_format(), Some('v'));
+        assert_eq!(field.sound_on_medium(), Some('a'));
+        assert_eq!(field.medium_for_sound(), Some('i'));
+        assert_eq!(field.dimensions(), Some('z'));
+        assert_eq!(field.playback_channels(), Some('q'));
+
+        assert_eq!(field.speed(), None);
+        assert_eq!(field.generation(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oo7_microform() -> anyhow::Result<()> {
+        let field = Oo7::new(b"hd bfa024baca")?;
+
+        assert_eq!(field.category(), CategoryOfMaterial::Microform);
+        assert_eq!(field.positive_negative_aspect(), Some('b'));
+        assert_eq!(field.dimensions(), Some('f'));
+        assert_eq!(field.reduction_ratio_range(), Some('a'));
+        assert_eq!(field.reduction_ratio(), Some(&b"024"[..]));
+        assert_eq!(field.color(), Some('b'));
+        assert_eq!(field.emulsion_on_film(), Some('a'));
+        assert_eq!(field.generation(), Some('c'));
+        assert_eq!(field.base_of_film(), Some('a'));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oo7_other() -> anyhow::Result<()> {
+        let field = Oo7::new(b"xyz")?;
+        assert_eq!(field.category(), CategoryOfMaterial::Other('x'));
+        assert_eq!(field.data(), b"xyz");
+        assert_eq!(field.specific_material_designation(), Some('y'));
+        assert_eq!(field.dimensions(), None);
+
+        let field = Oo7::new(b"s")?;
+        assert_eq!(field.specific_material_designation(), None);
+        assert_eq!(field.speed(), None);
+
+        assert_eq!(
+            Oo7::new(b""),
+            Err(ParseFixedFieldError::MissingCategory)
+        );
+        Ok(())
+    }
+}
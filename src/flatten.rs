@@ -0,0 +1,248 @@
+//! Flattening of records into rows of selected values, e.g. for CSV
+//! or TSV export.
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::Record;
+
+/// A column of a [FlattenSpec].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Column {
+    tag: [u8; 3],
+    code: Option<u8>,
+    name: String,
+}
+
+/// The columns into which records are flattened.
+///
+/// Each column selects a control field by its tag or a subfield by the
+/// tag of its data field and a subfield code.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::flatten::FlattenSpec;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let spec = FlattenSpec::new()
+///         .column(b"001", None, "id")
+///         .column(b"245", Some(b'a'), "title");
+///
+///     assert_eq!(spec.names().collect::<Vec<_>>(), ["id", "title"]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlattenSpec {
+    columns: Vec<Column>,
+    separator: Option<String>,
+}
+
+impl FlattenSpec {
+    /// Creates a new spec without any columns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a column. Control fields are selected by their tag alone
+    /// and ignore the subfield code.
+    pub fn column(
+        mut self,
+        tag: &[u8; 3],
+        code: Option<u8>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.columns.push(Column {
+            tag: *tag,
+            code,
+            name: name.into(),
+        });
+        self
+    }
+
+    /// Joins all matching values with the separator, instead of taking
+    /// the first match only.
+    pub fn join_repeated(
+        mut self,
+        separator: impl Into<String>,
+    ) -> Self {
+        self.separator = Some(separator.into());
+        self
+    }
+
+    /// Returns the names of the columns, e.g. for a header row.
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.columns.iter().map(|column| column.name.as_str())
+    }
+}
+
+/// Flattens the record into one value per column of the spec.
+///
+/// A column is `None` if the record has no matching value. Otherwise
+/// it holds the first match or, if [FlattenSpec::join_repeated] is
+/// set, all matches joined by the separator. A data field column
+/// without a subfield code selects the subfield values of the field
+/// joined by a blank. Bytes which aren't valid UTF-8 are replaced by
+/// `U+FFFD`.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::flatten::{flatten_record, FlattenSpec};
+/// use marc21::Record;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let record = Record::from_bytes(
+///         b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d",
+///     )?;
+///
+///     let spec = FlattenSpec::new()
+///         .column(b"001", None, "id")
+///         .column(b"245", Some(b'a'), "title");
+///
+///     assert_eq!(
+///         flatten_record(&record, &spec),
+///         [Some("123456789".into()), None]
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+pub fn flatten_record(
+    record: &Record,
+    spec: &FlattenSpec,
+) -> Vec<Option<String>> {
+    spec.columns
+        .iter()
+        .map(|column| {
+            let mut values = values(record, column);
+
+            match spec.separator {
+                None => values.next(),
+                Some(ref separator) => {
+                    let first = values.next()?;
+                    Some(values.fold(first, |mut out, value| {
+                        out.push_str(separator);
+                        out.push_str(&value);
+                        out
+                    }))
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns all values of the record that match the column.
+fn values<'a>(
+    record: &'a Record,
+    column: &'a Column,
+) -> impl Iterator<Item = String> + 'a {
+    let control_fields = record
+        .control_fields
+        .iter()
+        .filter(|field| field.tag == column.tag)
+        .map(|field| String::from_utf8_lossy(&field.data).into_owned());
+
+    let data_fields = record
+        .data_fields
+        .iter()
+        .filter(|field| field.tag == column.tag)
+        .flat_map(move |field| {
+            let values: Vec<String> = match column.code {
+                Some(code) => field
+                    .subfields
+                    .iter()
+                    .filter(|subfield| subfield.code == code)
+                    .map(|subfield| {
+                        String::from_utf8_lossy(&subfield.value)
+                            .into_owned()
+                    })
+                    .collect(),
+                None => {
+                    let value = field
+                        .subfields
+                        .iter()
+                        .map(|subfield| {
+                            String::from_utf8_lossy(&subfield.value)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    vec![value]
+                }
+            };
+
+            values
+        });
+
+    control_fields.chain(data_fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RecordBuilder, Subfield};
+
+    #[test]
+    fn test_flatten_record() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [
+                    Subfield::new(b'a', "Arithmetic /"),
+                    Subfield::new(b'c', "Carl Sandburg."),
+                ],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Children's poetry")],
+            )
+            .build();
+
+        let spec = FlattenSpec::new()
+            .column(b"001", None, "id")
+            .column(b"245", Some(b'a'), "title")
+            .column(b"650", Some(b'a'), "subject");
+
+        assert_eq!(
+            flatten_record(&record, &spec),
+            [
+                Some("123456789".into()),
+                Some("Arithmetic /".into()),
+                Some("Arithmetic".into()),
+            ]
+        );
+
+        let spec = spec
+            .column(b"245", None, "statement")
+            .column(b"100", Some(b'a'), "author")
+            .join_repeated(";");
+
+        assert_eq!(
+            flatten_record(&record, &spec),
+            [
+                Some("123456789".into()),
+                Some("Arithmetic /".into()),
+                Some("Arithmetic;Children's poetry".into()),
+                Some("Arithmetic / Carl Sandburg.".into()),
+                None,
+            ]
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use alloc::vec::Vec;
+
+use crate::{MarcFormat, Record};
+
+/// The location of a holdings record, taken from the first location
+/// field (`852`).
+///
+/// The values are the raw subfield values.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HoldingsLocation<'a> {
+    /// The location, i.e. the holding institution (`$a`).
+    pub location: Option<&'a [u8]>,
+    /// The sublocations or collections (`$b`).
+    pub sublocation: Vec<&'a [u8]>,
+    /// The parts of the call number, i.e. the classification part
+    /// (`$h`) and the item parts (`$i`), in field order.
+    pub call_number: Vec<&'a [u8]>,
+}
+
+impl Record {
+    /// Returns the location of a holdings record, taken from the first
+    /// `852` field. Records of other formats (see [Leader::format])
+    /// and holdings records without an `852` field yield `None`.
+    ///
+    /// [Leader::format]: crate::Leader::format
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{
+    ///     LeaderBuilder, RecordBuilder, Subfield, TypeOfRecord,
+    /// };
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .leader(
+    ///             LeaderBuilder::new()
+    ///                 .type_of_record(
+    ///                     TypeOfRecord::SinglePartItemHoldings,
+    ///                 )
+    ///                 .build()?,
+    ///         )
+    ///         .data_field(
+    ///             b"852",
+    ///             b'0',
+    ///             b' ',
+    ///             [
+    ///                 Subfield::new(b'a', "DLC"),
+    ///                 Subfield::new(b'h', "PS3537.A618"),
+    ///                 Subfield::new(b'i', "A88 1993"),
+    ///             ],
+    ///         )
+    ///         .build();
+    ///
+    ///     let location = record.holdings_location().unwrap();
+    ///     assert_eq!(location.location, Some(&b"DLC"[..]));
+    ///     assert_eq!(
+    ///         location.call_number,
+    ///         [&b"PS3537.A618"[..], b"A88 1993"]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn holdings_location(&self) -> Option<HoldingsLocation<'_>> {
+        if self.leader.format() != MarcFormat::Holdings {
+            return None;
+        }
+
+        let field = self.fields(b"852").next()?;
+        let values = |codes: &'static [u8]| {
+            field
+                .subfields
+                .iter()
+                .filter(move |subfield| codes.contains(&subfield.code))
+                .map(|subfield| subfield.value())
+        };
+
+        Some(HoldingsLocation {
+            location: values(b"a").next(),
+            sublocation: values(b"b").collect(),
+            call_number: values(b"hi").collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LeaderBuilder, RecordBuilder, Subfield, TypeOfRecord};
+
+    #[test]
+    fn test_holdings_location() -> anyhow::Result<()> {
+        let subfields = [
+            Subfield::new(b'a', "DE-101"),
+            Subfield::new(b'b', "Lesesaal"),
+            Subfield::new(b'b', "Magazin"),
+            Subfield::new(b'h', "QA 39.2"),
+            Subfield::new(b'i', ".S26"),
+            Subfield::new(b'z', "Ask at the desk"),
+        ];
+
+        let record = RecordBuilder::new()
+            .leader(
+                LeaderBuilder::new()
+                    .type_of_record(TypeOfRecord::SerialItemHoldings)
+                    .build()?,
+            )
+            .control_field(b"001", "123456789")
+            .data_field(b"852", b'8', b' ', subfields.clone())
+            .data_field(
+                b"852",
+                b'8',
+                b' ',
+                [Subfield::new(b'a', "Other")],
+            )
+            .build();
+
+        assert_eq!(
+            record.holdings_location(),
+            Some(HoldingsLocation {
+                location: Some(b"DE-101"),
+                sublocation: vec![b"Lesesaal", b"Magazin"],
+                call_number: vec![b"QA 39.2", b".S26"],
+            })
+        );
+
+        let record = RecordBuilder::new()
+            .leader(
+                LeaderBuilder::new()
+                    .type_of_record(TypeOfRecord::LanguageMaterial)
+                    .build()?,
+            )
+            .data_field(b"852", b'8', b' ', subfields)
+            .build();
+        assert_eq!(record.holdings_location(), None);
+
+        let record = RecordBuilder::new()
+            .leader(
+                LeaderBuilder::new()
+                    .type_of_record(TypeOfRecord::UnknownHoldings)
+                    .build()?,
+            )
+            .build();
+        assert_eq!(record.holdings_location(), None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::Record;
+
+/// Strips a trailing parenthesized qualifier (e.g. `(pbk.)`) and the
+/// surrounding blanks from an identifier.
+fn strip_qualifier(value: &[u8]) -> &[u8] {
+    let end =
+        value.iter().position(|b| *b == b'(').unwrap_or(value.len());
+    value[..end].trim_ascii()
+}
+
+impl Record {
+    /// Returns an iterator over the ISBNs of the record, i.e. the
+    /// values of the `020 $a` subfields.
+    ///
+    /// Qualifying information given in parentheses (e.g. `(pbk.)`)
+    /// is stripped, as well as surrounding blanks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"020",
+    ///             b' ',
+    ///             b' ',
+    ///             [Subfield::new(b'a', "0152038655 (pbk.)")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let isbns: Vec<_> = record.isbns().collect();
+    ///     assert_eq!(isbns, [b"0152038655"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn isbns(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.subfield_values(b"020", b'a').map(strip_qualifier)
+    }
+
+    /// Returns an iterator over the ISSNs of the record, i.e. the
+    /// values of the `022 $a` subfields.
+    ///
+    /// Qualifying information given in parentheses is stripped, as
+    /// well as surrounding blanks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"022",
+    ///             b'0',
+    ///             b' ',
+    ///             [Subfield::new(b'a', "0028-0836")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let issns: Vec<_> = record.issns().collect();
+    ///     assert_eq!(issns, [b"0028-0836"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn issns(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        self.subfield_values(b"022", b'a').map(strip_qualifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RecordBuilder, Subfield};
+
+    #[test]
+    fn test_identifiers() {
+        let record = RecordBuilder::new()
+            .data_field(
+                b"020",
+                b' ',
+                b' ',
+                [
+                    Subfield::new(b'a', "9780152038656"),
+                    Subfield::new(b'q', "hardcover"),
+                ],
+            )
+            .data_field(
+                b"020",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "0152038655 (pbk. : alk. paper)")],
+            )
+            .data_field(
+                b"020",
+                b' ',
+                b' ',
+                [Subfield::new(b'z', "0152038650")],
+            )
+            .data_field(
+                b"022",
+                b'0',
+                b' ',
+                [
+                    Subfield::new(b'a', "0028-0836"),
+                    Subfield::new(b'y', "0028-0835"),
+                ],
+            )
+            .build();
+
+        let isbns: Vec<_> = record.isbns().collect();
+        assert_eq!(isbns, [&b"9780152038656"[..], &b"0152038655"[..]]);
+
+        let issns: Vec<_> = record.issns().collect();
+        assert_eq!(issns, [b"0028-0836"]);
+
+        assert_eq!(strip_qualifier(b" (pbk.)"), b"");
+        assert_eq!(strip_qualifier(b""), b"");
+    }
+}
@@ -0,0 +1,181 @@
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::record::tag_matches;
+use crate::{DataField, Record};
+
+/// An index of the data fields of a [Record] by tag, created by
+/// [Record::index].
+///
+/// [Record::fields] scans all data fields of the record on every call,
+/// which is cheap for one-shot lookups. A record which is queried many
+/// times is better indexed once, such that each lookup only visits the
+/// matching fields.
+///
+/// The index borrows the record, i.e. the record can't be modified
+/// while the index is alive.
+///
+/// The tags are kept in a [BTreeMap] rather than a hash map, since the
+/// crate supports `no_std` targets with `alloc` only, where no hash
+/// map is available without an additional dependency. With the few
+/// distinct tags of a record, the logarithmic lookup is on par with
+/// hashing.
+#[derive(Debug, Clone)]
+pub struct FieldIndex<'a> {
+    record: &'a Record,
+    tags: BTreeMap<[u8; 3], Vec<usize>>,
+}
+
+impl<'a> FieldIndex<'a> {
+    /// Returns an iterator over all data fields with the given tag, in
+    /// directory order; see [Record::fields]. A tag without an `X` is
+    /// looked up directly, while a tag with an `X` (matching any
+    /// character) visits all distinct tags of the record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"650",
+    ///             b' ',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Arithmetic")],
+    ///         )
+    ///         .data_field(
+    ///             b"650",
+    ///             b' ',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Poetry")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let index = record.index();
+    ///     assert_eq!(index.fields(b"650").count(), 2);
+    ///     assert_eq!(index.fields(b"245").count(), 0);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn fields(
+        &self,
+        tag: impl AsRef<[u8]>,
+    ) -> impl Iterator<Item = &'a DataField> + '_ {
+        let record = self.record;
+        let tag = tag.as_ref();
+        let wildcard = tag.contains(&b'X');
+
+        let exact = <[u8; 3]>::try_from(tag)
+            .ok()
+            .filter(|_| !wildcard)
+            .and_then(|tag| self.tags.get(&tag));
+
+        let mut matches: Vec<usize> = vec![];
+        if wildcard {
+            matches.extend(
+                self.tags
+                    .iter()
+                    .filter(|(key, _)| tag_matches(tag, key))
+                    .flat_map(|(_, indices)| indices.iter().copied()),
+            );
+            matches.sort_unstable();
+        }
+
+        exact
+            .into_iter()
+            .flatten()
+            .copied()
+            .chain(matches)
+            .map(move |idx| &record.data_fields[idx])
+    }
+
+    /// Returns `true` if the record has a data field with the given
+    /// tag. An `X` in the tag matches any character.
+    pub fn contains(&self, tag: impl AsRef<[u8]>) -> bool {
+        self.fields(tag).next().is_some()
+    }
+}
+
+impl Record {
+    /// Builds an index of the data fields by tag, which speeds up
+    /// repeated lookups; see [FieldIndex].
+    pub fn index(&self) -> FieldIndex<'_> {
+        let mut tags: BTreeMap<[u8; 3], Vec<usize>> = BTreeMap::new();
+        for (idx, field) in self.data_fields.iter().enumerate() {
+            tags.entry(field.tag).or_default().push(idx);
+        }
+
+        FieldIndex { record: self, tags }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RecordBuilder, Subfield};
+
+    #[test]
+    fn test_index() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Title")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .data_field(
+                b"500",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "Note")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .build();
+
+        let index = record.index();
+        for tag in [
+            &b"245"[..],
+            b"500",
+            b"650",
+            b"700",
+            b"001",
+            b"65",
+            b"6XX",
+            b"X5X",
+            b"XXX",
+        ] {
+            assert!(index.fields(tag).eq(record.fields(tag)));
+            assert_eq!(
+                index.contains(tag),
+                record.fields(tag).next().is_some()
+            );
+        }
+
+        let subjects: Vec<_> = index
+            .fields(b"650")
+            .flat_map(|field| field.subfield(b'a'))
+            .map(Subfield::value)
+            .collect();
+        assert_eq!(subjects, [&b"Arithmetic"[..], &b"Poetry"[..]]);
+
+        let record = RecordBuilder::new().build();
+        assert_eq!(record.index().fields(b"245").count(), 0);
+    }
+}
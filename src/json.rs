@@ -0,0 +1,657 @@
+use std::io::{self, Read};
+
+use serde_json::{json, Map, Value};
+
+use crate::record::FieldOrder;
+use crate::{
+    ControlField, DataField, Field, Leader, MarcReader,
+    ParseLeaderError, ParseOptions, ParseRecordError, Record, Subfield,
+};
+
+/// An error that can occur when parsing MARC-in-JSON.
+#[derive(Debug, thiserror::Error)]
+pub enum ParseMarcJsonError {
+    #[error("invalid leader: {0}")]
+    Leader(#[from] ParseLeaderError),
+
+    #[error("missing or invalid leader")]
+    MissingLeader,
+
+    #[error("missing or invalid fields")]
+    InvalidFields,
+
+    #[error("invalid field")]
+    InvalidField,
+
+    #[error("invalid indicator")]
+    InvalidIndicator,
+
+    #[error("invalid subfield")]
+    InvalidSubfield,
+}
+
+/// Converts `value` into a (lossy) UTF-8 JSON string.
+#[inline]
+fn string(value: &[u8]) -> Value {
+    Value::String(String::from_utf8_lossy(value).into_owned())
+}
+
+impl Record {
+    /// Converts the record into its MARC-in-JSON representation.
+    ///
+    /// Each field is represented as a single-key object inside the
+    /// `fields` array, which preserves the order (see
+    /// [Record::all_fields]) and repetition of fields and subfields.
+    /// Bytes which aren't valid UTF-8 are replaced by `U+FFFD`.
+    ///
+    /// This method requires the `serde` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let value = record.to_marc_json();
+    ///     assert_eq!(value["leader"], "00048nam a2200037 c 4500");
+    ///     assert_eq!(value["fields"][0]["001"], "123456789");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_marc_json(&self) -> Value {
+        let mut fields = vec![];
+
+        for field in self.all_fields() {
+            let value = match field {
+                Field::Control(field) => string(&field.data),
+                Field::Data(field) => {
+                    let subfields = field
+                        .subfields
+                        .iter()
+                        .map(|subfield| {
+                            let mut object = Map::new();
+                            object.insert(
+                                String::from_utf8_lossy(&[
+                                    subfield.code
+                                ])
+                                .into_owned(),
+                                string(&subfield.value),
+                            );
+                            Value::Object(object)
+                        })
+                        .collect::<Vec<_>>();
+
+                    json!({
+                        "ind1": string(&[field.ind1]),
+                        "ind2": string(&[field.ind2]),
+                        "subfields": subfields,
+                    })
+                }
+            };
+
+            let mut object = Map::new();
+            object.insert(
+                String::from_utf8_lossy(field.tag()).into_owned(),
+                value,
+            );
+            fields.push(Value::Object(object));
+        }
+
+        json!({
+            "leader": string(&self.leader.to_bytes()),
+            "fields": fields,
+        })
+    }
+
+    /// Creates a record from its MARC-in-JSON representation.
+    ///
+    /// Control fields are represented by a string value, data fields
+    /// by an object with the indicators and an ordered array of
+    /// subfields. Missing indicators default to a blank. The fields
+    /// keep the order of the input, and the record length and the
+    /// base address of data of the leader are recomputed from the
+    /// fields.
+    ///
+    /// This method requires the `serde` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    /// use serde_json::json;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_marc_json(&json!({
+    ///         "leader": "00048nam a2200037 c 4500",
+    ///         "fields": [{ "001": "123456789" }],
+    ///     }))?;
+    ///
+    ///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_marc_json(
+        value: &Value,
+    ) -> Result<Self, ParseMarcJsonError> {
+        let leader = value
+            .get("leader")
+            .and_then(Value::as_str)
+            .ok_or(ParseMarcJsonError::MissingLeader)?;
+        let leader = Leader::from_bytes(leader.as_bytes())?;
+
+        let mut control_fields = vec![];
+        let mut data_fields = vec![];
+        let mut kinds = vec![];
+
+        let fields = value
+            .get("fields")
+            .and_then(Value::as_array)
+            .ok_or(ParseMarcJsonError::InvalidFields)?;
+
+        for field in fields.iter() {
+            let (tag, value) = single_entry(field)
+                .ok_or(ParseMarcJsonError::InvalidField)?;
+            let tag: [u8; 3] = tag
+                .as_bytes()
+                .try_into()
+                .map_err(|_| ParseMarcJsonError::InvalidField)?;
+
+            match value {
+                Value::String(data) => {
+                    control_fields
+                        .push(ControlField::new(tag, data.as_str()));
+                    kinds.push(true);
+                }
+                Value::Object(object) => {
+                    let subfields = object
+                        .get("subfields")
+                        .and_then(Value::as_array)
+                        .ok_or(ParseMarcJsonError::InvalidField)?
+                        .iter()
+                        .map(parse_subfield)
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    data_fields.push(DataField {
+                        tag,
+                        ind1: parse_indicator(object.get("ind1"))?,
+                        ind2: parse_indicator(object.get("ind2"))?,
+                        subfields,
+                    });
+                    kinds.push(false);
+                }
+                _ => return Err(ParseMarcJsonError::InvalidField),
+            }
+        }
+
+        let mut record = Record {
+            leader,
+            control_fields,
+            data_fields,
+            length_overflowed: false,
+            missing_terminator: false,
+            original: Default::default(),
+            directory: Default::default(),
+            field_order: FieldOrder::new(kinds),
+        };
+
+        // The record length and the base address of data of the
+        // `leader` needn't match the fields.
+        record.update_leader();
+        Ok(record)
+    }
+}
+
+/// A writer that streams records as JSON Lines (NDJSON) into an
+/// underlying [io::Write], one MARC-in-JSON object per line.
+///
+/// Each record is written as soon as it's passed to the writer, such
+/// that the memory usage doesn't grow with the number of records.
+///
+/// This type requires the `serde` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{MarcJsonLinesWriter, Record};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let record = Record::from_bytes(
+///         b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d",
+///     )?;
+///
+///     let mut writer = MarcJsonLinesWriter::new(Vec::new());
+///     writer.write_record(&record)?;
+///
+///     let output = String::from_utf8(writer.into_inner())?;
+///     assert_eq!(
+///         output,
+///         "{\"fields\":[{\"001\":\"123456789\"}],\
+///         \"leader\":\"00048nam a2200037 c 4500\"}\n"
+///     );
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MarcJsonLinesWriter<W: io::Write> {
+    inner: W,
+}
+
+impl<W: io::Write> MarcJsonLinesWriter<W> {
+    /// Creates a new writer over the given [io::Write].
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Writes the MARC-in-JSON object of the record followed by a
+    /// newline.
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        serde_json::to_writer(&mut self.inner, &record.to_marc_json())?;
+        self.inner.write_all(b"\n")
+    }
+
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    /// Unwraps the writer, returning the underlying [io::Write].
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Converts the binary records read from `reader` into MARC-in-JSON
+/// lines (NDJSON) written to `writer`, and returns the number of
+/// records converted.
+///
+/// The records are read and written one at a time (see [MarcReader]
+/// and [MarcJsonLinesWriter]). In lenient mode, records which can't be
+/// parsed are skipped and the conversion continues with the next
+/// record; otherwise, the first such record aborts the conversion. I/O
+/// errors always abort the conversion, as do an invalid record length
+/// and a truncated record, after which the start of the next record
+/// is unknown.
+///
+/// This function requires the `serde` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::Cursor;
+///
+/// use marc21::ParseOptions;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let data = Cursor::new(
+///         b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d",
+///     );
+///
+///     let mut output = Vec::new();
+///     let count = marc21::convert_to_ndjson(
+///         data,
+///         &mut output,
+///         &ParseOptions::default(),
+///     )?;
+///
+///     assert_eq!(count, 1);
+///     assert!(output.ends_with(b"\"}\n"));
+///     Ok(())
+/// }
+/// ```
+pub fn convert_to_ndjson<R: Read, W: io::Write>(
+    reader: R,
+    writer: W,
+    options: &ParseOptions,
+) -> Result<usize, ParseRecordError> {
+    let mut writer = MarcJsonLinesWriter::new(writer);
+    let mut count = 0;
+
+    for result in MarcReader::with_options(reader, options) {
+        match result {
+            Ok(record) => {
+                writer.write_record(&record)?;
+                count += 1;
+            }
+            Err(e) if options.lenient && !desynchronizes(&e) => (),
+            Err(e) => return Err(e),
+        }
+    }
+
+    writer.flush()?;
+    Ok(count)
+}
+
+/// Returns `true` if the reader loses track of the record boundaries
+/// after the error, such that the following records can't be read.
+#[inline]
+fn desynchronizes(e: &ParseRecordError) -> bool {
+    matches!(
+        e,
+        ParseRecordError::Io(_)
+            | ParseRecordError::Truncated
+            | ParseRecordError::Leader {
+                source: ParseLeaderError::InvalidRecordLength,
+                ..
+            }
+    )
+}
+
+/// Returns the key and value of an object with exactly one entry.
+#[inline]
+fn single_entry(value: &Value) -> Option<(&String, &Value)> {
+    match value.as_object() {
+        Some(object) if object.len() == 1 => object.iter().next(),
+        _ => None,
+    }
+}
+
+/// Parses an indicator, which defaults to a blank if missing.
+fn parse_indicator(
+    value: Option<&Value>,
+) -> Result<u8, ParseMarcJsonError> {
+    match value.map(|value| value.as_str().map(str::as_bytes)) {
+        None | Some(Some([])) => Ok(b' '),
+        Some(Some([ind])) => Ok(*ind),
+        _ => Err(ParseMarcJsonError::InvalidIndicator),
+    }
+}
+
+/// Parses a subfield, which is an object with a single entry mapping
+/// the subfield code to the value.
+fn parse_subfield(
+    value: &Value,
+) -> Result<Subfield, ParseMarcJsonError> {
+    match single_entry(value) {
+        Some((code, Value::String(value))) => match code.as_bytes() {
+            [code] => Ok(Subfield::new(*code, value.as_str())),
+            _ => Err(ParseMarcJsonError::InvalidSubfield),
+        },
+        _ => Err(ParseMarcJsonError::InvalidSubfield),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_marc_json() -> anyhow::Result<()> {
+        let record = Record::from_bytes(
+            b"00122nam a2200061 c 450000100100000000300070001024500430\
+            0017\x1e123456789\x1eDE-101\x1e10\x1faArithmetic\
+            \x20<poems> /\x1fcSandburg & Rand.\x1e\x1d",
+        )?;
+
+        assert_eq!(
+            record.to_marc_json(),
+            json!({
+                "leader": "00122nam a2200061 c 4500",
+                "fields": [
+                    { "001": "123456789" },
+                    { "003": "DE-101" },
+                    {
+                        "245": {
+                            "ind1": "1",
+                            "ind2": "0",
+                            "subfields": [
+                                { "a": "Arithmetic <poems> /" },
+                                { "c": "Sandburg & Rand." },
+                            ]
+                        }
+                    }
+                ]
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_marc_json_field_order() -> anyhow::Result<()> {
+        let record = Record::from_bytes(
+            b"00067nam a2200049 c 4500245001000000008000700010\x1e\
+            00\x1faTitle\x1e740101\x1e\x1d",
+        )?;
+
+        let value = record.to_marc_json();
+        assert_eq!(
+            value["fields"][0]["245"]["subfields"][0]["a"],
+            "Title"
+        );
+        assert_eq!(value["fields"][1]["008"], "740101");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_marc_json() -> anyhow::Result<()> {
+        let record = Record::from_marc_json(&json!({
+            "leader": "00122nam a2200061 c 4500",
+            "fields": [
+                { "001": "123456789" },
+                { "245": {
+                    "ind1": "1",
+                    "subfields": [
+                        { "a": "Arithmetic /" },
+                        { "a": "Poems" },
+                    ]
+                } }
+            ]
+        }))?;
+
+        assert_eq!(
+            record.control_fields,
+            vec![ControlField::new(b"001", "123456789")]
+        );
+        assert_eq!(
+            record.data_fields,
+            vec![DataField {
+                tag: *b"245",
+                ind1: b'1',
+                ind2: b' ',
+                subfields: vec![
+                    Subfield::new(b'a', "Arithmetic /"),
+                    Subfield::new(b'a', "Poems"),
+                ]
+            }]
+        );
+
+        // The record length and the base address of data are
+        // recomputed from the fields.
+        assert_eq!(record.leader().record_length(), 84);
+        assert_eq!(record.leader().base_address_of_data(), 49);
+        assert_eq!(record.to_bytes().len(), 84);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_marc_json_roundtrip() -> anyhow::Result<()> {
+        let record = Record::from_bytes(include_bytes!(
+            "../tests/data/arithmetic.mrc"
+        ))?;
+
+        assert_eq!(
+            Record::from_marc_json(&record.to_marc_json())?,
+            record
+        );
+
+        let record = Record::from_bytes(
+            b"00067nam a2200049 c 4500245001000000008000700010\x1e\
+            00\x1faTitle\x1e740101\x1e\x1d",
+        )?;
+        let other = Record::from_marc_json(&record.to_marc_json())?;
+        assert_eq!(other.to_bytes(), record.to_bytes());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_marc_json_invalid() {
+        assert!(matches!(
+            Record::from_marc_json(&json!({ "fields": [] })),
+            Err(ParseMarcJsonError::MissingLeader)
+        ));
+        assert!(matches!(
+            Record::from_marc_json(&json!({ "leader": "00122nam" })),
+            Err(ParseMarcJsonError::Leader(_))
+        ));
+        assert!(matches!(
+            Record::from_marc_json(&json!({
+                "leader": "00122nam a2200061 c 4500",
+            })),
+            Err(ParseMarcJsonError::InvalidFields)
+        ));
+        assert!(matches!(
+            Record::from_marc_json(&json!({
+                "leader": "00122nam a2200061 c 4500",
+                "fields": [{ "001": 1 }]
+            })),
+            Err(ParseMarcJsonError::InvalidField)
+        ));
+        assert!(matches!(
+            Record::from_marc_json(&json!({
+                "leader": "00122nam a2200061 c 4500",
+                "fields": [{ "245": { "ind1": "10", "subfields": [] } }]
+            })),
+            Err(ParseMarcJsonError::InvalidIndicator)
+        ));
+        assert!(matches!(
+            Record::from_marc_json(&json!({
+                "leader": "00122nam a2200061 c 4500",
+                "fields": [{ "245": { "subfields": [{ "ab": "x" }] } }]
+            })),
+            Err(ParseMarcJsonError::InvalidSubfield)
+        ));
+    }
+
+    #[test]
+    fn test_marc_json_lines_writer() -> anyhow::Result<()> {
+        let data = b"00048nam a2200037 c 4500001001000000\x1e\
+            123456789\x1e\x1d00053nam a2200037 c 4500245001500000\x1e\
+            00\x1faArithmetic\x1e\x1d00048nam a2200037 c 4500001001000000\
+            \x1e987654321\x1e\x1d";
+
+        let mut writer = MarcJsonLinesWriter::new(Vec::new());
+        for record in crate::records(data) {
+            writer.write_record(&record?)?;
+        }
+        writer.flush()?;
+
+        let output = String::from_utf8(writer.into_inner())?;
+        assert!(output.ends_with('\n'));
+
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(output.matches('\n').count(), 3);
+
+        for (line, record) in lines.iter().zip(crate::records(data)) {
+            let value: Value = serde_json::from_str(line)?;
+            assert_eq!(Record::from_marc_json(&value)?, record?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_to_ndjson() -> anyhow::Result<()> {
+        let data = include_bytes!("../tests/data/two_records.mrc");
+        let record = &data[..48];
+
+        let mut invalid = record.to_vec();
+        invalid[41] = 0x1e;
+
+        let mut output = Vec::new();
+        let count = convert_to_ndjson(
+            io::Cursor::new(data),
+            &mut output,
+            &ParseOptions::default(),
+        )?;
+        assert_eq!(count, 2);
+
+        let output = String::from_utf8(output)?;
+        let lines: Vec<_> = output.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            serde_json::from_str::<Value>(lines[0])?,
+            json!({
+                "leader": "00048nam a2200037 c 4500",
+                "fields": [{ "001": "123456789" }]
+            })
+        );
+        assert_eq!(
+            Record::from_marc_json(&serde_json::from_str(lines[1])?)?
+                .first_subfield_value(b"245", b'a'),
+            Some(&b"Arithmetic"[..])
+        );
+
+        let data = [record, &invalid, record].concat();
+        assert!(matches!(
+            convert_to_ndjson(
+                io::Cursor::new(&data),
+                io::sink(),
+                &ParseOptions::default()
+            ),
+            Err(ParseRecordError::InvalidField { position: 85 })
+        ));
+
+        let mut output = Vec::new();
+        let count = convert_to_ndjson(
+            io::Cursor::new(&data),
+            &mut output,
+            &ParseOptions {
+                lenient: true,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(count, 2);
+        assert_eq!(output.iter().filter(|b| **b == b'\n').count(), 2);
+
+        // An invalid record length stops the conversion even in
+        // lenient mode, since the next record can't be located.
+        let data =
+            [record, b"0x048nam a2200037 c 4500", record].concat();
+        let mut output = Vec::new();
+        assert!(matches!(
+            convert_to_ndjson(
+                io::Cursor::new(&data),
+                &mut output,
+                &ParseOptions {
+                    lenient: true,
+                    ..Default::default()
+                },
+            ),
+            Err(ParseRecordError::Leader {
+                source: ParseLeaderError::InvalidRecordLength,
+                position: 48
+            })
+        ));
+        assert_eq!(output.iter().filter(|b| **b == b'\n').count(), 1);
+
+        let data = [record, &record[..30]].concat();
+        assert!(matches!(
+            convert_to_ndjson(
+                io::Cursor::new(&data),
+                io::sink(),
+                &ParseOptions {
+                    lenient: true,
+                    ..Default::default()
+                },
+            ),
+            Err(ParseRecordError::Truncated)
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use alloc::vec::Vec;
+
+use crate::Record;
+
+/// Returns `true` if the code is blank or consists of fill characters,
+/// i.e. doesn't name a language.
+fn is_undefined(code: &[u8; 3]) -> bool {
+    code == b"   " || code == b"|||"
+}
+
+impl Record {
+    /// Returns the language codes of the record: the language of the
+    /// `008` field (positions 35-37) followed by the codes of the
+    /// `041 $a` (language of text) and `$d` (language of sung or
+    /// spoken text) subfields, without duplicates and in the order
+    /// they first appear.
+    ///
+    /// Blank and fill codes are skipped. Subfields with several
+    /// concatenated codes (e.g. `engfre`), as found in older records,
+    /// are split into the individual codes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .control_field(
+    ///             b"008",
+    ///             "850423s1985    nyua   j      000 1 eng d",
+    ///         )
+    ///         .data_field(
+    ///             b"041",
+    ///             b'1',
+    ///             b' ',
+    ///             [
+    ///                 Subfield::new(b'a', "eng"),
+    ///                 Subfield::new(b'h', "rus"),
+    ///             ],
+    ///         )
+    ///         .build();
+    ///
+    ///     assert_eq!(record.languages(), [*b"eng"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn languages(&self) -> Vec<[u8; 3]> {
+        let primary = self
+            .control_fields_by_tag(b"008")
+            .next()
+            .and_then(|field| field.data().get(35..38));
+        let codes = self
+            .fields(b"041")
+            .flat_map(|field| field.subfields())
+            .filter(|subfield| matches!(subfield.code(), b'a' | b'd'))
+            .flat_map(|subfield| subfield.value().chunks_exact(3));
+
+        primary.into_iter().chain(codes).fold(
+            Vec::new(),
+            |mut languages, code| {
+                let code: [u8; 3] = code.try_into().unwrap();
+                if !is_undefined(&code) && !languages.contains(&code) {
+                    languages.push(code);
+                }
+                languages
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{RecordBuilder, Subfield};
+
+    #[test]
+    fn test_languages() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .control_field(
+                b"008",
+                "850423s1985    gw            000 0 eng d",
+            )
+            .data_field(
+                b"041",
+                b'1',
+                b' ',
+                [
+                    Subfield::new(b'a', "eng"),
+                    Subfield::new(b'a', "fre"),
+                    Subfield::new(b'h', "lat"),
+                    Subfield::new(b'd', "ger"),
+                ],
+            )
+            .data_field(
+                b"041",
+                b'0',
+                b' ',
+                [Subfield::new(b'a', "fregerita")],
+            )
+            .build();
+
+        assert_eq!(
+            record.languages(),
+            [*b"eng", *b"fre", *b"ger", *b"ita"]
+        );
+
+        let record = RecordBuilder::new()
+            .control_field(
+                b"008",
+                "850423s1985    gw            000 0     d",
+            )
+            .data_field(
+                b"041",
+                b'0',
+                b' ',
+                [Subfield::new(b'a', "fre")],
+            )
+            .build();
+        assert_eq!(record.languages(), [*b"fre"]);
+
+        let record = RecordBuilder::new()
+            .control_field(b"008", "850423s1985")
+            .build();
+        assert!(record.languages().is_empty());
+    }
+}
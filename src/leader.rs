@@ -1,31 +1,595 @@
-use nom::character::complete::{one_of, satisfy};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Write};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+
+use nom::character::complete::satisfy;
+use nom::combinator::map;
 use nom::error::{FromExternalError, ParseError};
 use nom::multi::fold_many_m_n;
-use nom::{Finish, IResult};
+use nom::sequence::tuple;
+use nom::{Finish, IResult, Needed};
+
+/// The length of the leader in bytes/octets.
+pub(crate) const LEADER_LEN: usize = 24;
+
+/// The maximum record length that can be encoded in the leader.
+pub(crate) const MAX_RECORD_LEN: u32 = 99_999;
 
 /// The leader contains information for the processing of the record.
-#[derive(Debug, PartialEq, Eq)]
+///
+/// The leader is a small, allocation-free value of fixed size, which
+/// can be copied freely.
+///
+/// Two leaders are equal if they agree in all positions except the
+/// record length (00-04) and the base address of data (12-16), which
+/// depend on the serialization of the record rather than its content.
+/// Compare [Leader::to_bytes] to take them into account.
+#[derive(Clone, Copy)]
 pub struct Leader {
+    /// The 24 bytes/octets of the leader. The code positions are read
+    /// from here, the numeric positions are kept in sync with the
+    /// fields below.
+    pub(crate) raw: [u8; LEADER_LEN],
     pub(crate) record_len: u32,
-    pub(crate) record_status: char,
+    pub(crate) indicator_count: u8,
+    pub(crate) subfield_code_count: u8,
+    pub(crate) base_address: u32,
+    pub(crate) entry_map: EntryMap,
+}
+
+impl PartialEq for Leader {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw[5..12] == other.raw[5..12]
+            && self.raw[17..] == other.raw[17..]
+    }
+}
+
+impl Eq for Leader {}
+
+impl Hash for Leader {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Consistent with `PartialEq`, the record length and the base
+        // address of data are left out.
+        self.raw[5..12].hash(state);
+        self.raw[17..].hash(state);
+    }
+}
+
+/// Returns `true` if the leader position depends on the serialization
+/// of the record, i.e. belongs to the record length (00-04) or the base
+/// address of data (12-16).
+#[inline]
+pub(crate) fn is_volatile_position(position: usize) -> bool {
+    matches!(position, 0..=4 | 12..=16)
+}
+
+/// The entry map (leader positions 20-22) describes the structure of
+/// the entries in the directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EntryMap {
+    /// The number of characters in the length-of-field portion of
+    /// each directory entry (position 20).
+    pub length_of_field_portion: u8,
+    /// The number of characters in the starting-character-position
+    /// portion of each directory entry (position 21).
+    pub starting_character_position_portion: u8,
+    /// The number of characters in the implementation-defined portion
+    /// of each directory entry (position 22).
+    pub implementation_defined_portion: u8,
+}
+
+impl EntryMap {
+    /// Returns the length of a directory entry, i.e. the tag plus
+    /// the lengths of the three portions.
+    #[inline]
+    pub(crate) fn entry_len(&self) -> usize {
+        3 + self.length_of_field_portion as usize
+            + self.starting_character_position_portion as usize
+            + self.implementation_defined_portion as usize
+    }
+}
+
+/// The status of the record (leader position 05).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecordStatus {
+    /// Increase in encoding level (`a`).
+    Increase,
+    /// Corrected or revised (`c`).
+    Corrected,
+    /// Deleted (`d`).
+    Deleted,
+    /// New (`n`).
+    New,
+    /// Increase in encoding level from prepublication (`p`).
+    IncreaseFromPrepublication,
+    /// Any other (non-standard or obsolete) status code.
+    Other(char),
+}
+
+impl From<char> for RecordStatus {
+    fn from(code: char) -> Self {
+        match code {
+            'a' => Self::Increase,
+            'c' => Self::Corrected,
+            'd' => Self::Deleted,
+            'n' => Self::New,
+            'p' => Self::IncreaseFromPrepublication,
+            _ => Self::Other(code),
+        }
+    }
+}
+
+impl From<RecordStatus> for char {
+    fn from(status: RecordStatus) -> Self {
+        match status {
+            RecordStatus::Increase => 'a',
+            RecordStatus::Corrected => 'c',
+            RecordStatus::Deleted => 'd',
+            RecordStatus::New => 'n',
+            RecordStatus::IncreaseFromPrepublication => 'p',
+            RecordStatus::Other(code) => code,
+        }
+    }
+}
+
+/// The type of the record (leader position 06).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TypeOfRecord {
+    /// Language material (`a`).
+    LanguageMaterial,
+    /// Notated music (`c`).
+    NotatedMusic,
+    /// Manuscript notated music (`d`).
+    ManuscriptNotatedMusic,
+    /// Cartographic material (`e`).
+    CartographicMaterial,
+    /// Manuscript cartographic material (`f`).
+    ManuscriptCartographicMaterial,
+    /// Projected medium (`g`).
+    ProjectedMedium,
+    /// Nonmusical sound recording (`i`).
+    NonmusicalSoundRecording,
+    /// Musical sound recording (`j`).
+    MusicalSoundRecording,
+    /// Two-dimensional nonprojectable graphic (`k`).
+    TwoDimensionalNonprojectableGraphic,
+    /// Computer file (`m`).
+    ComputerFile,
+    /// Kit (`o`).
+    Kit,
+    /// Mixed materials (`p`).
+    MixedMaterials,
+    /// Community information (`q`).
+    CommunityInformation,
+    /// Three-dimensional artifact or naturally occurring object
+    /// (`r`).
+    ThreeDimensionalArtifact,
+    /// Manuscript language material (`t`).
+    ManuscriptLanguageMaterial,
+    /// Unknown holdings (`u`).
+    UnknownHoldings,
+    /// Multipart item holdings (`v`).
+    MultipartItemHoldings,
+    /// Classification data (`w`).
+    ClassificationData,
+    /// Single-part item holdings (`x`).
+    SinglePartItemHoldings,
+    /// Serial item holdings (`y`).
+    SerialItemHoldings,
+    /// Authority data (`z`).
+    AuthorityData,
+    /// Any other (non-standard) type code.
+    Other(char),
+}
+
+impl From<char> for TypeOfRecord {
+    fn from(code: char) -> Self {
+        match code {
+            'a' => Self::LanguageMaterial,
+            'c' => Self::NotatedMusic,
+            'd' => Self::ManuscriptNotatedMusic,
+            'e' => Self::CartographicMaterial,
+            'f' => Self::ManuscriptCartographicMaterial,
+            'g' => Self::ProjectedMedium,
+            'i' => Self::NonmusicalSoundRecording,
+            'j' => Self::MusicalSoundRecording,
+            'k' => Self::TwoDimensionalNonprojectableGraphic,
+            'm' => Self::ComputerFile,
+            'o' => Self::Kit,
+            'p' => Self::MixedMaterials,
+            'q' => Self::CommunityInformation,
+            'r' => Self::ThreeDimensionalArtifact,
+            't' => Self::ManuscriptLanguageMaterial,
+            'u' => Self::UnknownHoldings,
+            'v' => Self::MultipartItemHoldings,
+            'w' => Self::ClassificationData,
+            'x' => Self::SinglePartItemHoldings,
+            'y' => Self::SerialItemHoldings,
+            'z' => Self::AuthorityData,
+            _ => Self::Other(code),
+        }
+    }
+}
+
+impl From<TypeOfRecord> for char {
+    fn from(value: TypeOfRecord) -> Self {
+        match value {
+            TypeOfRecord::LanguageMaterial => 'a',
+            TypeOfRecord::NotatedMusic => 'c',
+            TypeOfRecord::ManuscriptNotatedMusic => 'd',
+            TypeOfRecord::CartographicMaterial => 'e',
+            TypeOfRecord::ManuscriptCartographicMaterial => 'f',
+            TypeOfRecord::ProjectedMedium => 'g',
+            TypeOfRecord::NonmusicalSoundRecording => 'i',
+            TypeOfRecord::MusicalSoundRecording => 'j',
+            TypeOfRecord::TwoDimensionalNonprojectableGraphic => 'k',
+            TypeOfRecord::ComputerFile => 'm',
+            TypeOfRecord::Kit => 'o',
+            TypeOfRecord::MixedMaterials => 'p',
+            TypeOfRecord::CommunityInformation => 'q',
+            TypeOfRecord::ThreeDimensionalArtifact => 'r',
+            TypeOfRecord::ManuscriptLanguageMaterial => 't',
+            TypeOfRecord::UnknownHoldings => 'u',
+            TypeOfRecord::MultipartItemHoldings => 'v',
+            TypeOfRecord::ClassificationData => 'w',
+            TypeOfRecord::SinglePartItemHoldings => 'x',
+            TypeOfRecord::SerialItemHoldings => 'y',
+            TypeOfRecord::AuthorityData => 'z',
+            TypeOfRecord::Other(code) => code,
+        }
+    }
+}
+
+/// The bibliographic level of the record (leader position 07).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BibliographicLevel {
+    /// Monographic component part (`a`).
+    MonographicComponentPart,
+    /// Serial component part (`b`).
+    SerialComponentPart,
+    /// Collection (`c`).
+    Collection,
+    /// Subunit (`d`).
+    Subunit,
+    /// Integrating resource (`i`).
+    IntegratingResource,
+    /// Monograph/Item (`m`).
+    Monograph,
+    /// Serial (`s`).
+    Serial,
+    /// Any other (non-standard) bibliographic level code.
+    Other(char),
+}
+
+impl From<char> for BibliographicLevel {
+    fn from(code: char) -> Self {
+        match code {
+            'a' => Self::MonographicComponentPart,
+            'b' => Self::SerialComponentPart,
+            'c' => Self::Collection,
+            'd' => Self::Subunit,
+            'i' => Self::IntegratingResource,
+            'm' => Self::Monograph,
+            's' => Self::Serial,
+            _ => Self::Other(code),
+        }
+    }
+}
+
+impl From<BibliographicLevel> for char {
+    fn from(value: BibliographicLevel) -> Self {
+        match value {
+            BibliographicLevel::MonographicComponentPart => 'a',
+            BibliographicLevel::SerialComponentPart => 'b',
+            BibliographicLevel::Collection => 'c',
+            BibliographicLevel::Subunit => 'd',
+            BibliographicLevel::IntegratingResource => 'i',
+            BibliographicLevel::Monograph => 'm',
+            BibliographicLevel::Serial => 's',
+            BibliographicLevel::Other(code) => code,
+        }
+    }
+}
+
+/// The type of control of the record (leader position 08).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TypeOfControl {
+    /// No specified type (` `).
+    NoSpecifiedType,
+    /// Archival (`a`).
+    Archival,
+    /// Any other (non-standard) type of control code.
+    Other(char),
+}
+
+impl From<char> for TypeOfControl {
+    fn from(code: char) -> Self {
+        match code {
+            ' ' => Self::NoSpecifiedType,
+            'a' => Self::Archival,
+            _ => Self::Other(code),
+        }
+    }
+}
+
+impl From<TypeOfControl> for char {
+    fn from(value: TypeOfControl) -> Self {
+        match value {
+            TypeOfControl::NoSpecifiedType => ' ',
+            TypeOfControl::Archival => 'a',
+            TypeOfControl::Other(code) => code,
+        }
+    }
+}
+
+/// The character coding scheme of the record (leader position 09).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharacterCodingScheme {
+    /// MARC-8 (` `).
+    Marc8,
+    /// UCS/Unicode (`a`).
+    Utf8,
+    /// Any other (non-standard) coding scheme code.
+    Other(char),
+}
+
+impl From<char> for CharacterCodingScheme {
+    fn from(code: char) -> Self {
+        match code {
+            ' ' => Self::Marc8,
+            'a' => Self::Utf8,
+            _ => Self::Other(code),
+        }
+    }
+}
+
+impl From<CharacterCodingScheme> for char {
+    fn from(value: CharacterCodingScheme) -> Self {
+        match value {
+            CharacterCodingScheme::Marc8 => ' ',
+            CharacterCodingScheme::Utf8 => 'a',
+            CharacterCodingScheme::Other(code) => code,
+        }
+    }
+}
+
+/// The encoding level of the record (leader position 17), i.e. the
+/// completeness of the bibliographic description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EncodingLevel {
+    /// Full level (` `).
+    Full,
+    /// Full level, material not examined (`1`).
+    FullMaterialNotExamined,
+    /// Less-than-full level, material not examined (`2`).
+    LessThanFull,
+    /// Abbreviated level (`3`).
+    AbbreviatedLevel,
+    /// Core level (`4`).
+    CoreLevel,
+    /// Partial (preliminary) level (`5`).
+    PartialLevel,
+    /// Minimal level (`7`).
+    Minimal,
+    /// Prepublication level (`8`).
+    Prepublication,
+    /// Unknown (`u`).
+    Unknown,
+    /// Not applicable (`z`).
+    NotApplicable,
+    /// Any other (non-standard or local) encoding level code.
+    Other(char),
+}
+
+impl From<char> for EncodingLevel {
+    fn from(code: char) -> Self {
+        match code {
+            ' ' => Self::Full,
+            '1' => Self::FullMaterialNotExamined,
+            '2' => Self::LessThanFull,
+            '3' => Self::AbbreviatedLevel,
+            '4' => Self::CoreLevel,
+            '5' => Self::PartialLevel,
+            '7' => Self::Minimal,
+            '8' => Self::Prepublication,
+            'u' => Self::Unknown,
+            'z' => Self::NotApplicable,
+            _ => Self::Other(code),
+        }
+    }
+}
+
+impl From<EncodingLevel> for char {
+    fn from(value: EncodingLevel) -> Self {
+        match value {
+            EncodingLevel::Full => ' ',
+            EncodingLevel::FullMaterialNotExamined => '1',
+            EncodingLevel::LessThanFull => '2',
+            EncodingLevel::AbbreviatedLevel => '3',
+            EncodingLevel::CoreLevel => '4',
+            EncodingLevel::PartialLevel => '5',
+            EncodingLevel::Minimal => '7',
+            EncodingLevel::Prepublication => '8',
+            EncodingLevel::Unknown => 'u',
+            EncodingLevel::NotApplicable => 'z',
+            EncodingLevel::Other(code) => code,
+        }
+    }
+}
+
+/// The descriptive cataloging form of the record (leader position 18),
+/// i.e. the cataloging rules and whether ISBD punctuation is present.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DescriptiveCatalogingForm {
+    /// Non-ISBD (` `).
+    NonISBD,
+    /// AACR 2 (`a`).
+    AACR2,
+    /// ISBD punctuation omitted (`c`).
+    ISBDPunctuationOmitted,
+    /// ISBD punctuation included (`i`), which is also used for RDA
+    /// records.
+    ISBDPunctuationIncluded,
+    /// Non-ISBD punctuation omitted (`n`).
+    NonISBDPunctuationOmitted,
+    /// Unknown (`u`).
+    Unknown,
+    /// Any other (non-standard) descriptive cataloging form code.
+    Other(char),
+}
+
+impl From<char> for DescriptiveCatalogingForm {
+    fn from(code: char) -> Self {
+        match code {
+            ' ' => Self::NonISBD,
+            'a' => Self::AACR2,
+            'c' => Self::ISBDPunctuationOmitted,
+            'i' => Self::ISBDPunctuationIncluded,
+            'n' => Self::NonISBDPunctuationOmitted,
+            'u' => Self::Unknown,
+            _ => Self::Other(code),
+        }
+    }
+}
+
+impl From<DescriptiveCatalogingForm> for char {
+    fn from(value: DescriptiveCatalogingForm) -> Self {
+        match value {
+            DescriptiveCatalogingForm::NonISBD => ' ',
+            DescriptiveCatalogingForm::AACR2 => 'a',
+            DescriptiveCatalogingForm::ISBDPunctuationOmitted => 'c',
+            DescriptiveCatalogingForm::ISBDPunctuationIncluded => 'i',
+            DescriptiveCatalogingForm::NonISBDPunctuationOmitted => 'n',
+            DescriptiveCatalogingForm::Unknown => 'u',
+            DescriptiveCatalogingForm::Other(code) => code,
+        }
+    }
+}
+
+/// The multipart resource record level of the record (leader position
+/// 19), i.e. whether the record describes a set or a part of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MultipartResourceRecordLevel {
+    /// Not specified or not applicable (` `).
+    NotSpecified,
+    /// Set (`a`).
+    Set,
+    /// Part with independent title (`b`).
+    PartWithIndependentTitle,
+    /// Part with dependent title (`c`).
+    PartWithDependentTitle,
+    /// Any other (non-standard) multipart resource record level code.
+    Other(char),
+}
+
+impl From<char> for MultipartResourceRecordLevel {
+    fn from(code: char) -> Self {
+        match code {
+            ' ' => Self::NotSpecified,
+            'a' => Self::Set,
+            'b' => Self::PartWithIndependentTitle,
+            'c' => Self::PartWithDependentTitle,
+            _ => Self::Other(code),
+        }
+    }
+}
+
+impl From<MultipartResourceRecordLevel> for char {
+    fn from(value: MultipartResourceRecordLevel) -> Self {
+        match value {
+            MultipartResourceRecordLevel::NotSpecified => ' ',
+            MultipartResourceRecordLevel::Set => 'a',
+            MultipartResourceRecordLevel::PartWithIndependentTitle => {
+                'b'
+            }
+            MultipartResourceRecordLevel::PartWithDependentTitle => 'c',
+            MultipartResourceRecordLevel::Other(code) => code,
+        }
+    }
+}
+
+/// The MARC 21 format of a record, as inferred from the type of
+/// record (leader position 06).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarcFormat {
+    /// The Format for Bibliographic Data.
+    Bibliographic,
+    /// The Format for Authority Data (`z`).
+    Authority,
+    /// The Format for Holdings Data (`u`, `v`, `x` or `y`).
+    Holdings,
+    /// The Format for Classification Data (`w`).
+    Classification,
+    /// The Format for Community Information (`q`).
+    Community,
+    /// A non-standard type of record.
+    Unknown,
 }
 
 /// An error that can occur when parsing the leader field.
-#[derive(Debug, thiserror::Error)]
+#[derive(Debug)]
 pub enum ParseLeaderError {
-    #[error("invalid record length")]
     InvalidRecordLength,
-
-    #[error("invalid record status")]
     InvalidRecordStatus,
-
-    #[error("incomplete leader, missing: {0:?}")]
+    InvalidTypeOfRecord,
+    InvalidBibliographicLevel,
+    InvalidCharacterCodingScheme,
+    InvalidEncodingLevel,
+    InvalidIndicatorCount,
+    InvalidSubfieldCodeCount,
+    InvalidBaseAddress,
+    InvalidEntryMap,
+    InvalidLength(usize),
     Incomplete(nom::Needed),
-
-    #[error("parse error: {0:?}")]
     Nom(nom::error::ErrorKind),
 }
 
+impl fmt::Display for ParseLeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRecordLength => {
+                f.write_str("invalid record length")
+            }
+            Self::InvalidRecordStatus => {
+                f.write_str("invalid record status")
+            }
+            Self::InvalidTypeOfRecord => {
+                f.write_str("invalid type of record")
+            }
+            Self::InvalidBibliographicLevel => {
+                f.write_str("invalid bibliographic level")
+            }
+            Self::InvalidCharacterCodingScheme => {
+                f.write_str("invalid character coding scheme")
+            }
+            Self::InvalidEncodingLevel => {
+                f.write_str("invalid encoding level")
+            }
+            Self::InvalidIndicatorCount => {
+                f.write_str("invalid indicator count")
+            }
+            Self::InvalidSubfieldCodeCount => {
+                f.write_str("invalid subfield code count")
+            }
+            Self::InvalidBaseAddress => {
+                f.write_str("invalid base address of data")
+            }
+            Self::InvalidEntryMap => f.write_str("invalid entry map"),
+            Self::InvalidLength(len) => {
+                write!(f, "invalid leader length {len}, expected 24")
+            }
+            Self::Incomplete(needed) => {
+                write!(f, "incomplete leader, missing: {needed:?}")
+            }
+            Self::Nom(kind) => write!(f, "parse error: {kind:?}"),
+        }
+    }
+}
+
+impl core::error::Error for ParseLeaderError {}
+
 impl<'a> ParseError<&'a [u8]> for ParseLeaderError {
     fn from_error_kind(
         _: &'a [u8],
@@ -73,7 +637,7 @@ impl Leader {
     ///
     /// # fn main() { example().unwrap(); }
     /// fn example() -> anyhow::Result<()> {
-    ///     let leader = Leader::from_bytes(b"00827n")?;
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
     ///     assert_eq!(leader.record_length(), 827);
     ///
     ///     Ok(())
@@ -83,12 +647,29 @@ impl Leader {
         parse_leader(data).finish().map(|(_, leader)| leader)
     }
 
-    /// Returns the length of the entire record, including the leader
-    /// and the record terminator.
+    /// Converts the leader into its 24 bytes/octets representation.
     ///
-    /// # Note
+    /// # Example
     ///
-    /// The maximum length of a record is 99999 bytes/octets.
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(&leader.to_bytes(), b"00827nam a2200241 c 4500");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_bytes(&self) -> [u8; LEADER_LEN] {
+        self.raw
+    }
+
+    /// Returns a reference to the 24 bytes/octets of the leader.
+    ///
+    /// The bytes of a parsed leader are identical to the input until
+    /// the leader is modified; they always equal [Leader::to_bytes].
     ///
     /// # Example
     ///
@@ -97,97 +678,1748 @@ impl Leader {
     ///
     /// # fn main() { example().unwrap(); }
     /// fn example() -> anyhow::Result<()> {
-    ///     let leader = Leader::from_bytes(b"00827n")?;
-    ///     assert_eq!(leader.record_length(), 827);
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(leader.raw(), b"00827nam a2200241 c 4500");
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub fn record_length(&self) -> u32 {
-        self.record_len
+    pub fn raw(&self) -> &[u8; LEADER_LEN] {
+        &self.raw
     }
 
-    /// Returns the status code of the record.
+    /// Returns the byte at the given position (`0`-`23`) of the leader,
+    /// or `None` if the position is out of range. The named positions
+    /// are listed in [leader_positions](crate::leader_positions).
     ///
     /// # Example
     ///
     /// ```rust
+    /// use marc21::leader_positions::TYPE_OF_RECORD;
     /// use marc21::Leader;
     ///
     /// # fn main() { example().unwrap(); }
     /// fn example() -> anyhow::Result<()> {
-    ///     let leader = Leader::from_bytes(b"00827n")?;
-    ///     assert_eq!(leader.record_status(), 'n');
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(leader.position(TYPE_OF_RECORD), Some(b'a'));
+    ///     assert_eq!(leader.position(24), None);
     ///
     ///     Ok(())
     /// }
     /// ```
-    pub fn record_status(&self) -> char {
-        self.record_status
+    pub fn position(&self, index: usize) -> Option<u8> {
+        self.raw.get(index).copied()
     }
-}
 
-/// Parse the record length field.
-///
-/// The record length is encoded as five right justified ASCII digits.
-/// An unused positions is set to zero. The record length is between 0
-/// and 99999.
-#[inline]
-fn parse_record_len(i: &[u8]) -> ParseResult<u32> {
-    fold_many_m_n(
-        5,
-        5,
-        satisfy(|ch| ch.is_ascii_digit()),
-        || 0,
-        |acc, n| acc * 10 + (n as u8 - b'0') as u32,
-    )(i)
-}
+    /// Returns the code at the given (code) position.
+    #[inline]
+    fn code(&self, position: usize) -> char {
+        self.raw[position] as char
+    }
 
-/// Parse the record status field.
-#[inline]
-fn parse_record_status(i: &[u8]) -> ParseResult<char> {
-    one_of("acdnosx")(i)
-}
+    /// Sets the record length (positions 00-04). Lengths exceeding the
+    /// maximum record length are written as `00000`.
+    pub(crate) fn set_record_len(&mut self, value: u32) {
+        self.record_len = value;
+        write_record_len(&mut self.raw[0..5], value);
+    }
 
-pub(crate) fn parse_leader(i: &[u8]) -> ParseResult<Leader> {
-    let (i, record_len) = parse_record_len(i)
-        .map_err(|_| ParseLeaderError::InvalidRecordLength)?;
+    /// Sets the base address of data (positions 12-16).
+    pub(crate) fn set_base_address(&mut self, value: u32) {
+        self.base_address = value;
+        write_number(&mut self.raw[12..17], value);
+    }
 
-    let (i, record_status) = parse_record_status(i)
-        .map_err(|_| ParseLeaderError::InvalidRecordStatus)?;
+    /// Sets the indicator count and the subfield code count
+    /// (positions 10 and 11) to the standard value `2`.
+    pub(crate) fn set_standard_counts(&mut self) {
+        self.indicator_count = 2;
+        self.subfield_code_count = 2;
+        self.raw[10] = b'2';
+        self.raw[11] = b'2';
+    }
 
-    Ok((
-        i,
-        Leader {
-            record_len,
-            record_status,
-        },
-    ))
-}
+    /// Returns the length of the entire record, including the leader
+    /// and the record terminator.
+    ///
+    /// # Note
+    ///
+    /// The maximum length of a record is 99999 bytes/octets.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(leader.record_length(), 827);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn record_length(&self) -> u32 {
+        self.record_len
+    }
 
-#[cfg(test)]
-mod tests {
-    use nom_test_helpers::prelude::*;
+    /// Returns the status of the record (position 05).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, RecordStatus};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(leader.record_status(), RecordStatus::New);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn record_status(&self) -> RecordStatus {
+        RecordStatus::from(self.code(5))
+    }
 
-    use super::*;
+    /// Returns the type of the record (position 06).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, TypeOfRecord};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(
+    ///         leader.type_of_record(),
+    ///         TypeOfRecord::LanguageMaterial
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn type_of_record(&self) -> TypeOfRecord {
+        TypeOfRecord::from(self.code(6))
+    }
 
-    #[test]
-    fn test_leader_from_bytes() -> anyhow::Result<()> {
-        let leader = Leader::from_bytes(b"00123d")?;
-        assert_eq!(leader.record_length(), 123);
-        assert_eq!(leader.record_status(), 'd');
+    /// Returns the bibliographic level of the record (position 07).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{BibliographicLevel, Leader};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(
+    ///         leader.bibliographic_level(),
+    ///         BibliographicLevel::Monograph
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn bibliographic_level(&self) -> BibliographicLevel {
+        BibliographicLevel::from(self.code(7))
+    }
 
-        assert!(Leader::from_bytes(b"1234").is_err());
-        Ok(())
+    /// Returns the type of control of the record (position 08).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, TypeOfControl};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827npcaa2200241 c 4500")?;
+    ///     assert_eq!(leader.type_of_control(), TypeOfControl::Archival);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn type_of_control(&self) -> TypeOfControl {
+        TypeOfControl::from(self.code(8))
     }
 
-    #[test]
-    fn test_parse_record_len() {
-        assert_finished_and_eq!(parse_record_len(b"99999"), 99999);
-        assert_finished_and_eq!(parse_record_len(b"12345"), 12345);
-        assert_finished_and_eq!(parse_record_len(b"00000"), 0);
-        assert_error!(parse_record_len(b"-1000"));
-        assert_error!(parse_record_len(b"1234"));
+    /// Returns the character coding scheme of the record (position
+    /// 09).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{CharacterCodingScheme, Leader};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(
+    ///         leader.character_coding_scheme(),
+    ///         CharacterCodingScheme::Utf8
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn character_coding_scheme(&self) -> CharacterCodingScheme {
+        CharacterCodingScheme::from(self.code(9))
+    }
+
+    /// Returns the number of indicators of a data field (position 10).
+    ///
+    /// # Note
+    ///
+    /// In MARC 21 records the indicator count is always `2`. Other
+    /// values are returned faithfully and it's up to the caller to
+    /// decide how to deal with them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(leader.indicator_count(), 2);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn indicator_count(&self) -> u8 {
+        self.indicator_count
+    }
+
+    /// Returns the number of characters of a subfield code, including
+    /// the delimiter (position 11).
+    ///
+    /// # Note
+    ///
+    /// In MARC 21 records the subfield code count is always `2`. Other
+    /// values are returned faithfully and it's up to the caller to
+    /// decide how to deal with them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(leader.subfield_code_count(), 2);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn subfield_code_count(&self) -> u8 {
+        self.subfield_code_count
+    }
+
+    /// Returns the base address of data (positions 12-16).
+    ///
+    /// The base address of data is the offset of the first field
+    /// (relative to the beginning of the record) and equals the sum of
+    /// the lengths of the leader and the directory, including the
+    /// field terminator at the end of the directory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(leader.base_address_of_data(), 241);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn base_address_of_data(&self) -> u32 {
+        self.base_address
+    }
+
+    /// Returns the entry map of the record (positions 20-22).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     let entry_map = leader.entry_map();
+    ///     assert_eq!(entry_map.length_of_field_portion, 4);
+    ///     assert_eq!(entry_map.starting_character_position_portion, 5);
+    ///     assert_eq!(entry_map.implementation_defined_portion, 0);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn entry_map(&self) -> EntryMap {
+        self.entry_map
+    }
+
+    /// Returns the encoding level of the record (position 17).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{EncodingLevel, Leader};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(leader.encoding_level(), EncodingLevel::Full);
+    ///
+    ///     let leader = Leader::from_bytes(b"00827nam a22002417c 4500")?;
+    ///     assert_eq!(leader.encoding_level(), EncodingLevel::Minimal);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn encoding_level(&self) -> EncodingLevel {
+        EncodingLevel::from(self.code(17))
+    }
+
+    /// Returns the descriptive cataloging form of the record (position
+    /// 18).
+    ///
+    /// Records with ISBD punctuation included carry trailing
+    /// punctuation in their subfields, which can be removed with
+    /// [Subfield::trimmed_value](crate::Subfield::trimmed_value).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{DescriptiveCatalogingForm, Leader};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 i 4500")?;
+    ///     assert_eq!(
+    ///         leader.descriptive_cataloging_form(),
+    ///         DescriptiveCatalogingForm::ISBDPunctuationIncluded
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn descriptive_cataloging_form(
+        &self,
+    ) -> DescriptiveCatalogingForm {
+        DescriptiveCatalogingForm::from(self.code(18))
+    }
+
+    /// Returns the multipart resource record level of the record
+    /// (position 19).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, MultipartResourceRecordLevel};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 ca4500")?;
+    ///     assert_eq!(
+    ///         leader.multipart_resource_record_level(),
+    ///         MultipartResourceRecordLevel::Set
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn multipart_resource_record_level(
+        &self,
+    ) -> MultipartResourceRecordLevel {
+        MultipartResourceRecordLevel::from(self.code(19))
+    }
+
+    /// Sets the status of the record (position 05).
+    ///
+    /// Fails if the status isn't a single printable ASCII character,
+    /// which is only possible with [RecordStatus::Other].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, RecordStatus};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut leader =
+    ///         Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     leader.set_record_status(RecordStatus::Deleted)?;
+    ///     assert_eq!(&leader.to_bytes(), b"00827dam a2200241 c 4500");
+    ///
+    ///     assert!(leader
+    ///         .set_record_status(RecordStatus::Other('ä'))
+    ///         .is_err());
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_record_status(
+        &mut self,
+        value: RecordStatus,
+    ) -> Result<(), ParseLeaderError> {
+        self.raw[5] = single_byte(
+            value.into(),
+            ParseLeaderError::InvalidRecordStatus,
+        )?;
+        Ok(())
+    }
+
+    /// Sets the type of the record (position 06).
+    ///
+    /// Fails if the type isn't a single printable ASCII character,
+    /// which is only possible with [TypeOfRecord::Other].
+    pub fn set_type_of_record(
+        &mut self,
+        value: TypeOfRecord,
+    ) -> Result<(), ParseLeaderError> {
+        self.raw[6] = single_byte(
+            value.into(),
+            ParseLeaderError::InvalidTypeOfRecord,
+        )?;
+        Ok(())
+    }
+
+    /// Sets the character coding scheme of the record (position 09).
+    ///
+    /// Fails if the scheme isn't a single printable ASCII character,
+    /// which is only possible with [CharacterCodingScheme::Other].
+    pub fn set_character_coding_scheme(
+        &mut self,
+        value: CharacterCodingScheme,
+    ) -> Result<(), ParseLeaderError> {
+        self.raw[9] = single_byte(
+            value.into(),
+            ParseLeaderError::InvalidCharacterCodingScheme,
+        )?;
+        Ok(())
+    }
+
+    /// Sets the encoding level of the record (position 17).
+    ///
+    /// Fails if the encoding level isn't a single printable ASCII
+    /// character, which is only possible with [EncodingLevel::Other].
+    pub fn set_encoding_level(
+        &mut self,
+        value: EncodingLevel,
+    ) -> Result<(), ParseLeaderError> {
+        self.raw[17] = single_byte(
+            value.into(),
+            ParseLeaderError::InvalidEncodingLevel,
+        )?;
+        Ok(())
+    }
+
+    /// Returns the leader with the given record length (positions
+    /// 00-04), failing if the length exceeds 99999 and can't be
+    /// encoded in five digits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, ParseLeaderError};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::default().with_length(827)?;
+    ///     assert_eq!(leader.record_length(), 827);
+    ///
+    ///     assert!(matches!(
+    ///         leader.with_length(100_000),
+    ///         Err(ParseLeaderError::InvalidRecordLength)
+    ///     ));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_length(
+        self,
+        len: u32,
+    ) -> Result<Leader, ParseLeaderError> {
+        if len > MAX_RECORD_LEN {
+            return Err(ParseLeaderError::InvalidRecordLength);
+        }
+
+        let mut leader = self;
+        leader.set_record_len(len);
+        Ok(leader)
+    }
+
+    /// Returns `true` if the bibliographic level is serial (`s`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nas a2200241 c 4500")?;
+    ///     assert!(leader.is_serial());
+    ///     assert!(!leader.is_monograph());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn is_serial(&self) -> bool {
+        self.bibliographic_level() == BibliographicLevel::Serial
+    }
+
+    /// Returns `true` if the bibliographic level is monograph/item
+    /// (`m`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert!(leader.is_monograph());
+    ///     assert!(!leader.is_serial());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn is_monograph(&self) -> bool {
+        self.bibliographic_level() == BibliographicLevel::Monograph
+    }
+
+    /// Returns `true` if the type of record belongs to the MARC 21
+    /// Format for Bibliographic Data, as opposed to e.g. authority or
+    /// holdings records.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert!(leader.is_bibliographic());
+    ///
+    ///     let leader = Leader::from_bytes(b"00827nz  a2200241 n 4500")?;
+    ///     assert!(!leader.is_bibliographic());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn is_bibliographic(&self) -> bool {
+        use TypeOfRecord::*;
+
+        matches!(
+            self.type_of_record(),
+            LanguageMaterial
+                | NotatedMusic
+                | ManuscriptNotatedMusic
+                | CartographicMaterial
+                | ManuscriptCartographicMaterial
+                | ProjectedMedium
+                | NonmusicalSoundRecording
+                | MusicalSoundRecording
+                | TwoDimensionalNonprojectableGraphic
+                | ComputerFile
+                | Kit
+                | MixedMaterials
+                | ThreeDimensionalArtifact
+                | ManuscriptLanguageMaterial
+        )
+    }
+
+    /// Returns `true` if the record is an authority record (`z`).
+    pub fn is_authority(&self) -> bool {
+        self.type_of_record() == TypeOfRecord::AuthorityData
+    }
+
+    /// Returns `true` if the record is a holdings record (`u`, `v`,
+    /// `x` or `y`).
+    pub fn is_holdings(&self) -> bool {
+        matches!(
+            self.type_of_record(),
+            TypeOfRecord::UnknownHoldings
+                | TypeOfRecord::MultipartItemHoldings
+                | TypeOfRecord::SinglePartItemHoldings
+                | TypeOfRecord::SerialItemHoldings
+        )
+    }
+
+    /// Returns the MARC 21 format the record belongs to, which
+    /// determines the meaning of the other leader positions and of
+    /// the fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, MarcFormat};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(leader.format(), MarcFormat::Bibliographic);
+    ///
+    ///     let leader = Leader::from_bytes(b"00827nz  a2200241 n 4500")?;
+    ///     assert_eq!(leader.format(), MarcFormat::Authority);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn format(&self) -> MarcFormat {
+        match self.type_of_record() {
+            TypeOfRecord::AuthorityData => MarcFormat::Authority,
+            TypeOfRecord::ClassificationData => {
+                MarcFormat::Classification
+            }
+            TypeOfRecord::CommunityInformation => MarcFormat::Community,
+            TypeOfRecord::Other(_) => MarcFormat::Unknown,
+            _ if self.is_holdings() => MarcFormat::Holdings,
+            _ => MarcFormat::Bibliographic,
+        }
+    }
+
+    /// Compares two leaders, ignoring the record length and the base
+    /// address of data, which depend on the serialization of the
+    /// record rather than its content. This is the same comparison as
+    /// `==`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let a = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     let b = Leader::from_bytes(b"00048nam a2200037 c 4500")?;
+    ///     assert!(a.semantic_eq(&b));
+    ///     assert_eq!(a, b);
+    ///     assert_ne!(a.to_bytes(), b.to_bytes());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn semantic_eq(&self, other: &Leader) -> bool {
+        self == other
+    }
+
+    /// Returns a multi-line, human-readable breakdown of all leader
+    /// positions, e.g. for debugging. See [Display](fmt::Display) for
+    /// the compact 24-character representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     let description = leader.describe();
+    ///     assert!(description.contains("status: New (n)\n"));
+    ///     assert!(description.contains("type: Language material (a)\n"));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn describe(&self) -> String {
+        let entry_map = self.entry_map;
+        let lines = [
+            ("record length", self.record_len.to_string()),
+            (
+                "status",
+                labeled(
+                    self.code(5),
+                    status_label(self.record_status()),
+                ),
+            ),
+            (
+                "type",
+                labeled(
+                    self.code(6),
+                    type_label(self.type_of_record()),
+                ),
+            ),
+            (
+                "bibliographic level",
+                labeled(
+                    self.code(7),
+                    level_label(self.bibliographic_level()),
+                ),
+            ),
+            (
+                "type of control",
+                labeled(
+                    self.code(8),
+                    control_label(self.type_of_control()),
+                ),
+            ),
+            (
+                "character coding scheme",
+                labeled(
+                    self.code(9),
+                    scheme_label(self.character_coding_scheme()),
+                ),
+            ),
+            ("indicator count", self.indicator_count.to_string()),
+            (
+                "subfield code count",
+                self.subfield_code_count.to_string(),
+            ),
+            ("base address of data", self.base_address.to_string()),
+            (
+                "encoding level",
+                labeled(
+                    self.code(17),
+                    encoding_label(self.encoding_level()),
+                ),
+            ),
+            (
+                "descriptive cataloging form",
+                labeled(
+                    self.code(18),
+                    form_label(self.descriptive_cataloging_form()),
+                ),
+            ),
+            (
+                "multipart resource record level",
+                labeled(
+                    self.code(19),
+                    multipart_label(
+                        self.multipart_resource_record_level(),
+                    ),
+                ),
+            ),
+            (
+                "entry map",
+                format!(
+                    "{}{}{}{}",
+                    entry_map.length_of_field_portion,
+                    entry_map.starting_character_position_portion,
+                    entry_map.implementation_defined_portion,
+                    self.code(23),
+                ),
+            ),
+        ];
+
+        lines
+            .iter()
+            .map(|(name, value)| format!("{name}: {value}\n"))
+            .collect()
+    }
+}
+
+impl Default for Leader {
+    /// Returns a skeleton leader of a new record (`n`) in UTF-8 (`a`)
+    /// with two indicators, two-character subfield codes and the
+    /// entry map `4500`. All other positions are blank; the record
+    /// length and the base address of data are zero and recomputed
+    /// when a record is built.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::default();
+    ///     assert_eq!(leader.to_string(), "00000n   a2200000   4500");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn default() -> Self {
+        Self {
+            raw: *b"00000n   a2200000   4500",
+            record_len: 0,
+            indicator_count: 2,
+            subfield_code_count: 2,
+            base_address: 0,
+            entry_map: EntryMap {
+                length_of_field_portion: 4,
+                starting_character_position_portion: 5,
+                implementation_defined_portion: 0,
+            },
+        }
+    }
+}
+
+/// Formats a label together with its code, e.g. `New (n)`.
+fn labeled(code: char, label: &str) -> String {
+    format!("{label} ({code})")
+}
+
+fn status_label(status: RecordStatus) -> &'static str {
+    match status {
+        RecordStatus::Increase => "Increase in encoding level",
+        RecordStatus::Corrected => "Corrected or revised",
+        RecordStatus::Deleted => "Deleted",
+        RecordStatus::New => "New",
+        RecordStatus::IncreaseFromPrepublication => {
+            "Increase in encoding level from prepublication"
+        }
+        RecordStatus::Other(_) => "Other",
+    }
+}
+
+fn type_label(value: TypeOfRecord) -> &'static str {
+    match value {
+        TypeOfRecord::LanguageMaterial => "Language material",
+        TypeOfRecord::NotatedMusic => "Notated music",
+        TypeOfRecord::ManuscriptNotatedMusic => {
+            "Manuscript notated music"
+        }
+        TypeOfRecord::CartographicMaterial => "Cartographic material",
+        TypeOfRecord::ManuscriptCartographicMaterial => {
+            "Manuscript cartographic material"
+        }
+        TypeOfRecord::ProjectedMedium => "Projected medium",
+        TypeOfRecord::NonmusicalSoundRecording => {
+            "Nonmusical sound recording"
+        }
+        TypeOfRecord::MusicalSoundRecording => {
+            "Musical sound recording"
+        }
+        TypeOfRecord::TwoDimensionalNonprojectableGraphic => {
+            "Two-dimensional nonprojectable graphic"
+        }
+        TypeOfRecord::ComputerFile => "Computer file",
+        TypeOfRecord::Kit => "Kit",
+        TypeOfRecord::MixedMaterials => "Mixed materials",
+        TypeOfRecord::CommunityInformation => "Community information",
+        TypeOfRecord::ThreeDimensionalArtifact => {
+            "Three-dimensional artifact or naturally occurring object"
+        }
+        TypeOfRecord::ManuscriptLanguageMaterial => {
+            "Manuscript language material"
+        }
+        TypeOfRecord::UnknownHoldings => "Unknown holdings",
+        TypeOfRecord::MultipartItemHoldings => {
+            "Multipart item holdings"
+        }
+        TypeOfRecord::ClassificationData => "Classification data",
+        TypeOfRecord::SinglePartItemHoldings => {
+            "Single-part item holdings"
+        }
+        TypeOfRecord::SerialItemHoldings => "Serial item holdings",
+        TypeOfRecord::AuthorityData => "Authority data",
+        TypeOfRecord::Other(_) => "Other",
+    }
+}
+
+fn level_label(value: BibliographicLevel) -> &'static str {
+    match value {
+        BibliographicLevel::MonographicComponentPart => {
+            "Monographic component part"
+        }
+        BibliographicLevel::SerialComponentPart => {
+            "Serial component part"
+        }
+        BibliographicLevel::Collection => "Collection",
+        BibliographicLevel::Subunit => "Subunit",
+        BibliographicLevel::IntegratingResource => {
+            "Integrating resource"
+        }
+        BibliographicLevel::Monograph => "Monograph/Item",
+        BibliographicLevel::Serial => "Serial",
+        BibliographicLevel::Other(_) => "Other",
+    }
+}
+
+fn control_label(value: TypeOfControl) -> &'static str {
+    match value {
+        TypeOfControl::NoSpecifiedType => "No specified type",
+        TypeOfControl::Archival => "Archival",
+        TypeOfControl::Other(_) => "Other",
+    }
+}
+
+fn encoding_label(value: EncodingLevel) -> &'static str {
+    match value {
+        EncodingLevel::Full => "Full level",
+        EncodingLevel::FullMaterialNotExamined => {
+            "Full level, material not examined"
+        }
+        EncodingLevel::LessThanFull => {
+            "Less-than-full level, material not examined"
+        }
+        EncodingLevel::AbbreviatedLevel => "Abbreviated level",
+        EncodingLevel::CoreLevel => "Core level",
+        EncodingLevel::PartialLevel => "Partial (preliminary) level",
+        EncodingLevel::Minimal => "Minimal level",
+        EncodingLevel::Prepublication => "Prepublication level",
+        EncodingLevel::Unknown => "Unknown",
+        EncodingLevel::NotApplicable => "Not applicable",
+        EncodingLevel::Other(_) => "Other",
+    }
+}
+
+fn form_label(value: DescriptiveCatalogingForm) -> &'static str {
+    match value {
+        DescriptiveCatalogingForm::NonISBD => "Non-ISBD",
+        DescriptiveCatalogingForm::AACR2 => "AACR 2",
+        DescriptiveCatalogingForm::ISBDPunctuationOmitted => {
+            "ISBD punctuation omitted"
+        }
+        DescriptiveCatalogingForm::ISBDPunctuationIncluded => {
+            "ISBD punctuation included"
+        }
+        DescriptiveCatalogingForm::NonISBDPunctuationOmitted => {
+            "Non-ISBD punctuation omitted"
+        }
+        DescriptiveCatalogingForm::Unknown => "Unknown",
+        DescriptiveCatalogingForm::Other(_) => "Other",
+    }
+}
+
+fn multipart_label(
+    value: MultipartResourceRecordLevel,
+) -> &'static str {
+    match value {
+        MultipartResourceRecordLevel::NotSpecified => {
+            "Not specified or not applicable"
+        }
+        MultipartResourceRecordLevel::Set => "Set",
+        MultipartResourceRecordLevel::PartWithIndependentTitle => {
+            "Part with independent title"
+        }
+        MultipartResourceRecordLevel::PartWithDependentTitle => {
+            "Part with dependent title"
+        }
+        MultipartResourceRecordLevel::Other(_) => "Other",
+    }
+}
+
+fn scheme_label(value: CharacterCodingScheme) -> &'static str {
+    match value {
+        CharacterCodingScheme::Marc8 => "MARC-8",
+        CharacterCodingScheme::Utf8 => "UCS/Unicode",
+        CharacterCodingScheme::Other(_) => "Other",
+    }
+}
+
+impl FromStr for Leader {
+    type Err = ParseLeaderError;
+
+    /// Parses a leader from a string, which must be exactly 24
+    /// characters long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader: Leader = "00827nam a2200241 c 4500".parse()?;
+    ///     assert_eq!(leader.record_length(), 827);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != LEADER_LEN {
+            return Err(ParseLeaderError::InvalidLength(s.len()));
+        }
+
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl TryFrom<&[u8]> for Leader {
+    type Error = ParseLeaderError;
+
+    /// Creates a leader from a byte slice, which must be exactly 24
+    /// bytes long.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader =
+    ///         Leader::try_from(&b"00827nam a2200241 c 4500"[..])?;
+    ///     assert_eq!(leader.record_length(), 827);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        if data.len() != LEADER_LEN {
+            return Err(ParseLeaderError::InvalidLength(data.len()));
+        }
+
+        Self::from_bytes(data)
+    }
+}
+
+impl TryFrom<[u8; LEADER_LEN]> for Leader {
+    type Error = ParseLeaderError;
+
+    /// Creates a leader from its 24 bytes/octets representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::try_from(*b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(leader.record_length(), 827);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn try_from(data: [u8; LEADER_LEN]) -> Result<Self, Self::Error> {
+        Self::from_bytes(&data)
+    }
+}
+
+impl fmt::Display for Leader {
+    /// Formats the leader as its 24-character string representation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(leader.to_string(), "00827nam a2200241 c 4500");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_bytes()
+            .iter()
+            .try_for_each(|&byte| f.write_char(byte as char))
+    }
+}
+
+impl fmt::Debug for Leader {
+    /// Formats the leader as its 24-character string representation
+    /// together with the decoded status, type, bibliographic level and
+    /// character coding scheme of the record. See [Leader::describe]
+    /// for a breakdown of all positions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Leader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert_eq!(
+    ///         format!("{leader:?}"),
+    ///         "Leader { raw: \"00827nam a2200241 c 4500\", \
+    ///         record_status: New, type_of_record: LanguageMaterial, \
+    ///         bibliographic_level: Monograph, \
+    ///         character_coding_scheme: Utf8 }"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Leader")
+            .field("raw", &self.to_string())
+            .field("record_status", &self.record_status())
+            .field("type_of_record", &self.type_of_record())
+            .field("bibliographic_level", &self.bibliographic_level())
+            .field(
+                "character_coding_scheme",
+                &self.character_coding_scheme(),
+            )
+            .finish()
+    }
+}
+
+/// A builder to construct a [Leader] programmatically.
+///
+/// Unless set explicitly, the record status is `n` (new), the
+/// character coding scheme is `a` (UCS/Unicode), the indicator count
+/// and the subfield code count are `2` and the entry map is `4500`.
+/// All other code positions are blank and the record length and the
+/// base address of data are zero.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{LeaderBuilder, RecordStatus, TypeOfRecord};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let leader = LeaderBuilder::new()
+///         .record_status(RecordStatus::Corrected)
+///         .type_of_record(TypeOfRecord::NotatedMusic)
+///         .build()?;
+///
+///     assert_eq!(&leader.to_bytes(), b"00000cc  a2200000   4500");
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct LeaderBuilder {
+    leader: Leader,
+    error: Option<ParseLeaderError>,
+}
+
+impl LeaderBuilder {
+    /// Creates a new leader builder with MARC 21 defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the first error, which is returned by [Self::build].
+    fn fail(&mut self, err: ParseLeaderError) {
+        if self.error.is_none() {
+            self.error = Some(err);
+        }
+    }
+
+    /// Writes the code into the given position of the leader.
+    fn set_code(
+        &mut self,
+        pos: usize,
+        code: char,
+        err: ParseLeaderError,
+    ) {
+        match single_byte(code, err) {
+            Ok(byte) => self.leader.raw[pos] = byte,
+            Err(e) => self.fail(e),
+        }
+    }
+
+    /// Returns the ASCII digit of the value, or `None` if the value is
+    /// greater than nine.
+    fn digit(value: u8) -> Option<u8> {
+        (value <= 9).then(|| b'0' + value)
+    }
+
+    /// Sets the length of the entire record.
+    ///
+    /// Lengths greater than 99999 cause [Self::build] to fail.
+    pub fn record_length(mut self, value: u32) -> Self {
+        if value > MAX_RECORD_LEN {
+            self.fail(ParseLeaderError::InvalidRecordLength);
+        } else {
+            self.leader.set_record_len(value);
+        }
+        self
+    }
+
+    /// Sets the status of the record.
+    pub fn record_status(mut self, value: RecordStatus) -> Self {
+        let err = ParseLeaderError::InvalidRecordStatus;
+        self.set_code(5, value.into(), err);
+        self
+    }
+
+    /// Sets the type of the record.
+    pub fn type_of_record(mut self, value: TypeOfRecord) -> Self {
+        let err = ParseLeaderError::InvalidTypeOfRecord;
+        self.set_code(6, value.into(), err);
+        self
+    }
+
+    /// Sets the bibliographic level of the record.
+    pub fn bibliographic_level(
+        mut self,
+        value: BibliographicLevel,
+    ) -> Self {
+        let err = ParseLeaderError::InvalidBibliographicLevel;
+        self.set_code(7, value.into(), err);
+        self
+    }
+
+    /// Sets the character coding scheme of the record.
+    pub fn character_coding_scheme(
+        mut self,
+        value: CharacterCodingScheme,
+    ) -> Self {
+        let err = ParseLeaderError::InvalidCharacterCodingScheme;
+        self.set_code(9, value.into(), err);
+        self
+    }
+
+    /// Sets the indicator count.
+    ///
+    /// Values greater than nine cause [Self::build] to fail.
+    pub fn indicator_count(mut self, value: u8) -> Self {
+        match Self::digit(value) {
+            Some(digit) => {
+                self.leader.indicator_count = value;
+                self.leader.raw[10] = digit;
+            }
+            None => self.fail(ParseLeaderError::InvalidIndicatorCount),
+        }
+        self
+    }
+
+    /// Sets the subfield code count.
+    ///
+    /// Values greater than nine cause [Self::build] to fail.
+    pub fn subfield_code_count(mut self, value: u8) -> Self {
+        match Self::digit(value) {
+            Some(digit) => {
+                self.leader.subfield_code_count = value;
+                self.leader.raw[11] = digit;
+            }
+            None => {
+                self.fail(ParseLeaderError::InvalidSubfieldCodeCount)
+            }
+        }
+        self
+    }
+
+    /// Sets the base address of data.
+    ///
+    /// Addresses greater than 99999 cause [Self::build] to fail.
+    pub fn base_address_of_data(mut self, value: u32) -> Self {
+        if value > MAX_RECORD_LEN {
+            self.fail(ParseLeaderError::InvalidBaseAddress);
+        } else {
+            self.leader.set_base_address(value);
+        }
+        self
+    }
+
+    /// Sets the entry map.
+    ///
+    /// Portions greater than nine cause [Self::build] to fail.
+    pub fn entry_map(mut self, value: EntryMap) -> Self {
+        let digits = [
+            value.length_of_field_portion,
+            value.starting_character_position_portion,
+            value.implementation_defined_portion,
+        ]
+        .map(Self::digit);
+
+        if let [Some(a), Some(b), Some(c)] = digits {
+            self.leader.entry_map = value;
+            self.leader.raw[20..23].copy_from_slice(&[a, b, c]);
+        } else {
+            self.fail(ParseLeaderError::InvalidEntryMap);
+        }
+        self
+    }
+
+    /// Consumes the builder and returns the constructed leader.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error caused by an invalid value passed to
+    /// one of the setters.
+    pub fn build(self) -> Result<Leader, ParseLeaderError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.leader),
+        }
+    }
+}
+
+/// Returns the byte of the code if it's a printable ASCII character
+/// (including a blank), and the given error otherwise.
+#[inline]
+fn single_byte(
+    code: char,
+    err: ParseLeaderError,
+) -> Result<u8, ParseLeaderError> {
+    if code == ' ' || code.is_ascii_graphic() {
+        Ok(code as u8)
+    } else {
+        Err(err)
+    }
+}
+
+/// Writes the record length into `data`. Lengths exceeding the
+/// maximum record length are written as `00000`.
+#[inline]
+pub(crate) fn write_record_len(data: &mut [u8], value: u32) {
+    if value > MAX_RECORD_LEN {
+        write_number(data, 0);
+    } else {
+        write_number(data, value);
+    }
+}
+
+/// Parse a number encoded as `width` right justified ASCII digits.
+#[inline]
+pub(crate) fn parse_number<'a, E: ParseError<&'a [u8]>>(
+    width: usize,
+) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], u32, E> {
+    fold_many_m_n(
+        width,
+        width,
+        satisfy(|ch| ch.is_ascii_digit()),
+        || 0,
+        |acc, n| acc * 10 + (n as u8 - b'0') as u32,
+    )
+}
+
+/// Writes `value` as right justified, zero-padded ASCII digits into
+/// `data`.
+#[inline]
+pub(crate) fn write_number(data: &mut [u8], mut value: u32) {
+    for byte in data.iter_mut().rev() {
+        *byte = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+}
+
+/// Returns the largest number which can be written with `width`
+/// digits.
+#[inline]
+pub(crate) fn max_number(width: u8) -> usize {
+    10usize.saturating_pow(width as u32) - 1
+}
+
+/// Parse a number encoded as a single ASCII digit.
+#[inline]
+fn parse_digit(i: &[u8]) -> ParseResult<'_, u8> {
+    map(satisfy(|ch| ch.is_ascii_digit()), |ch| ch as u8 - b'0')(i)
+}
+
+/// Parse the record length field.
+///
+/// The record length is encoded as five right justified ASCII digits.
+/// An unused positions is set to zero. The record length is between 0
+/// and 99999.
+#[inline]
+pub(crate) fn parse_record_len(i: &[u8]) -> ParseResult<'_, u32> {
+    parse_number(5)(i)
+}
+
+/// Returns the record length of the record starting at the beginning
+/// of `data`, without parsing the rest of the leader.
+///
+/// Only the first five bytes are read; any bytes after them are
+/// ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{peek_record_length, ParseLeaderError};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     assert_eq!(peek_record_length(b"00048nam a22")?, 48);
+///     assert!(matches!(
+///         peek_record_length(b"0004x"),
+///         Err(ParseLeaderError::InvalidRecordLength)
+///     ));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn peek_record_length(
+    data: &[u8],
+) -> Result<u32, ParseLeaderError> {
+    if data.len() < 5 {
+        return Err(ParseLeaderError::Incomplete(Needed::new(
+            5 - data.len(),
+        )));
+    }
+
+    parse_record_len(&data[..5])
+        .map(|(_, record_len)| record_len)
+        .map_err(|_| ParseLeaderError::InvalidRecordLength)
+}
+
+/// Parse the base address of data field.
+///
+/// Like the record length, the base address is encoded as five right
+/// justified ASCII digits.
+#[inline]
+fn parse_base_address(i: &[u8]) -> ParseResult<'_, u32> {
+    parse_number(5)(i)
+}
+
+/// Parse the record status field.
+///
+/// Unknown status codes are accepted as long as they are ASCII
+/// characters; they are mapped to [RecordStatus::Other].
+#[inline]
+fn parse_record_status(i: &[u8]) -> ParseResult<'_, char> {
+    satisfy(|ch| ch.is_ascii())(i)
+}
+
+/// Parse the type of record field.
+#[inline]
+fn parse_type_of_record(i: &[u8]) -> ParseResult<'_, char> {
+    satisfy(|ch| ch.is_ascii())(i)
+}
+
+/// Parse a code position, which may hold any ASCII character.
+#[inline]
+fn parse_ascii(i: &[u8]) -> ParseResult<'_, char> {
+    satisfy(|ch| ch.is_ascii())(i)
+}
+
+/// Parse the entry map (positions 20-22).
+#[inline]
+fn parse_entry_map(i: &[u8]) -> ParseResult<'_, EntryMap> {
+    map(
+        tuple((parse_digit, parse_digit, parse_digit)),
+        |(
+            length_of_field_portion,
+            starting_character_position_portion,
+            implementation_defined_portion,
+        )| EntryMap {
+            length_of_field_portion,
+            starting_character_position_portion,
+            implementation_defined_portion,
+        },
+    )(i)
+}
+
+/// Parse the full 24 bytes/octets of the leader.
+pub(crate) fn parse_leader(i: &[u8]) -> ParseResult<'_, Leader> {
+    if i.len() < LEADER_LEN {
+        return Err(ParseLeaderError::Incomplete(Needed::new(
+            LEADER_LEN - i.len(),
+        ))
+        .into());
+    }
+
+    let raw: [u8; LEADER_LEN] = i[..LEADER_LEN]
+        .try_into()
+        .expect("input has at least 24 bytes");
+
+    let (i, record_len) = parse_record_len(i)
+        .map_err(|_| ParseLeaderError::InvalidRecordLength)?;
+
+    // The code positions are only validated; their values are read
+    // from the raw bytes.
+    let (i, _) = parse_record_status(i)
+        .map_err(|_| ParseLeaderError::InvalidRecordStatus)?;
+
+    let (i, _) = parse_type_of_record(i)
+        .map_err(|_| ParseLeaderError::InvalidTypeOfRecord)?;
+    let (i, _) = parse_ascii(i)
+        .map_err(|_| ParseLeaderError::InvalidBibliographicLevel)?;
+    let (i, _) = parse_ascii(i)?;
+    let (i, _) = parse_ascii(i)
+        .map_err(|_| ParseLeaderError::InvalidCharacterCodingScheme)?;
+    let (i, indicator_count) = parse_digit(i)
+        .map_err(|_| ParseLeaderError::InvalidIndicatorCount)?;
+    let (i, subfield_code_count) = parse_digit(i)
+        .map_err(|_| ParseLeaderError::InvalidSubfieldCodeCount)?;
+    let (i, base_address) = parse_base_address(i)
+        .map_err(|_| ParseLeaderError::InvalidBaseAddress)?;
+    let (i, _) = parse_ascii(i)
+        .map_err(|_| ParseLeaderError::InvalidEncodingLevel)?;
+    let (i, _) = parse_ascii(i)?;
+    let (i, _) = parse_ascii(i)?;
+    let (i, entry_map) = parse_entry_map(i)
+        .map_err(|_| ParseLeaderError::InvalidEntryMap)?;
+    let (i, _) = parse_ascii(i)?;
+
+    Ok((
+        i,
+        Leader {
+            raw,
+            record_len,
+            indicator_count,
+            subfield_code_count,
+            base_address,
+            entry_map,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use nom_test_helpers::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_leader_from_bytes() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00123dam a2200049 c 4500")?;
+        assert_eq!(leader.record_length(), 123);
+        assert_eq!(leader.record_status(), RecordStatus::Deleted);
+
+        assert!(Leader::from_bytes(b"1234").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_from_bytes_non_ascii() {
+        for pos in [7, 8, 9, 17, 18, 19, 23] {
+            let mut data = *b"00123nam a2200049 c 4500";
+            data[pos] = 0xe9;
+            assert!(
+                Leader::from_bytes(&data).is_err(),
+                "position {pos}"
+            );
+        }
+
+        let mut data = *b"00123nam a2200049 c 4500";
+        data[7] = 0xe9;
+        assert!(matches!(
+            Leader::from_bytes(&data),
+            Err(ParseLeaderError::InvalidBibliographicLevel)
+        ));
+
+        data[7] = b'm';
+        data[9] = 0xe9;
+        assert!(matches!(
+            Leader::from_bytes(&data),
+            Err(ParseLeaderError::InvalidCharacterCodingScheme)
+        ));
+
+        data[9] = b'a';
+        data[17] = 0xe9;
+        assert!(matches!(
+            Leader::from_bytes(&data),
+            Err(ParseLeaderError::InvalidEncodingLevel)
+        ));
+    }
+
+    #[test]
+    fn test_leader_from_bytes_incomplete() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        assert_eq!(leader.record_length(), 827);
+        assert_eq!(leader.raw[6], b'a');
+        assert_eq!(leader.base_address, 241);
+        assert_eq!(leader.entry_map.length_of_field_portion, 4);
+
+        assert!(matches!(
+            Leader::from_bytes(b"00827"),
+            Err(ParseLeaderError::Incomplete(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_to_bytes() -> anyhow::Result<()> {
+        for data in [
+            b"00827nam a2200241 c 4500",
+            b"00005cz  a2200001n  4500",
+            b"99999dsmaa1100000#ia5501",
+        ] {
+            let leader = Leader::from_bytes(data)?;
+            assert_eq!(&leader.to_bytes(), data);
+            assert_eq!(
+                Leader::from_bytes(leader.to_bytes().as_slice())?,
+                leader
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_display() -> anyhow::Result<()> {
+        for data in
+            ["00827nam a2200241 c 4500", "00005cz  a2200001n  4500"]
+        {
+            let leader = Leader::from_bytes(data.as_bytes())?;
+            assert_eq!(leader.to_string().len(), 24);
+            assert_eq!(leader.to_string(), data);
+            assert_eq!(
+                Leader::from_bytes(leader.to_string().as_bytes())?,
+                leader
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_debug() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00005dj  a2200001n  4500")?;
+        let debug = format!("{leader:?}");
+        assert!(debug.contains("\"00005dj  a2200001n  4500\""));
+        assert!(debug.contains("record_status: Deleted"));
+        assert!(debug.contains("type_of_record: MusicalSoundRecording"));
+        assert!(debug.contains("bibliographic_level: Other(' ')"));
+
+        let debug = format!("{leader:#?}");
+        assert!(debug.contains(
+            "\n    type_of_record: MusicalSoundRecording,\n"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_from_str() -> anyhow::Result<()> {
+        let leader: Leader = "00827nam a2200241 c 4500".parse()?;
+        assert_eq!(
+            leader,
+            Leader::from_bytes(b"00827nam a2200241 c 4500")?
+        );
+
+        assert!(matches!(
+            "00827nam a2200241".parse::<Leader>(),
+            Err(ParseLeaderError::InvalidLength(17))
+        ));
+        assert!(matches!(
+            "00827nam a2200241 c 4500 ".parse::<Leader>(),
+            Err(ParseLeaderError::InvalidLength(25))
+        ));
+        assert!(matches!(
+            "0082xnam a2200241 c 4500".parse::<Leader>(),
+            Err(ParseLeaderError::InvalidRecordLength)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_builder() -> anyhow::Result<()> {
+        let leader = LeaderBuilder::new().build()?;
+        assert_eq!(&leader.to_bytes(), b"00000n   a2200000   4500");
+
+        let leader = LeaderBuilder::new()
+            .record_length(827)
+            .record_status(RecordStatus::New)
+            .type_of_record(TypeOfRecord::LanguageMaterial)
+            .bibliographic_level(BibliographicLevel::Monograph)
+            .character_coding_scheme(CharacterCodingScheme::Utf8)
+            .base_address_of_data(241)
+            .build()?;
+
+        let data = leader.to_bytes();
+        assert_eq!(data.len(), 24);
+        assert_eq!(&data, b"00827nam a2200241   4500");
+        assert_eq!(Leader::from_bytes(&data)?, leader);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_record_len() {
+        assert_finished_and_eq!(parse_record_len(b"99999"), 99999);
+        assert_finished_and_eq!(parse_record_len(b"12345"), 12345);
+        assert_finished_and_eq!(parse_record_len(b"00000"), 0);
+        assert_error!(parse_record_len(b"-1000"));
+        assert_error!(parse_record_len(b"1234"));
+    }
+
+    #[test]
+    fn test_peek_record_length() {
+        assert_eq!(
+            peek_record_length(b"00827\xff\x00garbage").unwrap(),
+            827
+        );
+        assert_eq!(peek_record_length(b"00827").unwrap(), 827);
+        assert!(matches!(
+            peek_record_length(b"0082"),
+            Err(ParseLeaderError::Incomplete(needed))
+                if needed == Needed::new(1)
+        ));
+        assert!(matches!(
+            peek_record_length(b"0 827nam"),
+            Err(ParseLeaderError::InvalidRecordLength)
+        ));
+    }
+
+    #[test]
+    fn test_parse_digit() {
+        assert_finished_and_eq!(parse_digit(b"0"), 0);
+        assert_finished_and_eq!(parse_digit(b"2"), 2);
+        assert_finished_and_eq!(parse_digit(b"9"), 9);
+        assert_error!(parse_digit(b"a"));
+        assert_error!(parse_digit(b""));
+    }
+
+    #[test]
+    fn test_indicator_and_subfield_code_count() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        assert_eq!(leader.indicator_count(), 2);
+        assert_eq!(leader.subfield_code_count(), 2);
+
+        let leader = Leader::from_bytes(b"00827nam a1100241 c 4500")?;
+        assert_eq!(leader.indicator_count(), 1);
+        assert_eq!(leader.subfield_code_count(), 1);
+
+        assert!(matches!(
+            Leader::from_bytes(b"00827nam ax200241 c 4500"),
+            Err(ParseLeaderError::InvalidIndicatorCount)
+        ));
+        assert!(matches!(
+            Leader::from_bytes(b"00827nam a2x00241 c 4500"),
+            Err(ParseLeaderError::InvalidSubfieldCodeCount)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_base_address() {
+        assert_finished_and_eq!(parse_base_address(b"99999"), 99999);
+        assert_finished_and_eq!(parse_base_address(b"12345"), 12345);
+        assert_finished_and_eq!(parse_base_address(b"00000"), 0);
+        assert_error!(parse_base_address(b"-1000"));
+        assert_error!(parse_base_address(b"1234"));
+    }
+
+    #[test]
+    fn test_base_address_of_data() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        assert_eq!(leader.base_address_of_data(), 241);
+
+        assert!(matches!(
+            Leader::from_bytes(b"00827nam a22002x1 c 4500"),
+            Err(ParseLeaderError::InvalidBaseAddress)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_entry_map() {
+        assert_finished_and_eq!(
+            parse_entry_map(b"450"),
+            EntryMap {
+                length_of_field_portion: 4,
+                starting_character_position_portion: 5,
+                implementation_defined_portion: 0,
+            }
+        );
+        assert_error!(parse_entry_map(b"4x0"));
+        assert_error!(parse_entry_map(b"45"));
+    }
+
+    #[test]
+    fn test_entry_map() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        assert_eq!(
+            leader.entry_map(),
+            EntryMap {
+                length_of_field_portion: 4,
+                starting_character_position_portion: 5,
+                implementation_defined_portion: 0,
+            }
+        );
+
+        assert!(matches!(
+            Leader::from_bytes(b"00827nam a2200241 c 45 0"),
+            Err(ParseLeaderError::InvalidEntryMap)
+        ));
+
+        Ok(())
     }
 
     #[test]
@@ -196,6 +2428,493 @@ mod tests {
             assert_finished_and_eq!(parse_record_status(&[c as u8]), c);
         }
 
-        assert_error!(parse_record_status(b"b"));
+        assert_error!(parse_record_status(&[0xff]));
+    }
+
+    #[test]
+    fn test_parse_type_of_record() {
+        assert_finished_and_eq!(parse_type_of_record(b"a"), 'a');
+        assert_finished_and_eq!(parse_type_of_record(b"b"), 'b');
+        assert_error!(parse_type_of_record(&[0xff]));
+    }
+
+    #[test]
+    fn test_type_of_record() -> anyhow::Result<()> {
+        for (code, type_of_record) in [
+            ('a', TypeOfRecord::LanguageMaterial),
+            ('c', TypeOfRecord::NotatedMusic),
+            ('e', TypeOfRecord::CartographicMaterial),
+            ('m', TypeOfRecord::ComputerFile),
+            ('b', TypeOfRecord::Other('b')),
+        ] {
+            let data = format!("00827n{code}m a2200241 c 4500");
+            let leader = Leader::from_bytes(data.as_bytes())?;
+            assert_eq!(leader.type_of_record(), type_of_record);
+            assert_eq!(char::from(type_of_record), code);
+        }
+
+        let mut data = *b"00827nam a2200241 c 4500";
+        data[6] = 0xff;
+        assert!(matches!(
+            Leader::from_bytes(&data),
+            Err(ParseLeaderError::InvalidTypeOfRecord)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bibliographic_level() -> anyhow::Result<()> {
+        for (code, level) in [
+            ('m', BibliographicLevel::Monograph),
+            ('s', BibliographicLevel::Serial),
+            ('i', BibliographicLevel::IntegratingResource),
+            ('#', BibliographicLevel::Other('#')),
+        ] {
+            let data = format!("00827na{code} a2200241 c 4500");
+            let leader = Leader::from_bytes(data.as_bytes())?;
+            assert_eq!(leader.bibliographic_level(), level);
+            assert_eq!(char::from(level), code);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_type_of_control() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        assert_eq!(
+            leader.type_of_control(),
+            TypeOfControl::NoSpecifiedType
+        );
+
+        let leader = Leader::from_bytes(b"00827npcaa2200241 c 4500")?;
+        assert_eq!(leader.type_of_control(), TypeOfControl::Archival);
+        assert_eq!(&leader.to_bytes()[8..9], b"a");
+
+        let leader = Leader::from_bytes(b"00827nam#a2200241 c 4500")?;
+        assert_eq!(leader.type_of_control(), TypeOfControl::Other('#'));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_character_coding_scheme() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00827nam  2200241 c 4500")?;
+        assert_eq!(
+            leader.character_coding_scheme(),
+            CharacterCodingScheme::Marc8
+        );
+
+        let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        assert_eq!(
+            leader.character_coding_scheme(),
+            CharacterCodingScheme::Utf8
+        );
+
+        let leader = Leader::from_bytes(b"00827nam z2200241 c 4500")?;
+        assert_eq!(
+            leader.character_coding_scheme(),
+            CharacterCodingScheme::Other('z')
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encoding_level() -> anyhow::Result<()> {
+        for (code, level) in [
+            (' ', EncodingLevel::Full),
+            ('7', EncodingLevel::Minimal),
+            ('3', EncodingLevel::AbbreviatedLevel),
+            ('K', EncodingLevel::Other('K')),
+        ] {
+            let mut data = *b"00827nam a2200241 c 4500";
+            data[17] = code as u8;
+
+            let leader = Leader::from_bytes(&data)?;
+            assert_eq!(leader.encoding_level(), level);
+            assert_eq!(char::from(level), code);
+        }
+
+        let mut leader =
+            Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        leader.set_encoding_level(EncodingLevel::CoreLevel)?;
+        assert_eq!(&leader.to_bytes(), b"00827nam a22002414c 4500");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_descriptive_cataloging_form() -> anyhow::Result<()> {
+        for (code, form) in [
+            ('a', DescriptiveCatalogingForm::AACR2),
+            ('i', DescriptiveCatalogingForm::ISBDPunctuationIncluded),
+            (' ', DescriptiveCatalogingForm::NonISBD),
+            ('x', DescriptiveCatalogingForm::Other('x')),
+        ] {
+            let mut data = *b"00827nam a2200241 c 4500";
+            data[18] = code as u8;
+
+            let leader = Leader::from_bytes(&data)?;
+            assert_eq!(leader.descriptive_cataloging_form(), form);
+            assert_eq!(char::from(form), code);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multipart_resource_record_level() -> anyhow::Result<()> {
+        for (code, level) in [
+            (' ', MultipartResourceRecordLevel::NotSpecified),
+            ('a', MultipartResourceRecordLevel::Set),
+            (
+                'b',
+                MultipartResourceRecordLevel::PartWithIndependentTitle,
+            ),
+            ('c', MultipartResourceRecordLevel::PartWithDependentTitle),
+            ('d', MultipartResourceRecordLevel::Other('d')),
+        ] {
+            let mut data = *b"00827nam a2200241 c 4500";
+            data[19] = code as u8;
+
+            let leader = Leader::from_bytes(&data)?;
+            assert_eq!(leader.multipart_resource_record_level(), level);
+            assert_eq!(char::from(level), code);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_status() -> anyhow::Result<()> {
+        for (code, status) in [
+            ('a', RecordStatus::Increase),
+            ('c', RecordStatus::Corrected),
+            ('d', RecordStatus::Deleted),
+            ('n', RecordStatus::New),
+            ('p', RecordStatus::IncreaseFromPrepublication),
+            ('x', RecordStatus::Other('x')),
+        ] {
+            let data = format!("00827{code}am a2200241 c 4500");
+            let leader = Leader::from_bytes(data.as_bytes())?;
+            assert_eq!(leader.record_status(), status);
+            assert_eq!(char::from(status), code);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_semantic_eq() -> anyhow::Result<()> {
+        let a = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        let b = Leader::from_bytes(b"01234nam a2200099 c 4500")?;
+        assert!(a.semantic_eq(&b));
+        assert!(b.semantic_eq(&a));
+        assert_eq!(a, b);
+        assert_ne!(a.to_bytes(), b.to_bytes());
+
+        let c = Leader::from_bytes(b"00827cam a2200241 c 4500")?;
+        assert!(!a.semantic_eq(&c));
+
+        let d = Leader::from_bytes(b"00827nas a2200241 c 4500")?;
+        assert!(!a.semantic_eq(&d));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_hash() -> anyhow::Result<()> {
+        use std::collections::HashSet;
+
+        let a = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        let b = Leader::from_bytes(b"01234nam a2200099 c 4500")?;
+        let c = Leader::from_bytes(b"00827cam a2200241 c 4500")?;
+
+        let mut set = HashSet::new();
+        assert!(set.insert(a));
+        assert!(!set.insert(b));
+        assert!(set.insert(c));
+        assert_eq!(set.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00827cam a2200241 c 4500")?;
+        let description = leader.describe();
+
+        assert!(description.starts_with("record length: 827\n"));
+        assert!(
+            description.contains("status: Corrected or revised (c)\n")
+        );
+        assert!(description.contains("type: Language material (a)\n"));
+        assert!(description
+            .contains("bibliographic level: Monograph/Item (m)\n"));
+        assert!(description
+            .contains("character coding scheme: UCS/Unicode (a)\n"));
+        assert!(description.contains("base address of data: 241\n"));
+        assert!(description
+            .contains("type of control: No specified type ( )\n"));
+        assert!(
+            description.contains("encoding level: Full level ( )\n")
+        );
+        assert!(description.contains(
+            "descriptive cataloging form: ISBD punctuation omitted (c)\n"
+        ));
+        assert!(description.contains(
+            "multipart resource record level: Not specified or not \
+            applicable ( )\n"
+        ));
+        assert!(description.ends_with("entry map: 4500\n"));
+        assert_eq!(description.lines().count(), 13);
+
+        let leader = Leader::from_bytes(b"00827xyz a2200241 c 4500")?;
+        assert!(leader.describe().contains("status: Other (x)\n"));
+
+        let leader = Leader::from_bytes(b"00827nam a2200241Iia4500")?;
+        let description = leader.describe();
+        assert!(description.contains("encoding level: Other (I)\n"));
+        assert!(description
+            .contains("multipart resource record level: Set (a)\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_classifiers() -> anyhow::Result<()> {
+        let serial = Leader::from_bytes(b"00827cas a2200241 c 4500")?;
+        assert!(serial.is_serial());
+        assert!(!serial.is_monograph());
+        assert!(serial.is_bibliographic());
+        assert!(!serial.is_authority());
+        assert!(!serial.is_holdings());
+
+        let monograph =
+            Leader::from_bytes(b"00827ntm a2200241 c 4500")?;
+        assert!(monograph.is_monograph());
+        assert!(!monograph.is_serial());
+        assert!(monograph.is_bibliographic());
+
+        let authority =
+            Leader::from_bytes(b"00827nz  a2200241 n 4500")?;
+        assert!(!authority.is_serial());
+        assert!(!authority.is_monograph());
+        assert!(!authority.is_bibliographic());
+        assert!(authority.is_authority());
+        assert!(!authority.is_holdings());
+
+        let holdings = Leader::from_bytes(b"00827nx  a2200241 1 4500")?;
+        assert!(!holdings.is_bibliographic());
+        assert!(holdings.is_holdings());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_default() -> anyhow::Result<()> {
+        let leader = Leader::default();
+        let data = leader.to_bytes();
+
+        assert_eq!(data.len(), 24);
+        assert!(data.is_ascii());
+        assert_eq!(&data, b"00000n   a2200000   4500");
+        assert_eq!(leader.record_status(), RecordStatus::New);
+        assert_eq!(
+            leader.character_coding_scheme(),
+            CharacterCodingScheme::Utf8
+        );
+        assert_eq!(Leader::from_bytes(&data)?, leader);
+        assert_eq!(LeaderBuilder::new().build()?, leader);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_leader_builder_invalid() {
+        assert!(matches!(
+            LeaderBuilder::new().record_length(100_000).build(),
+            Err(ParseLeaderError::InvalidRecordLength)
+        ));
+        assert!(matches!(
+            LeaderBuilder::new().base_address_of_data(100_000).build(),
+            Err(ParseLeaderError::InvalidBaseAddress)
+        ));
+        assert!(matches!(
+            LeaderBuilder::new().indicator_count(10).build(),
+            Err(ParseLeaderError::InvalidIndicatorCount)
+        ));
+        assert!(matches!(
+            LeaderBuilder::new().subfield_code_count(255).build(),
+            Err(ParseLeaderError::InvalidSubfieldCodeCount)
+        ));
+        assert!(matches!(
+            LeaderBuilder::new()
+                .entry_map(EntryMap {
+                    length_of_field_portion: 4,
+                    starting_character_position_portion: 10,
+                    implementation_defined_portion: 0,
+                })
+                .build(),
+            Err(ParseLeaderError::InvalidEntryMap)
+        ));
+        assert!(matches!(
+            LeaderBuilder::new()
+                .record_status(RecordStatus::Other('ä'))
+                .build(),
+            Err(ParseLeaderError::InvalidRecordStatus)
+        ));
+
+        // The first error wins.
+        assert!(matches!(
+            LeaderBuilder::new()
+                .indicator_count(10)
+                .record_length(100_000)
+                .build(),
+            Err(ParseLeaderError::InvalidIndicatorCount)
+        ));
+    }
+
+    #[test]
+    fn test_format() -> anyhow::Result<()> {
+        let format = |leader: &[u8]| {
+            Leader::from_bytes(leader).map(|l| l.format())
+        };
+
+        assert_eq!(
+            format(b"00827nz  a2200241 n 4500")?,
+            MarcFormat::Authority
+        );
+        assert_eq!(
+            format(b"00827nam a2200241 c 4500")?,
+            MarcFormat::Bibliographic
+        );
+        assert_eq!(
+            format(b"00827ntm a2200241 c 4500")?,
+            MarcFormat::Bibliographic
+        );
+        assert_eq!(
+            format(b"00827ny  a2200241 n 4500")?,
+            MarcFormat::Holdings
+        );
+        assert_eq!(
+            format(b"00827nu  a2200241 n 4500")?,
+            MarcFormat::Holdings
+        );
+        assert_eq!(
+            format(b"00827nw  a2200241 n 4500")?,
+            MarcFormat::Classification
+        );
+        assert_eq!(
+            format(b"00827nq  a2200241 n 4500")?,
+            MarcFormat::Community
+        );
+        assert_eq!(
+            format(b"00827n   a2200241 n 4500")?,
+            MarcFormat::Unknown
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_size() {
+        fn assert_copy<T: Copy>() {}
+
+        assert_copy::<Leader>();
+        assert!(core::mem::size_of::<Leader>() <= 64);
+    }
+
+    #[test]
+    fn test_position() -> anyhow::Result<()> {
+        use crate::leader_positions::*;
+
+        let leader = Leader::from_bytes(b"00827cpcaa22002417ia4500")?;
+        assert_eq!(
+            leader.position(TYPE_OF_RECORD),
+            Some(char::from(leader.type_of_record()) as u8)
+        );
+        assert_eq!(leader.position(TYPE_OF_RECORD), Some(b'p'));
+        assert_eq!(leader.position(RECORD_LENGTH), Some(b'0'));
+        assert_eq!(leader.position(ENCODING_LEVEL), Some(b'7'));
+        assert_eq!(leader.position(UNDEFINED), Some(b'0'));
+        assert_eq!(leader.position(LEADER_LEN), None);
+
+        for index in 0..LEADER_LEN {
+            assert_eq!(
+                leader.position(index),
+                Some(leader.raw()[index])
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_raw() -> anyhow::Result<()> {
+        let data = b"00827cpcaa22002417ia4500";
+        let leader = Leader::from_bytes(data)?;
+        assert_eq!(leader.raw(), data);
+        assert_eq!(leader.raw(), &leader.to_bytes());
+
+        let mut leader = leader.with_length(1234)?;
+        leader.set_record_status(RecordStatus::Deleted)?;
+        assert_eq!(leader.raw(), b"01234dpcaa22002417ia4500");
+        assert_eq!(Leader::from_bytes(leader.raw())?, leader);
+
+        let leader = LeaderBuilder::new()
+            .base_address_of_data(37)
+            .indicator_count(1)
+            .build()?;
+        assert_eq!(leader.raw(), b"00000n   a1200037   4500");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from() -> anyhow::Result<()> {
+        let data = b"00827nam a2200241 c 4500";
+        let leader = Leader::from_bytes(data)?;
+
+        assert_eq!(Leader::try_from(&data[..])?, leader);
+        assert_eq!(Leader::try_from(*data)?, leader);
+
+        assert!(matches!(
+            Leader::try_from(&data[..23]),
+            Err(ParseLeaderError::InvalidLength(23))
+        ));
+        assert!(matches!(
+            Leader::try_from(&b"00827nam a2200241 c 4500\x1e"[..]),
+            Err(ParseLeaderError::InvalidLength(25))
+        ));
+        assert!(matches!(
+            Leader::try_from(*b"0082xnam a2200241 c 4500"),
+            Err(ParseLeaderError::InvalidRecordLength)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_length() -> anyhow::Result<()> {
+        let leader = Leader::default().with_length(99_999)?;
+        assert_eq!(&leader.to_bytes()[..5], b"99999");
+
+        let leader = leader.with_length(0)?;
+        assert_eq!(&leader.to_bytes()[..5], b"00000");
+
+        assert!(matches!(
+            leader.with_length(100_000),
+            Err(ParseLeaderError::InvalidRecordLength)
+        ));
+        assert!(matches!(
+            leader.with_length(u32::MAX),
+            Err(ParseLeaderError::InvalidRecordLength)
+        ));
+
+        Ok(())
     }
 }
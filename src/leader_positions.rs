@@ -0,0 +1,75 @@
+//! Positions (byte offsets) of the elements of the
+//! [Leader](crate::Leader).
+//!
+//! Elements spanning several positions are given by their first
+//! position. The positions can be read with
+//! [Leader::position](crate::Leader::position).
+//!
+//! # Example
+//!
+//! ```rust
+//! use marc21::leader_positions::{
+//!     BIBLIOGRAPHIC_LEVEL, RECORD_STATUS,
+//! };
+//! use marc21::Leader;
+//!
+//! # fn main() { example().unwrap(); }
+//! fn example() -> anyhow::Result<()> {
+//!     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+//!     assert_eq!(leader.position(RECORD_STATUS), Some(b'n'));
+//!     assert_eq!(leader.position(BIBLIOGRAPHIC_LEVEL), Some(b'm'));
+//!
+//!     Ok(())
+//! }
+//! ```
+
+/// Record length (positions 00-04).
+pub const RECORD_LENGTH: usize = 0;
+
+/// Record status (position 05).
+pub const RECORD_STATUS: usize = 5;
+
+/// Type of record (position 06).
+pub const TYPE_OF_RECORD: usize = 6;
+
+/// Bibliographic level (position 07).
+pub const BIBLIOGRAPHIC_LEVEL: usize = 7;
+
+/// Type of control (position 08).
+pub const TYPE_OF_CONTROL: usize = 8;
+
+/// Character coding scheme (position 09).
+pub const CHARACTER_CODING_SCHEME: usize = 9;
+
+/// Indicator count (position 10).
+pub const INDICATOR_COUNT: usize = 10;
+
+/// Subfield code count (position 11).
+pub const SUBFIELD_CODE_COUNT: usize = 11;
+
+/// Base address of data (positions 12-16).
+pub const BASE_ADDRESS: usize = 12;
+
+/// Encoding level (position 17).
+pub const ENCODING_LEVEL: usize = 17;
+
+/// Descriptive cataloging form (position 18).
+pub const DESCRIPTIVE_CATALOGING_FORM: usize = 18;
+
+/// Multipart resource record level (position 19).
+pub const MULTIPART_RESOURCE_RECORD_LEVEL: usize = 19;
+
+/// Length of the length-of-field portion of a directory entry
+/// (position 20).
+pub const LENGTH_OF_FIELD_PORTION: usize = 20;
+
+/// Length of the starting-character-position portion of a directory
+/// entry (position 21).
+pub const LENGTH_OF_STARTING_POSITION: usize = 21;
+
+/// Length of the implementation-defined portion of a directory entry
+/// (position 22).
+pub const LENGTH_OF_IMPLEMENTATION_DEFINED_PORTION: usize = 22;
+
+/// Undefined (position 23).
+pub const UNDEFINED: usize = 23;
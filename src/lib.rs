@@ -1,4 +1,116 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod aleph;
+#[cfg(feature = "tokio")]
+mod async_reader;
+mod control_field;
+mod data_field;
+#[cfg(feature = "chrono")]
+mod datetime;
+mod diff;
+mod directory;
+pub mod dublin_core;
 mod error;
+mod field;
+mod field_tag;
+mod fixed_field;
+pub mod flatten;
+mod holdings;
+mod identifiers;
+mod index;
+#[cfg(feature = "serde")]
+mod json;
+mod language;
 mod leader;
+pub mod leader_positions;
+mod linkage;
+mod main_entry;
+#[cfg(feature = "indexmap")]
+mod map;
+mod marc8;
+#[cfg(feature = "std")]
+mod marcxml;
+#[cfg(feature = "memmap")]
+mod mmap;
+mod mrk;
+mod options;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod publication;
+mod reader;
+mod record;
+mod record_ref;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod split;
+mod subfield;
+pub mod tags;
+#[cfg(feature = "unicode")]
+mod unicode;
+mod validation;
+#[cfg(feature = "std")]
+mod writer;
+
+#[cfg(feature = "tokio")]
+pub use async_reader::AsyncMarcReader;
+pub use control_field::ControlField;
+pub use data_field::{DataField, DataFieldError, InvalidIndicator};
+pub use diff::{FieldChange, LeaderChange};
+pub use directory::{Directory, DirectoryEntry};
+pub use error::{ParseRecordError, SerializeError};
+pub use field::{Field, Fields};
+pub use field_tag::{FieldTag, ParseFieldTagError};
+pub use fixed_field::{
+    CategoryOfMaterial, MaterialType, Oo6, Oo7, Oo8,
+    ParseFixedFieldError,
+};
+pub use holdings::HoldingsLocation;
+pub use index::FieldIndex;
+#[cfg(feature = "serde")]
+pub use json::{
+    convert_to_ndjson, MarcJsonLinesWriter, ParseMarcJsonError,
+};
+pub use leader::{
+    peek_record_length, BibliographicLevel, CharacterCodingScheme,
+    DescriptiveCatalogingForm, EncodingLevel, EntryMap, Leader,
+    LeaderBuilder, MarcFormat, MultipartResourceRecordLevel,
+    ParseLeaderError, RecordStatus, TypeOfControl, TypeOfRecord,
+};
+pub use main_entry::MainEntry;
+pub use marc8::{decode_marc8, Marc8Error};
+#[cfg(feature = "std")]
+pub use marcxml::{
+    from_oai_marcxml, records_from_marcxml_collection,
+    records_to_marcxml_collection, MarcXmlWriter, ParseMarcXmlError,
+};
+#[cfg(feature = "memmap")]
+pub use mmap::MarcMmapReader;
+pub use mrk::ParseMrkError;
+pub use options::{ParseOptions, ParseWarning, Utf8Validation};
+#[cfg(feature = "rayon")]
+pub use parallel::par_records;
+pub use publication::{Publication, PublicationFunction};
+#[cfg(feature = "std")]
+pub use reader::{count_records, MarcReader};
+pub use reader::{
+    parse_records_prefix, raw_records, records, records_lenient,
+    records_with, Records,
+};
+pub use record::{Record, RecordBuilder};
+pub use record_ref::{FieldRef, RecordRef};
+pub use subfield::Subfield;
+pub use validation::{LeaderIssue, ValidationIssue};
+#[cfg(feature = "std")]
+pub use writer::MarcWriter;
+
+/// The field terminator (`0x1E`), which ends the directory and each
+/// variable field.
+pub(crate) const FIELD_TERMINATOR: u8 = 0x1e;
+
+/// The subfield delimiter (`0x1F`), which introduces a subfield.
+pub(crate) const SUBFIELD_DELIMITER: u8 = 0x1f;
 
-pub use leader::{Leader, ParseLeaderError};
+/// The record terminator (`0x1D`), which ends a record.
+pub(crate) const RECORD_TERMINATOR: u8 = 0x1d;
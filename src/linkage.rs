@@ -0,0 +1,223 @@
+use crate::{DataField, Record};
+
+/// The tag of the alternate graphic representation field.
+const ALTERNATE_GRAPHIC_TAG: &[u8; 3] = b"880";
+
+/// Splits the `$6` linkage subfield of the field (e.g. `880-01/$1`)
+/// into the linked tag and the occurrence number. A field without
+/// linkage or with the occurrence number `00` (no linked field) yields
+/// `None`.
+fn linkage(field: &DataField) -> Option<(&[u8], &[u8])> {
+    let value = field.subfield(b'6')?.value();
+    let (tag, rest) = value.split_first_chunk::<3>()?;
+    let rest = rest.strip_prefix(b"-")?;
+    let end =
+        rest.iter().position(|b| *b == b'/').unwrap_or(rest.len());
+    let occurrence = &rest[..end];
+
+    if occurrence.is_empty()
+        || !occurrence.iter().all(u8::is_ascii_digit)
+        || occurrence.iter().all(|b| *b == b'0')
+    {
+        return None;
+    }
+
+    Some((tag.as_slice(), occurrence))
+}
+
+impl Record {
+    /// Returns the alternate graphic representation (`880`) field
+    /// linked to the given field, e.g. the title in its original
+    /// script.
+    ///
+    /// The field's `$6` linkage subfield (e.g. `880-01`) names the
+    /// occurrence number, and the `880` field whose `$6` points back
+    /// to the field's tag with the same occurrence number (e.g.
+    /// `245-01`) is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"245",
+    ///             b'1',
+    ///             b'0',
+    ///             [
+    ///                 Subfield::new(b'6', "880-01"),
+    ///                 Subfield::new(b'a', "Voina i mir"),
+    ///             ],
+    ///         )
+    ///         .data_field(
+    ///             b"880",
+    ///             b'1',
+    ///             b'0',
+    ///             [
+    ///                 Subfield::new(b'6', "245-01/(N"),
+    ///                 Subfield::new(b'a', "Война и мир"),
+    ///             ],
+    ///         )
+    ///         .build();
+    ///
+    ///     let field = record.fields(b"245").next().unwrap();
+    ///     let linked = record.linked_880(field).unwrap();
+    ///     assert_eq!(
+    ///         linked.subfield(b'a').unwrap().value(),
+    ///         "Война и мир".as_bytes()
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn linked_880(&self, field: &DataField) -> Option<&DataField> {
+        let (tag, occurrence) = linkage(field)?;
+        if tag != ALTERNATE_GRAPHIC_TAG {
+            return None;
+        }
+
+        self.fields(ALTERNATE_GRAPHIC_TAG).find(|other| {
+            linkage(other) == Some((field.tag.as_slice(), occurrence))
+        })
+    }
+
+    /// Returns the regular field linked to the given alternate graphic
+    /// representation (`880`) field; this is the reverse of
+    /// [Record::linked_880].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"245",
+    ///             b'1',
+    ///             b'0',
+    ///             [Subfield::new(b'6', "880-01")],
+    ///         )
+    ///         .data_field(
+    ///             b"880",
+    ///             b'1',
+    ///             b'0',
+    ///             [Subfield::new(b'6', "245-01")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let field = record.fields(b"880").next().unwrap();
+    ///     let linked = record.linked_field(field).unwrap();
+    ///     assert_eq!(linked.tag(), b"245");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn linked_field(
+        &self,
+        field: &DataField,
+    ) -> Option<&DataField> {
+        if &field.tag != ALTERNATE_GRAPHIC_TAG {
+            return None;
+        }
+
+        let (tag, occurrence) = linkage(field)?;
+        self.data_fields.iter().find(|other| {
+            other.tag.as_slice() == tag
+                && linkage(other)
+                    == Some((
+                        ALTERNATE_GRAPHIC_TAG.as_slice(),
+                        occurrence,
+                    ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{RecordBuilder, Subfield};
+
+    #[test]
+    fn test_linked_880() {
+        let record = RecordBuilder::new()
+            .data_field(
+                b"100",
+                b'1',
+                b' ',
+                [
+                    Subfield::new(b'6', "880-02"),
+                    Subfield::new(b'a', "Tolstoy, Leo,"),
+                ],
+            )
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [
+                    Subfield::new(b'6', "880-01"),
+                    Subfield::new(b'a', "Voina i mir"),
+                ],
+            )
+            .data_field(
+                b"500",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "Note")],
+            )
+            .data_field(
+                b"880",
+                b'1',
+                b'0',
+                [
+                    Subfield::new(b'6', "245-01/(N"),
+                    Subfield::new(b'a', "Война и мир"),
+                ],
+            )
+            .data_field(
+                b"880",
+                b'1',
+                b' ',
+                [
+                    Subfield::new(b'6', "100-02/(N"),
+                    Subfield::new(b'a', "Толстой, Лев,"),
+                ],
+            )
+            .data_field(
+                b"880",
+                b' ',
+                b' ',
+                [
+                    Subfield::new(b'6', "500-00/(N"),
+                    Subfield::new(b'a', "Примечание"),
+                ],
+            )
+            .build();
+
+        let mut fields = record.data_fields();
+        let author = fields.next().unwrap();
+        let title = fields.next().unwrap();
+        let note = fields.next().unwrap();
+
+        let linked = record.linked_880(title).unwrap();
+        assert_eq!(
+            linked.subfield(b'a').unwrap().value(),
+            "Война и мир".as_bytes()
+        );
+        assert_eq!(record.linked_field(linked), Some(title));
+
+        let linked = record.linked_880(author).unwrap();
+        assert_eq!(linked.ind2(), b' ');
+        assert_eq!(record.linked_field(linked), Some(author));
+
+        assert_eq!(record.linked_880(note), None);
+        assert_eq!(record.linked_field(title), None);
+
+        let unlinked = record.fields(b"880").nth(2).unwrap();
+        assert_eq!(record.linked_field(unlinked), None);
+        assert_eq!(record.linked_880(linked), None);
+    }
+}
@@ -0,0 +1,192 @@
+use alloc::vec::Vec;
+
+use crate::{DataField, Record};
+
+/// The main entry of a record, i.e. the primary personal name (`100`),
+/// corporate name (`110`) or meeting name (`111`).
+///
+/// Each variant carries the name (`$a`) and the relator terms (`$e`)
+/// and codes (`$4`) in the order they appear in the field.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MainEntry<'a> {
+    /// A personal name (`100`).
+    Personal {
+        name: &'a [u8],
+        relators: Vec<&'a [u8]>,
+    },
+    /// A corporate name (`110`).
+    Corporate {
+        name: &'a [u8],
+        relators: Vec<&'a [u8]>,
+    },
+    /// A meeting name (`111`).
+    Meeting {
+        name: &'a [u8],
+        relators: Vec<&'a [u8]>,
+    },
+}
+
+impl<'a> MainEntry<'a> {
+    /// Returns the name (`$a`) of the main entry.
+    pub fn name(&self) -> &'a [u8] {
+        match self {
+            Self::Personal { name, .. }
+            | Self::Corporate { name, .. }
+            | Self::Meeting { name, .. } => name,
+        }
+    }
+
+    /// Returns the relator terms (`$e`) and codes (`$4`) of the main
+    /// entry.
+    pub fn relators(&self) -> &[&'a [u8]] {
+        match self {
+            Self::Personal { relators, .. }
+            | Self::Corporate { relators, .. }
+            | Self::Meeting { relators, .. } => relators,
+        }
+    }
+}
+
+/// Returns the name and the relators of a main entry field. A field
+/// without a `$a` subfield yields `None`.
+fn name_and_relators(field: &DataField) -> Option<(&[u8], Vec<&[u8]>)> {
+    let name = field.subfield(b'a')?.value();
+    let relators = field
+        .subfields
+        .iter()
+        .filter(|subfield| matches!(subfield.code, b'e' | b'4'))
+        .map(|subfield| subfield.value())
+        .collect();
+
+    Some((name, relators))
+}
+
+impl Record {
+    /// Returns the main entry of the record, taken from the first
+    /// `100`, `110` or `111` field in directory order. A valid record
+    /// has at most one of these fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{MainEntry, RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"100",
+    ///             b'1',
+    ///             b' ',
+    ///             [
+    ///                 Subfield::new(b'a', "Tolstoy, Leo,"),
+    ///                 Subfield::new(b'e', "author."),
+    ///             ],
+    ///         )
+    ///         .build();
+    ///
+    ///     let entry = record.main_entry().unwrap();
+    ///     assert!(matches!(entry, MainEntry::Personal { .. }));
+    ///     assert_eq!(entry.name(), b"Tolstoy, Leo,");
+    ///     assert_eq!(entry.relators(), [b"author."]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn main_entry(&self) -> Option<MainEntry<'_>> {
+        self.data_fields.iter().find_map(|field| {
+            let (name, relators) = match &field.tag {
+                b"100" | b"110" | b"111" => name_and_relators(field)?,
+                _ => return None,
+            };
+
+            Some(match &field.tag {
+                b"100" => MainEntry::Personal { name, relators },
+                b"110" => MainEntry::Corporate { name, relators },
+                _ => MainEntry::Meeting { name, relators },
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RecordBuilder, Subfield};
+
+    #[test]
+    fn test_main_entry_personal() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"100",
+                b'1',
+                b' ',
+                [
+                    Subfield::new(b'a', "Tolstoy, Leo,"),
+                    Subfield::new(b'd', "1828-1910,"),
+                    Subfield::new(b'e', "author."),
+                    Subfield::new(b'4', "aut"),
+                ],
+            )
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Voina i mir")],
+            )
+            .build();
+
+        assert_eq!(
+            record.main_entry(),
+            Some(MainEntry::Personal {
+                name: b"Tolstoy, Leo,",
+                relators: vec![b"author.", b"aut"],
+            })
+        );
+    }
+
+    #[test]
+    fn test_main_entry_corporate() {
+        let record = RecordBuilder::new()
+            .data_field(
+                b"110",
+                b'2',
+                b' ',
+                [Subfield::new(b'a', "United Nations.")],
+            )
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Yearbook")],
+            )
+            .build();
+
+        let entry = record.main_entry().unwrap();
+        assert!(matches!(entry, MainEntry::Corporate { .. }));
+        assert_eq!(entry.name(), b"United Nations.");
+        assert!(entry.relators().is_empty());
+
+        let record = RecordBuilder::new()
+            .data_field(
+                b"100",
+                b'1',
+                b' ',
+                [Subfield::new(b'd', "1900")],
+            )
+            .data_field(
+                b"111",
+                b'2',
+                b' ',
+                [Subfield::new(b'a', "Conference")],
+            )
+            .build();
+        assert!(matches!(
+            record.main_entry(),
+            Some(MainEntry::Meeting { .. })
+        ));
+
+        assert_eq!(RecordBuilder::new().build().main_entry(), None);
+    }
+}
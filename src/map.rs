@@ -0,0 +1,146 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use indexmap::IndexMap;
+
+use crate::{Field, Record};
+
+impl Record {
+    /// Converts the record into a map from keys to the list of their
+    /// values, e.g. for display or templating.
+    ///
+    /// Subfield values are keyed by the tag and the subfield code
+    /// (e.g. `245$a`), the data of control fields by the tag only.
+    /// The keys are ordered by their first occurrence and the values
+    /// of each key keep the order of the record, including repeats.
+    /// Bytes which aren't valid UTF-8 are replaced by `U+FFFD`. The
+    /// map doesn't retain the leader or the indicators and can't be
+    /// converted back into a record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .control_field(b"001", "123456789")
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Arithmetic")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let map = record.to_map();
+    ///     assert_eq!(map["001"], ["123456789"]);
+    ///     assert_eq!(map["245$a"], ["Arithmetic"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_map(&self) -> IndexMap<String, Vec<String>> {
+        let mut map: IndexMap<String, Vec<String>> = IndexMap::new();
+
+        for field in self.all_fields() {
+            match field {
+                Field::Control(field) => {
+                    map.entry(
+                        String::from_utf8_lossy(&field.tag)
+                            .into_owned(),
+                    )
+                    .or_default()
+                    .push(
+                        String::from_utf8_lossy(&field.data)
+                            .into_owned(),
+                    );
+                }
+                Field::Data(field) => {
+                    for subfield in field.subfields.iter() {
+                        let key = format!(
+                            "{}${}",
+                            String::from_utf8_lossy(&field.tag),
+                            subfield.code as char
+                        );
+
+                        map.entry(key).or_default().push(
+                            String::from_utf8_lossy(&subfield.value)
+                                .into_owned(),
+                        );
+                    }
+                }
+            }
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Record, RecordBuilder, Subfield};
+
+    #[test]
+    fn test_to_map() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [
+                    Subfield::new(b'a', "Arithmetic /"),
+                    Subfield::new(b'c', "Carl Sandburg."),
+                    Subfield::new(b'b', "poems"),
+                ],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [
+                    Subfield::new(b'a', "Arithmetic"),
+                    Subfield::new(b'v', "Juvenile poetry."),
+                ],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Children's poetry, American.")],
+            )
+            .build();
+
+        let map = record.to_map();
+        assert_eq!(
+            map.keys().collect::<Vec<_>>(),
+            ["001", "245$a", "245$c", "245$b", "650$a", "650$v"]
+        );
+        assert_eq!(map["001"], ["123456789"]);
+        assert_eq!(map["245$c"], ["Carl Sandburg."]);
+        assert_eq!(
+            map["650$a"],
+            ["Arithmetic", "Children's poetry, American."]
+        );
+        assert!(map.get("500$a").is_none());
+    }
+
+    #[test]
+    fn test_to_map_field_order() -> anyhow::Result<()> {
+        // The 245 field precedes the 008 field.
+        let record = Record::from_bytes(
+            b"00067nam a2200049 c 4500245001000000008000700010\x1e\
+            00\x1faTitle\x1e740101\x1e\x1d",
+        )?;
+
+        assert_eq!(
+            record.to_map().keys().collect::<Vec<_>>(),
+            ["245$a", "008"]
+        );
+
+        Ok(())
+    }
+}
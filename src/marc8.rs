@@ -0,0 +1,555 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{CharacterCodingScheme, Record};
+
+/// An error that can occur when decoding MARC-8 encoded data.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Marc8Error {
+    InvalidByte { byte: u8, position: usize },
+    UnsupportedEscapeSequence { position: usize },
+    MissingBaseCharacter,
+}
+
+impl fmt::Display for Marc8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidByte { byte, position } => write!(
+                f,
+                "invalid byte {byte:#04x} at position {position}"
+            ),
+            Self::UnsupportedEscapeSequence { position } => write!(
+                f,
+                "unsupported escape sequence at position {position}"
+            ),
+            Self::MissingBaseCharacter => f.write_str(
+                "combining diacritic without base character",
+            ),
+        }
+    }
+}
+
+impl core::error::Error for Marc8Error {}
+
+/// The escape character (`0x1B`), which introduces a character set
+/// designation.
+const ESCAPE: u8 = 0x1b;
+
+/// Maps a byte of the ANSEL extended Latin character set (G1) to a
+/// character and a flag indicating whether the character is a
+/// combining diacritic.
+fn ansel(byte: u8) -> Option<(char, bool)> {
+    let c = match byte {
+        0x8d => '\u{200d}',
+        0x8e => '\u{200c}',
+        0xa1 => '\u{0141}',
+        0xa2 => '\u{00d8}',
+        0xa3 => '\u{0110}',
+        0xa4 => '\u{00de}',
+        0xa5 => '\u{00c6}',
+        0xa6 => '\u{0152}',
+        0xa7 => '\u{02b9}',
+        0xa8 => '\u{00b7}',
+        0xa9 => '\u{266d}',
+        0xaa => '\u{00ae}',
+        0xab => '\u{00b1}',
+        0xac => '\u{01a0}',
+        0xad => '\u{01af}',
+        0xae => '\u{02bc}',
+        0xb0 => '\u{02bb}',
+        0xb1 => '\u{0142}',
+        0xb2 => '\u{00f8}',
+        0xb3 => '\u{0111}',
+        0xb4 => '\u{00fe}',
+        0xb5 => '\u{00e6}',
+        0xb6 => '\u{0153}',
+        0xb7 => '\u{02ba}',
+        0xb8 => '\u{0131}',
+        0xb9 => '\u{00a3}',
+        0xba => '\u{00f0}',
+        0xbc => '\u{01a1}',
+        0xbd => '\u{01b0}',
+        0xc0 => '\u{00b0}',
+        0xc1 => '\u{2113}',
+        0xc2 => '\u{2117}',
+        0xc3 => '\u{00a9}',
+        0xc4 => '\u{266f}',
+        0xc5 => '\u{00bf}',
+        0xc6 => '\u{00a1}',
+        0xc7 => '\u{00df}',
+        0xc8 => '\u{20ac}',
+        _ => return ansel_combining(byte).map(|c| (c, true)),
+    };
+
+    Some((c, false))
+}
+
+/// Maps a combining diacritic of the ANSEL character set to the
+/// corresponding Unicode combining character.
+fn ansel_combining(byte: u8) -> Option<char> {
+    let c = match byte {
+        0xe0 => '\u{0309}',
+        0xe1 => '\u{0300}',
+        0xe2 => '\u{0301}',
+        0xe3 => '\u{0302}',
+        0xe4 => '\u{0303}',
+        0xe5 => '\u{0304}',
+        0xe6 => '\u{0306}',
+        0xe7 => '\u{0307}',
+        0xe8 => '\u{0308}',
+        0xe9 => '\u{030c}',
+        0xea => '\u{030a}',
+        0xeb => '\u{fe20}',
+        0xec => '\u{fe21}',
+        0xed => '\u{0315}',
+        0xee => '\u{030b}',
+        0xef => '\u{0310}',
+        0xf0 => '\u{0327}',
+        0xf1 => '\u{0328}',
+        0xf2 => '\u{0323}',
+        0xf3 => '\u{0324}',
+        0xf4 => '\u{0325}',
+        0xf5 => '\u{0333}',
+        0xf6 => '\u{0332}',
+        0xf7 => '\u{0326}',
+        0xf8 => '\u{031c}',
+        0xf9 => '\u{032e}',
+        0xfa => '\u{fe22}',
+        0xfb => '\u{fe23}',
+        0xfe => '\u{0313}',
+        _ => return None,
+    };
+
+    Some(c)
+}
+
+/// Decodes MARC-8 encoded data into an UTF-8 string.
+///
+/// The decoder supports the Basic Latin (ASCII) and the ANSEL extended
+/// Latin character sets. In MARC-8 a combining diacritic precedes its
+/// base character, whereas in Unicode it follows it; the decoder
+/// reorders them accordingly. The result is not normalized, i.e.
+/// characters remain in decomposed form.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::decode_marc8;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let value = decode_marc8(b"Caf\xe2e")?;
+///     assert_eq!(value, "Cafe\u{301}");
+///
+///     Ok(())
+/// }
+/// ```
+pub fn decode_marc8(data: &[u8]) -> Result<String, Marc8Error> {
+    let mut result = String::with_capacity(data.len());
+    let mut diacritics: Vec<char> = vec![];
+    let mut position = 0;
+
+    while let Some(&byte) = data.get(position) {
+        let c = match byte {
+            ESCAPE => {
+                // Only the designation of the Basic Latin character
+                // set (`ESC ( B` or `ESC s`) is supported, which is
+                // the default anyway.
+                position += match &data[position + 1..] {
+                    [b's', ..] => 2,
+                    [b'(', b'B', ..] => 3,
+                    _ => {
+                        return Err(
+                            Marc8Error::UnsupportedEscapeSequence {
+                                position,
+                            },
+                        )
+                    }
+                };
+                continue;
+            }
+            0x00..=0x7e => byte as char,
+            0x88 | 0x89 => {
+                // The non-sorting markers are ignored.
+                position += 1;
+                continue;
+            }
+            _ => match ansel(byte) {
+                Some((c, true)) => {
+                    diacritics.push(c);
+                    position += 1;
+                    continue;
+                }
+                Some((c, false)) => c,
+                None => {
+                    return Err(Marc8Error::InvalidByte {
+                        byte,
+                        position,
+                    })
+                }
+            },
+        };
+
+        result.push(c);
+        result.extend(diacritics.drain(..));
+        position += 1;
+    }
+
+    if !diacritics.is_empty() {
+        return Err(Marc8Error::MissingBaseCharacter);
+    }
+
+    Ok(result)
+}
+
+impl Record {
+    /// Returns the value as an UTF-8 string. If the record is MARC-8
+    /// encoded (leader position 09), the value is decoded; otherwise
+    /// the value is converted (lossy) into a string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam  2200037 c 4500\
+    ///         245001000000\x1e00\x1faCaf\xe2e\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let field = record.fields(b"245").next().unwrap();
+    ///     let value = field.subfield(b'a').unwrap().value();
+    ///     assert_eq!(record.field_value_utf8(value)?, "Cafe\u{301}");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn field_value_utf8<'a>(
+        &self,
+        value: &'a [u8],
+    ) -> Result<Cow<'a, str>, Marc8Error> {
+        match self.leader.character_coding_scheme() {
+            CharacterCodingScheme::Marc8 => {
+                decode_marc8(value).map(Cow::Owned)
+            }
+            _ => Ok(String::from_utf8_lossy(value)),
+        }
+    }
+
+    /// Converts a MARC-8 encoded record into an UTF-8 encoded record.
+    ///
+    /// All control field data and subfield values are decoded and the
+    /// character coding scheme of the leader (position 09) is set to
+    /// `a` (UCS/Unicode). Records that aren't MARC-8 encoded are
+    /// returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{CharacterCodingScheme, Record};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam  2200037 c 4500\
+    ///         245001000000\x1e00\x1faCaf\xe2e\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let record = record.to_utf8()?;
+    ///     assert_eq!(
+    ///         record.leader().character_coding_scheme(),
+    ///         CharacterCodingScheme::Utf8
+    ///     );
+    ///
+    ///     let field = record.fields(b"245").next().unwrap();
+    ///     let value = field.subfield(b'a').unwrap().value();
+    ///     assert_eq!(value, "Cafe\u{301}".as_bytes());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_utf8(&self) -> Result<Record, Marc8Error> {
+        let mut record = self.clone();
+        if self.leader.character_coding_scheme()
+            != CharacterCodingScheme::Marc8
+        {
+            return Ok(record);
+        }
+
+        for field in record.control_fields.iter_mut() {
+            field.data = decode_marc8(&field.data)?.into_bytes();
+        }
+
+        for field in record.data_fields.iter_mut() {
+            for subfield in field.subfields.iter_mut() {
+                subfield.value =
+                    decode_marc8(&subfield.value)?.into_bytes();
+            }
+        }
+
+        record.leader.raw[9] =
+            char::from(CharacterCodingScheme::Utf8) as u8;
+        record.update_leader();
+
+        Ok(record)
+    }
+
+    /// Guesses the character coding scheme of the record from its
+    /// data, regardless of the leader (position 09), e.g. to fix
+    /// records whose coding scheme is mislabeled.
+    ///
+    /// Data containing an escape sequence or bytes which aren't valid
+    /// UTF-8 (such as ANSEL diacritics) is considered MARC-8, and data
+    /// consisting of valid UTF-8 with multibyte sequences is
+    /// considered UTF-8. Plain ASCII data is valid in both schemes;
+    /// the scheme of the leader is returned for it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{CharacterCodingScheme, Record};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam  2200037 c 4500\
+    ///         245001000000\x1e00\x1faCaf\xc3\xa9\x1e\x1d",
+    ///     )?;
+    ///
+    ///     assert_eq!(
+    ///         record.leader().character_coding_scheme(),
+    ///         CharacterCodingScheme::Marc8
+    ///     );
+    ///     assert_eq!(
+    ///         record.detect_coding_scheme(),
+    ///         CharacterCodingScheme::Utf8
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn detect_coding_scheme(&self) -> CharacterCodingScheme {
+        let values = self
+            .control_fields
+            .iter()
+            .map(|field| field.data.as_slice())
+            .chain(self.data_fields.iter().flat_map(|field| {
+                field
+                    .subfields
+                    .iter()
+                    .map(|subfield| &subfield.value[..])
+            }));
+
+        let mut multibyte = false;
+        for value in values {
+            if value.contains(&ESCAPE)
+                || core::str::from_utf8(value).is_err()
+            {
+                return CharacterCodingScheme::Marc8;
+            }
+
+            multibyte |= !value.is_ascii();
+        }
+
+        if multibyte {
+            CharacterCodingScheme::Utf8
+        } else {
+            self.leader.character_coding_scheme()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Leader, RecordBuilder, Subfield};
+
+    #[test]
+    fn test_decode_marc8() {
+        assert_eq!(decode_marc8(b"abc"), Ok("abc".into()));
+        assert_eq!(decode_marc8(b"Caf\xe2e"), Ok("Cafe\u{301}".into()));
+        assert_eq!(
+            decode_marc8(b"\xe2E\xe1e\xe8a"),
+            Ok("E\u{301}e\u{300}a\u{308}".into())
+        );
+        assert_eq!(
+            decode_marc8(b"\xf0\xe2c"),
+            Ok("c\u{327}\u{301}".into())
+        );
+        assert_eq!(
+            decode_marc8(b"\xa1\xb2d\xc7"),
+            Ok("\u{141}\u{f8}d\u{df}".into())
+        );
+        assert_eq!(
+            decode_marc8(b"\x1b(BA\x1bsB\x88C\x89"),
+            Ok("ABC".into())
+        );
+    }
+
+    #[test]
+    fn test_decode_marc8_invalid() {
+        assert_eq!(
+            decode_marc8(b"ab\xff"),
+            Err(Marc8Error::InvalidByte {
+                byte: 0xff,
+                position: 2
+            })
+        );
+        assert_eq!(
+            decode_marc8(b"a\x1b$1"),
+            Err(Marc8Error::UnsupportedEscapeSequence { position: 1 })
+        );
+        assert_eq!(
+            decode_marc8(b"a\xe2"),
+            Err(Marc8Error::MissingBaseCharacter)
+        );
+    }
+
+    #[test]
+    fn test_field_value_utf8() -> anyhow::Result<()> {
+        let record = Record::from_bytes(
+            b"00048nam  2200037 c 4500\
+            245001000000\x1e00\x1faCaf\xe2e\x1e\x1d",
+        )?;
+        assert_eq!(
+            record.field_value_utf8(b"Caf\xe2e")?,
+            "Cafe\u{301}"
+        );
+
+        let record = Record::from_bytes(
+            b"00048nam a2200037 c 4500\
+            245001000000\x1e00\x1faCaf\xc3\xa9\x1e\x1d",
+        )?;
+        assert_eq!(record.field_value_utf8("Café".as_bytes())?, "Café");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_utf8() -> anyhow::Result<()> {
+        let record = RecordBuilder::new()
+            .leader(Leader::from_bytes(b"00000nam  2200000 c 4500")?)
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [
+                    Subfield::new(b'a', &b"Caf\xe2e /"[..]),
+                    Subfield::new(
+                        b'c',
+                        &b"Fran\xf0cois L\xe8o\xb2d."[..],
+                    ),
+                ],
+            )
+            .build();
+
+        let utf8 = record.to_utf8()?;
+        assert_eq!(
+            utf8.leader().character_coding_scheme(),
+            CharacterCodingScheme::Utf8
+        );
+        assert_eq!(utf8.control_number(), Some(&b"123456789"[..]));
+
+        let field = utf8.fields(b"245").next().unwrap();
+        assert_eq!(field.ind1(), b'1');
+        assert_eq!(
+            field.subfield(b'a').unwrap().value(),
+            "Cafe\u{301} /".as_bytes()
+        );
+        assert_eq!(
+            field.subfield(b'c').unwrap().value(),
+            "Franc\u{327}ois Lo\u{308}\u{f8}d.".as_bytes()
+        );
+
+        let data = utf8.to_bytes();
+        assert_eq!(utf8.leader().record_length(), data.len() as u32);
+        assert_eq!(Record::from_bytes(&data)?, utf8);
+
+        assert_eq!(utf8.to_utf8()?, utf8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_coding_scheme() -> anyhow::Result<()> {
+        let record =
+            |leader: &[u8], value: &[u8]| -> anyhow::Result<_> {
+                Ok(RecordBuilder::new()
+                    .leader(Leader::from_bytes(leader)?)
+                    .control_field(b"001", "123456789")
+                    .data_field(
+                        b"245",
+                        b'1',
+                        b'0',
+                        [Subfield::new(b'a', value)],
+                    )
+                    .build())
+            };
+
+        let marc8 = b"00000nam  2200000 c 4500";
+        let utf8 = b"00000nam a2200000 c 4500";
+
+        // UTF-8 data, flagged as MARC-8
+        let value = "Café /".as_bytes();
+        assert_eq!(
+            record(marc8, value)?.detect_coding_scheme(),
+            CharacterCodingScheme::Utf8
+        );
+        assert_eq!(
+            record(utf8, value)?.detect_coding_scheme(),
+            CharacterCodingScheme::Utf8
+        );
+
+        // MARC-8 data, flagged as UTF-8
+        let value = b"Caf\xe2e /";
+        assert_eq!(
+            record(utf8, value)?.detect_coding_scheme(),
+            CharacterCodingScheme::Marc8
+        );
+        let value = b"\x1b(NTolstoi\x1b(B";
+        assert_eq!(
+            record(utf8, value)?.detect_coding_scheme(),
+            CharacterCodingScheme::Marc8
+        );
+
+        // plain ASCII
+        assert_eq!(
+            record(utf8, b"Title")?.detect_coding_scheme(),
+            CharacterCodingScheme::Utf8
+        );
+        assert_eq!(
+            record(marc8, b"Title")?.detect_coding_scheme(),
+            CharacterCodingScheme::Marc8
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_utf8_invalid() -> anyhow::Result<()> {
+        let record = RecordBuilder::new()
+            .leader(Leader::from_bytes(b"00000nam  2200000 c 4500")?)
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', &b"\xff"[..])],
+            )
+            .build();
+
+        assert_eq!(
+            record.to_utf8(),
+            Err(Marc8Error::InvalidByte {
+                byte: 0xff,
+                position: 0
+            })
+        );
+
+        Ok(())
+    }
+}
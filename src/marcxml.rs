@@ -0,0 +1,1034 @@
+use std::fmt::{self, Write};
+use std::io;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::name::{Namespace, ResolveResult};
+use quick_xml::NsReader;
+
+use crate::record::FieldOrder;
+use crate::{
+    ControlField, DataField, Field, Leader, ParseLeaderError, Record,
+    Subfield,
+};
+
+/// The namespace of the MARC 21 XML schema (MARCXML).
+pub(crate) const MARCXML_NS: &str = "http://www.loc.gov/MARC21/slim";
+
+/// An error that can occur when parsing MARCXML.
+#[derive(Debug, thiserror::Error)]
+pub enum ParseMarcXmlError {
+    #[error("xml error: {0}")]
+    Xml(#[from] quick_xml::Error),
+
+    #[error("invalid leader: {0}")]
+    Leader(#[from] ParseLeaderError),
+
+    #[error("missing record")]
+    MissingRecord,
+
+    #[error("missing leader")]
+    MissingLeader,
+
+    #[error("missing attribute `{0}`")]
+    MissingAttribute(&'static str),
+
+    #[error("invalid attribute `{0}`")]
+    InvalidAttribute(&'static str),
+
+    #[error("unexpected end of input")]
+    UnexpectedEof,
+}
+
+impl From<quick_xml::events::attributes::AttrError>
+    for ParseMarcXmlError
+{
+    fn from(e: quick_xml::events::attributes::AttrError) -> Self {
+        Self::Xml(e.into())
+    }
+}
+
+impl Record {
+    /// Converts the record into its MARCXML representation.
+    ///
+    /// The leader is emitted as its 24-character string and all
+    /// values are XML-escaped. The fields are written in the order of
+    /// [Record::all_fields]. Bytes which aren't valid UTF-8 and
+    /// control characters which can't occur in XML 1.0 (all but tab,
+    /// line feed and carriage return) are replaced by `U+FFFD`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     )?;
+    ///
+    ///     assert!(record.to_marcxml().contains(
+    ///         "<controlfield tag=\"001\">123456789</controlfield>"
+    ///     ));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_marcxml(&self) -> String {
+        let mut out = String::new();
+        self.write_marcxml(&mut out)
+            .expect("writing to a string can't fail");
+        out
+    }
+
+    /// Writes the MARCXML representation of the record into the given
+    /// [fmt::Write].
+    pub fn write_marcxml<W: Write>(&self, out: &mut W) -> fmt::Result {
+        write_record(out, self, true)
+    }
+
+    /// Creates a record from its MARCXML representation.
+    ///
+    /// The first `<record>` element of the input is parsed; namespace
+    /// prefixes are ignored. Missing indicators default to a blank.
+    /// The fields keep the order of the input, and the record length
+    /// and the base address of data of the leader are recomputed from
+    /// the fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_marcxml(
+    ///         r#"<record xmlns="http://www.loc.gov/MARC21/slim">
+    ///           <leader>00048nam a2200037 c 4500</leader>
+    ///           <controlfield tag="001">123456789</controlfield>
+    ///         </record>"#,
+    ///     )?;
+    ///
+    ///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_marcxml(data: &str) -> Result<Self, ParseMarcXmlError> {
+        let mut reader = NsReader::from_str(data);
+        read_record(&mut reader)?
+            .ok_or(ParseMarcXmlError::MissingRecord)
+    }
+}
+
+/// Converts the records into a MARCXML `<collection>`.
+///
+/// The output is the same as that of a [MarcXmlWriter]: an XML
+/// declaration followed by the `<collection>` element, whose
+/// `<record>` children inherit the MARCXML namespace.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::Record;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let record = Record::from_bytes(
+///         b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d",
+///     )?;
+///
+///     let xml = marc21::records_to_marcxml_collection(&[record]);
+///     assert!(xml.ends_with("</record>\n</collection>\n"));
+///     Ok(())
+/// }
+/// ```
+pub fn records_to_marcxml_collection(records: &[Record]) -> String {
+    let mut out = format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <collection xmlns=\"{MARCXML_NS}\">\n"
+    );
+
+    for record in records.iter() {
+        write_record(&mut out, record, false)
+            .expect("writing to a string can't fail");
+    }
+
+    out.push_str("</collection>\n");
+    out
+}
+
+/// Parses all `<record>` elements of a MARCXML `<collection>`, in
+/// document order.
+///
+/// Namespace prefixes are ignored. An empty collection yields no
+/// records; a single `<record>` without the `<collection>` wrapper is
+/// accepted as well.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let records = marc21::records_from_marcxml_collection(
+///         r#"<collection xmlns="http://www.loc.gov/MARC21/slim">
+///           <record>
+///             <leader>00048nam a2200037 c 4500</leader>
+///             <controlfield tag="001">123456789</controlfield>
+///           </record>
+///           <record>
+///             <leader>00048nam a2200037 c 4500</leader>
+///             <controlfield tag="001">987654321</controlfield>
+///           </record>
+///         </collection>"#,
+///     )?;
+///
+///     assert_eq!(records.len(), 2);
+///     assert_eq!(
+///         records[1].control_number(),
+///         Some(&b"987654321"[..])
+///     );
+///     Ok(())
+/// }
+/// ```
+pub fn records_from_marcxml_collection(
+    data: &str,
+) -> Result<Vec<Record>, ParseMarcXmlError> {
+    let mut reader = NsReader::from_str(data);
+    let mut records = vec![];
+
+    while let Some(record) = read_record(&mut reader)? {
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// Parses the MARCXML records of an OAI-PMH response, e.g. of a
+/// `ListRecords` or `GetRecord` request.
+///
+/// The OAI envelope and the record headers are ignored; the MARC
+/// `<record>` within the `<metadata>` element of each OAI record is
+/// parsed. Records whose header has the status `deleted` don't carry
+/// any metadata and are skipped, as is metadata in other formats, i.e.
+/// elements outside the MARCXML namespace
+/// (`http://www.loc.gov/MARC21/slim`).
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let records = marc21::from_oai_marcxml(
+///         r#"<OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/">
+///           <ListRecords>
+///             <record>
+///               <header><identifier>oai:1</identifier></header>
+///               <metadata>
+///                 <record xmlns="http://www.loc.gov/MARC21/slim">
+///                   <leader>00048nam a2200037 c 4500</leader>
+///                   <controlfield tag="001">123456789</controlfield>
+///                 </record>
+///               </metadata>
+///             </record>
+///           </ListRecords>
+///         </OAI-PMH>"#,
+///     )?;
+///
+///     assert_eq!(records.len(), 1);
+///     assert_eq!(
+///         records[0].control_number(),
+///         Some(&b"123456789"[..])
+///     );
+///     Ok(())
+/// }
+/// ```
+pub fn from_oai_marcxml(
+    data: &str,
+) -> Result<Vec<Record>, ParseMarcXmlError> {
+    let mut reader = NsReader::from_str(data);
+    let mut records = vec![];
+    let mut deleted = false;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) | Event::Empty(e)
+                if e.local_name().as_ref() == b"header" =>
+            {
+                deleted = read_attribute(&e, "status")?.as_deref()
+                    == Some("deleted");
+            }
+            Event::Start(e)
+                if e.local_name().as_ref() == b"metadata" =>
+            {
+                if deleted {
+                    reader.read_to_end(e.name())?;
+                } else {
+                    records.extend(read_metadata(&mut reader)?);
+                }
+            }
+            Event::Eof => break,
+            _ => (),
+        }
+    }
+
+    Ok(records)
+}
+
+/// Reads the content of an OAI `<metadata>` element up to the closing
+/// tag and returns the MARC record it contains, if any.
+fn read_metadata(
+    reader: &mut NsReader<&[u8]>,
+) -> Result<Option<Record>, ParseMarcXmlError> {
+    let mut record = None;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) if is_marcxml_record(reader, &e) => {
+                record = Some(read_record_content(reader)?);
+            }
+            Event::Start(e) => {
+                reader.read_to_end(e.name())?;
+            }
+            Event::End(e) if e.local_name().as_ref() == b"metadata" => {
+                break
+            }
+            Event::Eof => return Err(ParseMarcXmlError::UnexpectedEof),
+            _ => (),
+        }
+    }
+
+    Ok(record)
+}
+
+/// Returns `true` if the element is a MARCXML `<record>`, i.e. if its
+/// local name is `record` and it's bound to the [MARCXML_NS]
+/// namespace.
+#[inline]
+fn is_marcxml_record(reader: &NsReader<&[u8]>, e: &BytesStart) -> bool {
+    let (ns, local_name) = reader.resolve_element(e.name());
+    local_name.as_ref() == b"record"
+        && ns == ResolveResult::Bound(Namespace(MARCXML_NS.as_bytes()))
+}
+
+/// A writer that streams records as a MARCXML `<collection>` into an
+/// underlying [io::Write].
+///
+/// Each record is written as soon as it's passed to the writer, such
+/// that the memory usage doesn't grow with the number of records. The
+/// records inherit the MARCXML namespace from the `<collection>`
+/// element.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{MarcXmlWriter, Record};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let record = Record::from_bytes(
+///         b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d",
+///     )?;
+///
+///     let mut writer = MarcXmlWriter::new(Vec::new());
+///     writer.start_collection()?;
+///     writer.write_record(&record)?;
+///     writer.end_collection()?;
+///
+///     let xml = String::from_utf8(writer.into_inner())?;
+///     assert!(xml.ends_with("</record>\n</collection>\n"));
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MarcXmlWriter<W: io::Write> {
+    inner: W,
+    buf: String,
+}
+
+impl<W: io::Write> MarcXmlWriter<W> {
+    /// Creates a new writer over the given [io::Write].
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            buf: String::new(),
+        }
+    }
+
+    /// Writes the XML declaration and the opening `<collection>` tag.
+    pub fn start_collection(&mut self) -> io::Result<()> {
+        writeln!(
+            self.inner,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <collection xmlns=\"{MARCXML_NS}\">"
+        )
+    }
+
+    /// Writes a single `<record>` element.
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        self.buf.clear();
+        write_record(&mut self.buf, record, false)
+            .expect("writing to a string can't fail");
+        self.inner.write_all(self.buf.as_bytes())
+    }
+
+    /// Writes the closing `</collection>` tag and flushes the
+    /// underlying writer.
+    pub fn end_collection(&mut self) -> io::Result<()> {
+        writeln!(self.inner, "</collection>")?;
+        self.inner.flush()
+    }
+
+    /// Unwraps the writer, returning the underlying [io::Write].
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/// Reads the next `<record>` element. Returns `Ok(None)` if the end
+/// of the input is reached before a record starts.
+pub(crate) fn read_record(
+    reader: &mut NsReader<&[u8]>,
+) -> Result<Option<Record>, ParseMarcXmlError> {
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) if e.local_name().as_ref() == b"record" => {
+                return read_record_content(reader).map(Some);
+            }
+            Event::Empty(e) if e.local_name().as_ref() == b"record" => {
+                return Err(ParseMarcXmlError::MissingLeader);
+            }
+            Event::Eof => return Ok(None),
+            _ => (),
+        }
+    }
+}
+
+/// Reads the content of a `<record>` element up to the closing tag.
+fn read_record_content(
+    reader: &mut NsReader<&[u8]>,
+) -> Result<Record, ParseMarcXmlError> {
+    let mut leader = None;
+    let mut control_fields = vec![];
+    let mut data_fields = vec![];
+    let mut kinds = vec![];
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => match e.local_name().as_ref() {
+                b"leader" => {
+                    let data = read_text(reader)?;
+                    leader = Some(Leader::from_bytes(data.as_bytes())?);
+                }
+                b"controlfield" => {
+                    let tag = read_tag(&e)?;
+                    let data = read_text(reader)?.into_bytes();
+                    control_fields.push(ControlField { tag, data });
+                    kinds.push(true);
+                }
+                b"datafield" => {
+                    let mut field = read_data_field(&e)?;
+                    field.subfields = read_subfields(reader)?;
+                    data_fields.push(field);
+                    kinds.push(false);
+                }
+                _ => {
+                    reader.read_to_end(e.name())?;
+                }
+            },
+            Event::Empty(e) => match e.local_name().as_ref() {
+                b"controlfield" => {
+                    let tag = read_tag(&e)?;
+                    control_fields
+                        .push(ControlField { tag, data: vec![] });
+                    kinds.push(true);
+                }
+                b"datafield" => {
+                    data_fields.push(read_data_field(&e)?);
+                    kinds.push(false);
+                }
+                _ => (),
+            },
+            Event::End(e) if e.local_name().as_ref() == b"record" => {
+                break
+            }
+            Event::Eof => return Err(ParseMarcXmlError::UnexpectedEof),
+            _ => (),
+        }
+    }
+
+    let mut record = Record {
+        leader: leader.ok_or(ParseMarcXmlError::MissingLeader)?,
+        control_fields,
+        data_fields,
+        length_overflowed: false,
+        missing_terminator: false,
+        original: Default::default(),
+        directory: Default::default(),
+        field_order: FieldOrder::new(kinds),
+    };
+
+    // The record length and the base address of data of the `<leader>`
+    // needn't match the fields.
+    record.update_leader();
+    Ok(record)
+}
+
+/// Reads the `<subfield>` elements of a `<datafield>` up to the
+/// closing tag.
+fn read_subfields(
+    reader: &mut NsReader<&[u8]>,
+) -> Result<Vec<Subfield>, ParseMarcXmlError> {
+    let mut subfields = vec![];
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e)
+                if e.local_name().as_ref() == b"subfield" =>
+            {
+                let code = read_code(&e)?;
+                let value = read_text(reader)?;
+                subfields.push(Subfield::new(code, value));
+            }
+            Event::Empty(e)
+                if e.local_name().as_ref() == b"subfield" =>
+            {
+                subfields.push(Subfield::new(read_code(&e)?, ""));
+            }
+            Event::Start(e) => {
+                reader.read_to_end(e.name())?;
+            }
+            Event::End(_) => break,
+            Event::Eof => return Err(ParseMarcXmlError::UnexpectedEof),
+            _ => (),
+        }
+    }
+
+    Ok(subfields)
+}
+
+/// Reads the (unescaped) text content of the current element up to
+/// the closing tag. Nested elements are skipped.
+fn read_text(
+    reader: &mut NsReader<&[u8]>,
+) -> Result<String, ParseMarcXmlError> {
+    let mut text = String::new();
+
+    loop {
+        match reader.read_event()? {
+            Event::Text(e) => text.push_str(&e.unescape()?),
+            Event::CData(e) => {
+                text.push_str(&String::from_utf8_lossy(&e.into_inner()))
+            }
+            Event::Start(e) => {
+                reader.read_to_end(e.name())?;
+            }
+            Event::End(_) => break,
+            Event::Eof => return Err(ParseMarcXmlError::UnexpectedEof),
+            _ => (),
+        }
+    }
+
+    Ok(text)
+}
+
+/// Returns the (unescaped) value of the attribute `name`, if present.
+fn read_attribute(
+    e: &BytesStart,
+    name: &'static str,
+) -> Result<Option<String>, ParseMarcXmlError> {
+    match e.try_get_attribute(name)? {
+        Some(attr) => Ok(Some(attr.unescape_value()?.into_owned())),
+        None => Ok(None),
+    }
+}
+
+/// Reads the `tag` attribute, which must consist of three bytes.
+fn read_tag(e: &BytesStart) -> Result<[u8; 3], ParseMarcXmlError> {
+    read_attribute(e, "tag")?
+        .ok_or(ParseMarcXmlError::MissingAttribute("tag"))?
+        .as_bytes()
+        .try_into()
+        .map_err(|_| ParseMarcXmlError::InvalidAttribute("tag"))
+}
+
+/// Reads the `code` attribute of a subfield, which must consist of a
+/// single byte.
+fn read_code(e: &BytesStart) -> Result<u8, ParseMarcXmlError> {
+    match read_attribute(e, "code")?.as_deref().map(str::as_bytes) {
+        Some([code]) => Ok(*code),
+        Some(_) => Err(ParseMarcXmlError::InvalidAttribute("code")),
+        None => Err(ParseMarcXmlError::MissingAttribute("code")),
+    }
+}
+
+/// Reads the attributes of a `<datafield>` element. Missing (or empty)
+/// indicators default to a blank.
+fn read_data_field(
+    e: &BytesStart,
+) -> Result<DataField, ParseMarcXmlError> {
+    let read_indicator = |name| match read_attribute(e, name)?
+        .as_deref()
+        .map(str::as_bytes)
+    {
+        Some([ind]) => Ok(*ind),
+        None | Some([]) => Ok(b' '),
+        Some(_) => Err(ParseMarcXmlError::InvalidAttribute(name)),
+    };
+
+    Ok(DataField {
+        tag: read_tag(e)?,
+        ind1: read_indicator("ind1")?,
+        ind2: read_indicator("ind2")?,
+        subfields: vec![],
+    })
+}
+
+/// Writes a single `<record>` element. If `namespace` is set, the
+/// MARCXML namespace is declared on the element.
+pub(crate) fn write_record<W: Write>(
+    out: &mut W,
+    record: &Record,
+    namespace: bool,
+) -> fmt::Result {
+    if namespace {
+        writeln!(out, "<record xmlns=\"{MARCXML_NS}\">")?;
+    } else {
+        writeln!(out, "<record>")?;
+    }
+
+    write!(out, "  <leader>")?;
+    write_escaped(out, &record.leader.to_bytes())?;
+    writeln!(out, "</leader>")?;
+
+    for field in record.all_fields() {
+        match field {
+            Field::Control(field) => {
+                write!(out, "  <controlfield tag=\"")?;
+                write_escaped(out, &field.tag)?;
+                write!(out, "\">")?;
+                write_escaped(out, &field.data)?;
+                writeln!(out, "</controlfield>")?;
+            }
+            Field::Data(field) => {
+                write!(out, "  <datafield tag=\"")?;
+                write_escaped(out, &field.tag)?;
+                write!(out, "\" ind1=\"")?;
+                write_escaped(out, &[field.ind1])?;
+                write!(out, "\" ind2=\"")?;
+                write_escaped(out, &[field.ind2])?;
+                writeln!(out, "\">")?;
+
+                for subfield in field.subfields.iter() {
+                    write!(out, "    <subfield code=\"")?;
+                    write_escaped(out, &[subfield.code])?;
+                    write!(out, "\">")?;
+                    write_escaped(out, &subfield.value)?;
+                    writeln!(out, "</subfield>")?;
+                }
+
+                writeln!(out, "  </datafield>")?;
+            }
+        }
+    }
+
+    writeln!(out, "</record>")
+}
+
+/// Writes the (lossy) UTF-8 representation of `value`, escaping all
+/// characters with a special meaning in XML. Control characters which
+/// aren't allowed in XML 1.0, not even as a character reference, are
+/// replaced by `U+FFFD`.
+fn write_escaped<W: Write>(out: &mut W, value: &[u8]) -> fmt::Result {
+    for ch in String::from_utf8_lossy(value).chars() {
+        match ch {
+            '&' => out.write_str("&amp;")?,
+            '<' => out.write_str("&lt;")?,
+            '>' => out.write_str("&gt;")?,
+            '"' => out.write_str("&quot;")?,
+            '\'' => out.write_str("&apos;")?,
+            '\t' | '\n' | '\r' => out.write_char(ch)?,
+            '\0'..='\x1f' => {
+                out.write_char(char::REPLACEMENT_CHARACTER)?
+            }
+            _ => out.write_char(ch)?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_marcxml() -> anyhow::Result<()> {
+        let record = Record::from_bytes(
+            b"00122nam a2200061 c 450000100100000000300070001024500430\
+            0017\x1e123456789\x1eDE-101\x1e10\x1faArithmetic\
+            \x20<poems> /\x1fcSandburg & Rand.\x1e\x1d",
+        )?;
+
+        assert_eq!(
+            record.to_marcxml(),
+            "<record xmlns=\"http://www.loc.gov/MARC21/slim\">\n\
+            \x20 <leader>00122nam a2200061 c 4500</leader>\n\
+            \x20 <controlfield tag=\"001\">123456789</controlfield>\n\
+            \x20 <controlfield tag=\"003\">DE-101</controlfield>\n\
+            \x20 <datafield tag=\"245\" ind1=\"1\" ind2=\"0\">\n\
+            \x20   <subfield code=\"a\">Arithmetic &lt;poems&gt; /</subfield>\n\
+            \x20   <subfield code=\"c\">Sandburg &amp; Rand.</subfield>\n\
+            \x20 </datafield>\n\
+            </record>\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_marcxml_field_order() -> anyhow::Result<()> {
+        let record = Record::from_bytes(
+            b"00067nam a2200049 c 4500245001000000008000700010\x1e\
+            00\x1faTitle\x1e740101\x1e\x1d",
+        )?;
+
+        let xml = record.to_marcxml();
+        let datafield = xml.find("<datafield tag=\"245\"").unwrap();
+        let controlfield =
+            xml.find("<controlfield tag=\"008\"").unwrap();
+        assert!(datafield < controlfield);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_marcxml_control_characters() -> anyhow::Result<()> {
+        let record = Record::from_bytes(
+            b"00054nam a2200037 c 4500245001600000\x1e\
+            00\x1faTi\x1btle\x01\tend\x1e\x1d",
+        )?;
+
+        let xml = record.to_marcxml();
+        assert!(xml.contains(
+            "<subfield code=\"a\">Ti\u{fffd}tle\u{fffd}\tend</subfield>"
+        ));
+
+        let other = Record::from_marcxml(&xml)?;
+        assert_eq!(
+            other.first_subfield_value(b"245", b'a'),
+            Some("Ti\u{fffd}tle\u{fffd}\tend".as_bytes())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_marcxml_writer() -> anyhow::Result<()> {
+        let mut writer = MarcXmlWriter::new(Vec::new());
+        writer.start_collection()?;
+        for record in crate::records(include_bytes!(
+            "../tests/data/two_records.mrc"
+        )) {
+            writer.write_record(&record?)?;
+        }
+        writer.end_collection()?;
+
+        let xml = String::from_utf8(writer.into_inner())?;
+        assert!(xml.starts_with(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <collection xmlns=\"http://www.loc.gov/MARC21/slim\">\n\
+            <record>\n"
+        ));
+        assert!(xml.ends_with("</record>\n</collection>\n"));
+
+        let mut reader = NsReader::from_str(&xml);
+        let mut depth = 0;
+        let mut records = vec![];
+        loop {
+            match reader.read_event()? {
+                Event::Start(e) => {
+                    if depth == 0 {
+                        assert_eq!(
+                            e.local_name().as_ref(),
+                            b"collection"
+                        );
+                    }
+                    if depth == 1 {
+                        assert_eq!(e.local_name().as_ref(), b"record");
+                        records.push(read_record_content(&mut reader)?);
+                        continue;
+                    }
+                    depth += 1;
+                }
+                Event::End(e) => {
+                    assert_eq!(e.local_name().as_ref(), b"collection");
+                    depth -= 1;
+                }
+                Event::Eof => break,
+                _ => (),
+            }
+        }
+
+        assert_eq!(depth, 0);
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[0].control_number(),
+            Some(&b"123456789"[..])
+        );
+        assert_eq!(records[1].fields(b"245").count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_marcxml() -> anyhow::Result<()> {
+        let record = Record::from_marcxml(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <marc:record xmlns:marc="http://www.loc.gov/MARC21/slim">
+              <marc:leader>00122nam a2200061 c 4500</marc:leader>
+              <marc:controlfield tag="001">123456789</marc:controlfield>
+              <marc:controlfield tag="003"/>
+              <marc:datafield tag="245" ind1="1">
+                <marc:subfield code="a">Arithmetic &lt;poems&gt; /</marc:subfield>
+                <marc:subfield code="c">Sandburg &amp; Rand.</marc:subfield>
+                <marc:subfield code="d"/>
+              </marc:datafield>
+              <marc:datafield tag="650" ind1=" " ind2="0"/>
+            </marc:record>"#,
+        )?;
+
+        // The record length and the base address of data are
+        // recomputed from the fields.
+        assert_eq!(record.leader().record_length(), 133);
+        assert_eq!(record.leader().base_address_of_data(), 73);
+        assert_eq!(record.to_bytes().len(), 133);
+        assert_eq!(
+            record.control_fields,
+            vec![
+                ControlField::new(b"001", "123456789"),
+                ControlField::new(b"003", ""),
+            ]
+        );
+        assert_eq!(
+            record.data_fields,
+            vec![
+                DataField {
+                    tag: *b"245",
+                    ind1: b'1',
+                    ind2: b' ',
+                    subfields: vec![
+                        Subfield::new(b'a', "Arithmetic <poems> /"),
+                        Subfield::new(b'c', "Sandburg & Rand."),
+                        Subfield::new(b'd', ""),
+                    ]
+                },
+                DataField {
+                    tag: *b"650",
+                    ind1: b' ',
+                    ind2: b'0',
+                    subfields: vec![]
+                }
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_oai_marcxml() -> anyhow::Result<()> {
+        let records = from_oai_marcxml(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+            <OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/">
+              <responseDate>2024-01-01T00:00:00Z</responseDate>
+              <request verb="ListRecords">https://example.org/oai</request>
+              <ListRecords>
+                <record>
+                  <header>
+                    <identifier>oai:example.org:1</identifier>
+                    <datestamp>2024-01-01</datestamp>
+                  </header>
+                  <metadata>
+                    <marc:record xmlns:marc="http://www.loc.gov/MARC21/slim">
+                      <marc:leader>00048nam a2200037 c 4500</marc:leader>
+                      <marc:controlfield tag="001">123456789</marc:controlfield>
+                    </marc:record>
+                  </metadata>
+                </record>
+                <record>
+                  <header status="deleted">
+                    <identifier>oai:example.org:2</identifier>
+                  </header>
+                </record>
+                <record>
+                  <header>
+                    <identifier>oai:example.org:3</identifier>
+                  </header>
+                  <metadata>
+                    <record xmlns="http://www.loc.gov/MARC21/slim">
+                      <leader>00053nam a2200037 c 4500</leader>
+                      <datafield tag="245" ind1="0" ind2="0">
+                        <subfield code="a">Arithmetic</subfield>
+                      </datafield>
+                    </record>
+                  </metadata>
+                  <about><provenance/></about>
+                </record>
+                <resumptionToken>token</resumptionToken>
+              </ListRecords>
+            </OAI-PMH>"#,
+        )?;
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[0].control_number(),
+            Some(&b"123456789"[..])
+        );
+        assert_eq!(
+            records[1].first_subfield_value(b"245", b'a'),
+            Some(&b"Arithmetic"[..])
+        );
+
+        assert!(from_oai_marcxml("<OAI-PMH/>")?.is_empty());
+        assert!(matches!(
+            from_oai_marcxml(
+                r#"<record><metadata>
+                <record xmlns="http://www.loc.gov/MARC21/slim">"#
+            ),
+            Err(ParseMarcXmlError::UnexpectedEof)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_oai_marcxml_namespace() -> anyhow::Result<()> {
+        // The `<record>` elements of other metadata formats and those
+        // without a namespace aren't MARCXML records.
+        let records = from_oai_marcxml(
+            r#"<OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/">
+              <ListRecords>
+                <record>
+                  <header><identifier>oai:1</identifier></header>
+                  <metadata>
+                    <record xmlns="http://example.org/other">
+                      <leader>00048nam a2200037 c 4500</leader>
+                    </record>
+                  </metadata>
+                </record>
+                <record>
+                  <header><identifier>oai:2</identifier></header>
+                  <metadata>
+                    <record xmlns="">
+                      <leader>00048nam a2200037 c 4500</leader>
+                    </record>
+                  </metadata>
+                </record>
+                <record>
+                  <header><identifier>oai:3</identifier></header>
+                  <metadata>
+                    <record xmlns="http://www.loc.gov/MARC21/slim">
+                      <leader>00048nam a2200037 c 4500</leader>
+                      <controlfield tag="001">123456789</controlfield>
+                    </record>
+                  </metadata>
+                </record>
+              </ListRecords>
+            </OAI-PMH>"#,
+        )?;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].control_number(),
+            Some(&b"123456789"[..])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_marcxml_roundtrip() -> anyhow::Result<()> {
+        let record = Record::from_bytes(include_bytes!(
+            "../tests/data/arithmetic.mrc"
+        ))?;
+
+        assert_eq!(Record::from_marcxml(&record.to_marcxml())?, record);
+
+        let record = Record::from_bytes(
+            b"00067nam a2200049 c 4500245001000000008000700010\x1e\
+            00\x1faTitle\x1e740101\x1e\x1d",
+        )?;
+        let other = Record::from_marcxml(&record.to_marcxml())?;
+        assert_eq!(other.to_bytes(), record.to_bytes());
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_marcxml_invalid() {
+        assert!(matches!(
+            Record::from_marcxml("<collection/>"),
+            Err(ParseMarcXmlError::MissingRecord)
+        ));
+        assert!(matches!(
+            Record::from_marcxml("<record></record>"),
+            Err(ParseMarcXmlError::MissingLeader)
+        ));
+        assert!(matches!(
+            Record::from_marcxml(
+                "<record><leader>00122nam</leader></record>"
+            ),
+            Err(ParseMarcXmlError::Leader(_))
+        ));
+        assert!(matches!(
+            Record::from_marcxml(
+                "<record><controlfield>1</controlfield></record>"
+            ),
+            Err(ParseMarcXmlError::MissingAttribute("tag"))
+        ));
+        assert!(matches!(
+            Record::from_marcxml(
+                "<record><datafield tag=\"24\"/></record>"
+            ),
+            Err(ParseMarcXmlError::InvalidAttribute("tag"))
+        ));
+        assert!(matches!(
+            Record::from_marcxml(
+                "<record><leader>00122nam a2200061 c 4500</leader>"
+            ),
+            Err(ParseMarcXmlError::UnexpectedEof)
+        ));
+    }
+
+    #[test]
+    fn test_marcxml_collection_roundtrip() -> anyhow::Result<()> {
+        let mut records = vec![Record::from_bytes(include_bytes!(
+            "../tests/data/arithmetic.mrc"
+        ))?];
+        for record in crate::records(include_bytes!(
+            "../tests/data/two_records.mrc"
+        )) {
+            records.push(record?);
+        }
+
+        let xml = records_to_marcxml_collection(&records);
+        assert!(xml.starts_with(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+            <collection xmlns=\"http://www.loc.gov/MARC21/slim\">\n\
+            <record>\n"
+        ));
+        assert_eq!(xml.matches("<record>").count(), 3);
+        assert_eq!(records_from_marcxml_collection(&xml)?, records);
+
+        assert!(records_to_marcxml_collection(&[])
+            .ends_with("slim\">\n</collection>\n"));
+        assert!(records_from_marcxml_collection("<collection/>")?
+            .is_empty());
+        assert!(matches!(
+            records_from_marcxml_collection("<collection><record>"),
+            Err(ParseMarcXmlError::UnexpectedEof)
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use memmap2::Mmap;
+
+use crate::error::ParseRecordError;
+use crate::reader::{records, Records};
+use crate::RecordRef;
+
+/// A zero-copy reader over the records of a memory-mapped file.
+///
+/// The reader yields a [RecordRef] for each record, which borrows the
+/// mapped bytes; the records are split by their declared length and
+/// no record data is copied. Errors carry the byte offset relative to
+/// the start of the mapping.
+///
+/// # Example
+///
+/// ```rust
+/// use std::fs::File;
+///
+/// use marc21::MarcMmapReader;
+/// use memmap2::Mmap;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let file = File::open("tests/data/arithmetic.mrc")?;
+///     let mmap = unsafe { Mmap::map(&file)? };
+///
+///     let mut reader = MarcMmapReader::from_mmap(&mmap);
+///     let record = reader.next().unwrap()?;
+///     assert!(record.fields_by_tag(b"245").next().is_some());
+///     assert!(reader.next().is_none());
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MarcMmapReader<'a> {
+    records: Records<'a>,
+}
+
+impl<'a> MarcMmapReader<'a> {
+    /// Creates a new reader over a byte slice, e.g. a memory map.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            records: records(data),
+        }
+    }
+
+    /// Creates a new reader over a memory-mapped file.
+    pub fn from_mmap(mmap: &'a Mmap) -> Self {
+        Self::new(mmap)
+    }
+}
+
+impl<'a> Iterator for MarcMmapReader<'a> {
+    type Item = Result<RecordRef<'a>, ParseRecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.records.next_slice()?.and_then(|(position, data)| {
+            RecordRef::from_bytes(data).map_err(|e| e.offset(position))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, File};
+
+    use super::*;
+
+    #[test]
+    fn test_mmap_reader() -> anyhow::Result<()> {
+        let path = std::env::temp_dir()
+            .join(format!("marc21-mmap-{}.mrc", std::process::id()));
+        fs::write(
+            &path,
+            b"00048nam a2200037 c 4500001001000000\x1e123456789\x1e\x1d\
+            00048nam a2200037 c 4500001001000000\x1e987654321\x1e\x1d\
+            00048nam a2200037",
+        )?;
+
+        let file = File::open(&path)?;
+        let mmap = unsafe { Mmap::map(&file)? };
+        fs::remove_file(&path)?;
+
+        let mut reader = MarcMmapReader::from_mmap(&mmap);
+        let record = reader.next().unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        assert_eq!(
+            record.control_number().unwrap().as_ptr(),
+            mmap[37..].as_ptr()
+        );
+
+        let record = reader.next().unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"987654321"[..]));
+
+        assert!(matches!(
+            reader.next(),
+            Some(Err(ParseRecordError::Truncated))
+        ));
+        assert!(reader.next().is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,609 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use core::iter;
+
+use crate::record::{is_control_tag, FieldOrder};
+use crate::{
+    ControlField, DataField, Field, Leader, ParseLeaderError, Record,
+    Subfield,
+};
+
+/// The mnemonics for the characters which have a special meaning in
+/// the MARCMaker format.
+const MNEMONICS: [(&str, char); 4] = [
+    ("dollar", '$'),
+    ("lcub", '{'),
+    ("rcub", '}'),
+    ("bsol", '\\'),
+];
+
+/// An error that can occur when parsing the MARCMaker text format.
+///
+/// Errors within a field carry the (1-based) number of the line on
+/// which the field starts.
+#[derive(Debug)]
+pub enum ParseMrkError {
+    Leader(ParseLeaderError),
+    MissingLeader,
+    InvalidField { line: usize },
+    InvalidIndicator { line: usize },
+    InvalidSubfield { line: usize },
+}
+
+impl fmt::Display for ParseMrkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Leader(e) => write!(f, "invalid leader: {e}"),
+            Self::MissingLeader => f.write_str("missing leader"),
+            Self::InvalidField { line } => {
+                write!(f, "invalid field on line {line}")
+            }
+            Self::InvalidIndicator { line } => {
+                write!(f, "invalid indicator on line {line}")
+            }
+            Self::InvalidSubfield { line } => {
+                write!(f, "invalid subfield on line {line}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseMrkError {}
+
+impl From<ParseLeaderError> for ParseMrkError {
+    fn from(e: ParseLeaderError) -> Self {
+        Self::Leader(e)
+    }
+}
+
+impl Record {
+    /// Converts the record into the MARCMaker (mnemonic) text format.
+    ///
+    /// Each field is emitted on its own line, starting with `=`, the
+    /// tag and two spaces, in the order of [Record::all_fields]. The
+    /// leader is emitted as the `LDR` line, control fields print their
+    /// data and data fields print their indicators followed by the
+    /// subfields, each introduced by `$`. A blank in a control field
+    /// or indicator is written as `\`. A literal `$` or `{` within a
+    /// value is written as the mnemonic `{dollar}` or `{lcub}`, and a
+    /// literal `\` within a control field as `{bsol}`. Bytes which
+    /// aren't valid UTF-8 are replaced by `U+FFFD`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     )?;
+    ///
+    ///     assert_eq!(
+    ///         record.to_mrk(),
+    ///         "=LDR  00048nam a2200037 c 4500\n=001  123456789\n"
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_mrk(&self) -> String {
+        let mut out = String::new();
+        write_record(&mut out, self)
+            .expect("writing to a string can't fail");
+        out
+    }
+
+    /// Returns the lines of the MARCMaker (mnemonic) representation of
+    /// the record, as written by [Record::to_mrk], without building
+    /// the whole text at once.
+    ///
+    /// The first line is the `LDR` line, followed by one line per
+    /// field. The lines don't contain the line break.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let mut lines = record.to_breaker_lines();
+    ///     assert_eq!(
+    ///         lines.next().unwrap(),
+    ///         "=LDR  00048nam a2200037 c 4500"
+    ///     );
+    ///     assert_eq!(lines.next().unwrap(), "=001  123456789");
+    ///     assert_eq!(lines.next(), None);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_breaker_lines(
+        &self,
+    ) -> impl Iterator<Item = String> + '_ {
+        let leader = line(|out| write_leader(out, &self.leader));
+        let fields = self
+            .all_fields()
+            .map(|field| line(|out| write_field(out, field)));
+
+        iter::once(leader).chain(fields)
+    }
+
+    /// Creates a record from the MARCMaker (mnemonic) text format, as
+    /// written by [Record::to_mrk].
+    ///
+    /// Every line starting with `=` begins a new field; all other
+    /// non-empty lines continue the previous field, such that wrapped
+    /// lines are joined. A `\` in a control field or indicator is
+    /// read as a blank, and the mnemonics `{dollar}`, `{lcub}`,
+    /// `{rcub}` and `{bsol}` within a value as a literal `$`, `{`,
+    /// `}` and `\`. Other mnemonics are kept as is. The fields keep
+    /// the order of the input, and the record length and the base
+    /// address of data of the leader are recomputed from the fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_mrk(
+    ///         "=LDR  00053nam a2200037 c 4500\n\
+    ///         =245  00$aArithmetic\n",
+    ///     )?;
+    ///
+    ///     let field = record.fields(b"245").next().unwrap();
+    ///     assert_eq!(field.ind1(), b'0');
+    ///     assert_eq!(
+    ///         field.subfield(b'a').unwrap().value(),
+    ///         b"Arithmetic"
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_mrk(data: &str) -> Result<Self, ParseMrkError> {
+        let mut leader = None;
+        let mut control_fields = vec![];
+        let mut data_fields = vec![];
+        let mut kinds = vec![];
+
+        for (line, field) in split_fields(data) {
+            let line = line + 1;
+            let (tag, value) = field
+                .strip_prefix('=')
+                .and_then(|field| field.split_at_checked(3))
+                .and_then(|(tag, rest)| {
+                    Some((tag, rest.strip_prefix("  ")?))
+                })
+                .ok_or(ParseMrkError::InvalidField { line })?;
+
+            if tag == "LDR" {
+                let value = value.replace('\\', " ");
+                leader = Some(Leader::from_bytes(value.as_bytes())?);
+                continue;
+            }
+
+            let tag: [u8; 3] = tag
+                .as_bytes()
+                .try_into()
+                .map_err(|_| ParseMrkError::InvalidField { line })?;
+
+            if is_control_tag(&tag) {
+                control_fields.push(ControlField::new(
+                    tag,
+                    unescape(value, true),
+                ));
+                kinds.push(true);
+            } else {
+                data_fields.push(parse_data_field(tag, value, line)?);
+                kinds.push(false);
+            }
+        }
+
+        let mut record = Record {
+            leader: leader.ok_or(ParseMrkError::MissingLeader)?,
+            control_fields,
+            data_fields,
+            length_overflowed: false,
+            missing_terminator: false,
+            original: Default::default(),
+            directory: Default::default(),
+            field_order: FieldOrder::new(kinds),
+        };
+
+        // The record length and the base address of data of the `LDR`
+        // line needn't match the fields.
+        record.update_leader();
+        Ok(record)
+    }
+}
+
+/// Splits the input into fields, each paired with the (0-based)
+/// number of the line on which it starts.
+fn split_fields(data: &str) -> Vec<(usize, String)> {
+    let mut fields: Vec<(usize, String)> = vec![];
+
+    for (number, line) in data.lines().enumerate() {
+        if line.starts_with('=') {
+            fields.push((number, line.to_string()));
+        } else if let Some((_, field)) = fields.last_mut() {
+            field.push_str(line);
+        } else if !line.trim().is_empty() {
+            // A continuation line without a preceding field.
+            fields.push((number, line.to_string()));
+        }
+    }
+
+    fields
+}
+
+/// Parses the indicators and subfields of a data field.
+fn parse_data_field(
+    tag: [u8; 3],
+    value: &str,
+    line: usize,
+) -> Result<DataField, ParseMrkError> {
+    let indicator = |ch: Option<char>| match ch {
+        Some('\\') => Ok(b' '),
+        Some(ch) if ch.is_ascii() => Ok(ch as u8),
+        _ => Err(ParseMrkError::InvalidIndicator { line }),
+    };
+
+    let mut chars = value.chars();
+    let ind1 = indicator(chars.next())?;
+    let ind2 = indicator(chars.next())?;
+
+    let mut parts = chars.as_str().split('$');
+    if parts.next() != Some("") {
+        return Err(ParseMrkError::InvalidSubfield { line });
+    }
+
+    let subfields = parts
+        .map(|part| match part.as_bytes().first() {
+            Some(code) if code.is_ascii() => {
+                Ok(Subfield::new(*code, unescape(&part[1..], false)))
+            }
+            _ => Err(ParseMrkError::InvalidSubfield { line }),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(DataField {
+        tag,
+        ind1,
+        ind2,
+        subfields,
+    })
+}
+
+/// Decodes the mnemonics of [MNEMONICS] within `value`. If `blanks`
+/// is set, a `\` is read as a blank.
+fn unescape(value: &str, blanks: bool) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(ch) = rest.chars().next() {
+        let mnemonic = rest
+            .strip_prefix('{')
+            .and_then(|rest| rest.split_once('}'))
+            .and_then(|(name, rest)| {
+                MNEMONICS
+                    .iter()
+                    .find(|(mnemonic, _)| *mnemonic == name)
+                    .map(|(_, ch)| (*ch, rest))
+            });
+
+        if let Some((ch, after)) = mnemonic {
+            out.push(ch);
+            rest = after;
+        } else {
+            out.push(if blanks && ch == '\\' { ' ' } else { ch });
+            rest = &rest[ch.len_utf8()..];
+        }
+    }
+
+    out
+}
+
+fn write_record<W: Write>(out: &mut W, record: &Record) -> fmt::Result {
+    write_leader(out, &record.leader)?;
+    writeln!(out)?;
+
+    for field in record.all_fields() {
+        write_field(out, field)?;
+        writeln!(out)?;
+    }
+
+    Ok(())
+}
+
+/// Writes a single line (without the line break) by means of `f`.
+fn line(f: impl FnOnce(&mut String) -> fmt::Result) -> String {
+    let mut out = String::new();
+    f(&mut out).expect("writing to a string can't fail");
+    out
+}
+
+fn write_leader<W: Write>(out: &mut W, leader: &Leader) -> fmt::Result {
+    write!(out, "=LDR  {leader}")
+}
+
+fn write_field<W: Write>(out: &mut W, field: Field) -> fmt::Result {
+    match field {
+        Field::Control(field) => write_control_field(out, field),
+        Field::Data(field) => write_data_field(out, field),
+    }
+}
+
+fn write_control_field<W: Write>(
+    out: &mut W,
+    field: &ControlField,
+) -> fmt::Result {
+    write_tag(out, &field.tag)?;
+    write_escaped(out, &field.data, true)
+}
+
+fn write_data_field<W: Write>(
+    out: &mut W,
+    field: &DataField,
+) -> fmt::Result {
+    write_tag(out, &field.tag)?;
+    write_indicator(out, field.ind1)?;
+    write_indicator(out, field.ind2)?;
+
+    for subfield in field.subfields.iter() {
+        out.write_char('$')?;
+        write_escaped(out, &[subfield.code], false)?;
+        write_escaped(out, &subfield.value, false)?;
+    }
+
+    Ok(())
+}
+
+fn write_tag<W: Write>(out: &mut W, tag: &[u8; 3]) -> fmt::Result {
+    write!(out, "={}  ", String::from_utf8_lossy(tag))
+}
+
+fn write_indicator<W: Write>(
+    out: &mut W,
+    indicator: u8,
+) -> fmt::Result {
+    if indicator == b' ' {
+        out.write_char('\\')
+    } else {
+        write_escaped(out, &[indicator], false)
+    }
+}
+
+/// Writes the (lossy) UTF-8 representation of `value`, escaping the
+/// subfield delimiter `$` and the start of a mnemonic `{`. If `blanks`
+/// is set, a blank is written as `\` and a literal `\` is escaped.
+fn write_escaped<W: Write>(
+    out: &mut W,
+    value: &[u8],
+    blanks: bool,
+) -> fmt::Result {
+    for ch in String::from_utf8_lossy(value).chars() {
+        match ch {
+            '$' => out.write_str("{dollar}")?,
+            '{' => out.write_str("{lcub}")?,
+            ' ' if blanks => out.write_char('\\')?,
+            '\\' if blanks => out.write_str("{bsol}")?,
+            _ => out.write_char(ch)?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RecordBuilder;
+
+    #[test]
+    fn test_to_mrk() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .control_field(
+                b"008",
+                "751101s1975    nyu           000 0 eng  ",
+            )
+            .data_field(
+                b"100",
+                b'1',
+                b' ',
+                [Subfield::new(b'a', "Knuth, Donald E.")],
+            )
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [
+                    Subfield::new(b'a', "Title :"),
+                    Subfield::new(b'b', "subtitle."),
+                ],
+            )
+            .data_field(
+                b"500",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "Price: $10.")],
+            )
+            .build();
+
+        assert_eq!(
+            record.to_mrk(),
+            "=LDR  00197n   a2200085   4500\n\
+            =001  123456789\n\
+            =008  751101s1975\\\\\\\\nyu\\\\\\\\\\\\\\\\\\\\\\000\\0\\eng\\\\\n\
+            =100  1\\$aKnuth, Donald E.\n\
+            =245  10$aTitle :$bsubtitle.\n\
+            =500  \\\\$aPrice: {dollar}10.\n"
+        );
+    }
+
+    #[test]
+    fn test_from_mrk() -> anyhow::Result<()> {
+        let record = Record::from_bytes(
+            b"00122nam a2200061 c 450000100100000000300070001024500430\
+            0017\x1e123456789\x1eDE-101\x1e10\x1faArithmetic\
+            \x20<poems> /\x1fcSandburg & Rand.\x1e\x1d",
+        )?;
+
+        let mrk = record.to_mrk();
+        assert_eq!(
+            Record::from_mrk(&mrk)?.to_bytes(),
+            record.to_bytes()
+        );
+
+        let record = Record::from_mrk(
+            "=LDR  00053nam\\a2200037\\c\\4500\r\n\
+            =245  \\0$aArith\r\n\
+            metic $bPrice: {dollar}10\r\n\
+            \r\n",
+        )?;
+
+        // The record length and the base address of data are
+        // recomputed from the fields.
+        let field = record.fields(b"245").next().unwrap();
+        assert_eq!(
+            record.leader().to_string(),
+            "00066nam a2200037 c 4500"
+        );
+        assert_eq!(record.to_bytes().len(), 66);
+        assert_eq!(field.ind1(), b' ');
+        assert_eq!(field.ind2(), b'0');
+        assert_eq!(
+            field.subfield(b'a').unwrap().value(),
+            b"Arithmetic "
+        );
+        assert_eq!(
+            field.subfield(b'b').unwrap().value(),
+            b"Price: $10"
+        );
+
+        assert!(matches!(
+            Record::from_mrk("=001  123456789\n"),
+            Err(ParseMrkError::MissingLeader)
+        ));
+        assert!(matches!(
+            Record::from_mrk(
+                "=LDR  00053nam a2200037 c 4500\n=24500$a"
+            ),
+            Err(ParseMrkError::InvalidField { line: 2 })
+        ));
+        assert!(matches!(
+            Record::from_mrk(
+                "=LDR  00053nam a2200037 c 4500\n=245  00a"
+            ),
+            Err(ParseMrkError::InvalidSubfield { line: 2 })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mrk_field_order() -> anyhow::Result<()> {
+        let record = Record::from_bytes(
+            b"00067nam a2200049 c 4500245001000000008000700010\x1e\
+            00\x1faTitle\x1e740101\x1e\x1d",
+        )?;
+
+        assert_eq!(
+            record.to_mrk(),
+            "=LDR  00067nam a2200049 c 4500\n\
+            =245  00$aTitle\n\
+            =008  740101\n"
+        );
+        assert_eq!(
+            record.to_breaker_lines().collect::<Vec<_>>(),
+            record.to_mrk().lines().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Record::from_mrk(&record.to_mrk())?.to_bytes(),
+            record.to_bytes()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mrk_escape() -> anyhow::Result<()> {
+        let record = RecordBuilder::new()
+            .control_field(b"007", "a\\b {c}")
+            .data_field(
+                b"500",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "{dollar} $5 {x} a\\b")],
+            )
+            .build();
+
+        let mrk = record.to_mrk();
+        assert_eq!(
+            mrk.lines().skip(1).collect::<Vec<_>>(),
+            [
+                "=007  a{bsol}b\\{lcub}c}",
+                "=500  \\\\$a{lcub}dollar} {dollar}5 {lcub}x} a\\b",
+            ]
+        );
+        assert_eq!(Record::from_mrk(&mrk)?, record);
+
+        let record = Record::from_mrk(
+            "=LDR  00053nam a2200037 c 4500\n\
+            =008  \\\\{rcub}{eacute}\n\
+            =500  \\\\$a{lcub}{rcub}{bsol}{eacute}\n",
+        )?;
+        assert_eq!(
+            record.control_fields_by_tag(b"008").next().unwrap().data(),
+            b"  }{eacute}"
+        );
+        assert_eq!(
+            record.first_subfield_value(b"500", b'a'),
+            Some(&b"{}\\{eacute}"[..])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_breaker_lines() -> anyhow::Result<()> {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [
+                    Subfield::new(b'a', "Title :"),
+                    Subfield::new(b'b', "subtitle."),
+                ],
+            )
+            .data_field(
+                b"500",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "Price: $10.")],
+            )
+            .build();
+
+        let lines: Vec<String> = record.to_breaker_lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1], "=001  123456789");
+        assert_eq!(lines[3], "=500  \\\\$aPrice: {dollar}10.");
+        assert_eq!(lines, record.to_mrk().lines().collect::<Vec<_>>());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use alloc::string::String;
+use core::fmt;
+
+use crate::leader::MAX_RECORD_LEN;
+use crate::{FIELD_TERMINATOR, RECORD_TERMINATOR, SUBFIELD_DELIMITER};
+
+/// Options that control how records are parsed.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{ParseOptions, ParseWarning, Record};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let options = ParseOptions {
+///         lenient: true,
+///         ..Default::default()
+///     };
+///     let (record, warnings) = Record::from_bytes_with(
+///         b"00047nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e",
+///         &options,
+///     )?;
+///
+///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+///     assert_eq!(warnings, vec![ParseWarning::MissingTerminator]);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// If enabled, recoverable spec violations are collected as
+    /// [`ParseWarning`]s instead of failing the parse.
+    pub lenient: bool,
+
+    /// The byte introducing a subfield (default: `0x1F`).
+    pub subfield_delimiter: u8,
+
+    /// The byte terminating a field (default: `0x1E`).
+    pub field_terminator: u8,
+
+    /// The byte terminating a record (default: `0x1D`).
+    pub record_terminator: u8,
+
+    /// How subfield values which aren't valid UTF-8 are handled in
+    /// records whose character coding scheme is UTF-8 (default:
+    /// [`Utf8Validation::Raw`]).
+    pub utf8_validation: Utf8Validation,
+
+    /// If enabled, the original bytes of a parsed record are retained
+    /// and emitted verbatim by [`Record::to_bytes`] as long as the
+    /// record isn't modified, preserving the exact layout of the
+    /// directory and the data area. Records with recovered spec
+    /// violations or replaced values don't retain their bytes.
+    ///
+    /// [`Record::to_bytes`]: crate::Record::to_bytes
+    pub preserve_layout: bool,
+
+    /// The maximum declared record length accepted by [`MarcReader`]
+    /// and [`records_with`] (default: `99999`). Longer records fail
+    /// with [`ParseRecordError::RecordTooLarge`] before their data is
+    /// buffered, which guards against hostile input.
+    ///
+    /// [`MarcReader`]: crate::MarcReader
+    /// [`records_with`]: crate::records_with
+    /// [`ParseRecordError::RecordTooLarge`]: crate::ParseRecordError::RecordTooLarge
+    pub max_record_len: u32,
+
+    /// If enabled in lenient mode, line feeds (`0x0A`) and CRLF
+    /// sequences (`0x0D0A`) are taken as field terminators in records
+    /// without any `0x1E` byte, as produced by text-mode file
+    /// transfers; a trailing pair of line feeds is taken as the field
+    /// and the record terminator. Such records are reported with
+    /// [`ParseWarning::LineFeedTerminators`].
+    pub accept_lf_terminators: bool,
+}
+
+/// The handling of subfield values which aren't valid UTF-8, see
+/// [`ParseOptions::utf8_validation`].
+///
+/// Only records whose character coding scheme (leader position 09)
+/// is UTF-8 are checked; MARC-8 records are always kept as is.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{ParseOptions, Record, Utf8Validation};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let data = b"00048nam a2200037 c 4500245001000000\x1e\
+///         00\x1faTit\xffe\x1e\x1d";
+///
+///     let options = ParseOptions {
+///         utf8_validation: Utf8Validation::Lossy,
+///         ..Default::default()
+///     };
+///
+///     let (record, _) = Record::from_bytes_with(data, &options)?;
+///     assert_eq!(
+///         record.first_subfield_value(b"245", b'a'),
+///         Some("Tit\u{fffd}e".as_bytes())
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Utf8Validation {
+    /// Keep the bytes as they are.
+    #[default]
+    Raw,
+    /// Replace invalid sequences with `U+FFFD`.
+    Lossy,
+    /// Fail with [`ParseRecordError::InvalidUtf8`].
+    ///
+    /// [`ParseRecordError::InvalidUtf8`]: crate::ParseRecordError::InvalidUtf8
+    Strict,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            lenient: false,
+            subfield_delimiter: SUBFIELD_DELIMITER,
+            field_terminator: FIELD_TERMINATOR,
+            record_terminator: RECORD_TERMINATOR,
+            utf8_validation: Utf8Validation::default(),
+            preserve_layout: false,
+            max_record_len: MAX_RECORD_LEN,
+            accept_lf_terminators: false,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Returns a translation table which maps the configured
+    /// delimiter bytes onto the standard ones, or `None` if the
+    /// standard delimiters are used.
+    ///
+    /// The table is a permutation: the standard bytes which aren't
+    /// configured as delimiters take over the freed bytes, such that
+    /// a translated record can be translated back without loss. This
+    /// requires the delimiter bytes to be pairwise distinct.
+    pub(crate) fn delimiter_table(&self) -> Option<[u8; 256]> {
+        let custom = [
+            self.subfield_delimiter,
+            self.field_terminator,
+            self.record_terminator,
+        ];
+        let standard =
+            [SUBFIELD_DELIMITER, FIELD_TERMINATOR, RECORD_TERMINATOR];
+
+        if custom == standard {
+            return None;
+        }
+
+        let mut table: [u8; 256] = core::array::from_fn(|i| i as u8);
+        let freed = custom.iter().filter(|b| !standard.contains(b));
+        let displaced = standard.iter().filter(|b| !custom.contains(b));
+        for (from, to) in displaced.zip(freed) {
+            table[*from as usize] = *to;
+        }
+
+        for (from, to) in custom.iter().zip(standard) {
+            table[*from as usize] = to;
+        }
+
+        Some(table)
+    }
+}
+
+/// A recoverable spec violation found while parsing in lenient mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseWarning {
+    LengthMismatch { declared: u32, actual: u32 },
+    InvalidIndicator { tag: [u8; 3], indicator: u8 },
+    InvalidSubfieldCode { tag: [u8; 3], code: u8 },
+    MissingTerminator,
+    DoubledTerminator,
+    LineFeedTerminators,
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch { declared, actual } => write!(
+                f,
+                "record length mismatch (declared {declared}, actual \
+                 {actual})"
+            ),
+            Self::InvalidIndicator { tag, indicator } => write!(
+                f,
+                "invalid indicator {indicator:#04x} in field {}",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::InvalidSubfieldCode { tag, code } => write!(
+                f,
+                "invalid subfield code {code:#04x} in field {}",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::MissingTerminator => {
+                f.write_str("missing record terminator")
+            }
+            Self::DoubledTerminator => f.write_str(
+                "doubled field terminators in the data area",
+            ),
+            Self::LineFeedTerminators => f.write_str(
+                "line feeds in place of the field terminators",
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ParseWarning {}
@@ -0,0 +1,79 @@
+use rayon::prelude::*;
+
+use crate::error::ParseRecordError;
+use crate::reader::records;
+use crate::Record;
+
+/// Parses all records of a byte slice in parallel.
+///
+/// The record boundaries are determined first by a cheap sequential
+/// scan over the 5-byte record lengths, then the records are parsed
+/// in parallel. The results are returned in input order and match
+/// those of [records](crate::records): if the data ends within a
+/// record, the last result is a [ParseRecordError::Truncated] error.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let data = b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d00048nam a2200037 c 4500001001000000\x1e\
+///         987654321\x1e\x1d";
+///
+///     let result = marc21::par_records(data);
+///     assert_eq!(result.len(), 2);
+///     assert_eq!(
+///         result[1].as_ref().unwrap().control_number(),
+///         Some(&b"987654321"[..])
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+pub fn par_records(
+    data: &[u8],
+) -> Vec<Result<Record, ParseRecordError>> {
+    let mut iter = records(data);
+    let mut slices = vec![];
+    while let Some(slice) = iter.next_slice() {
+        slices.push(slice);
+    }
+
+    slices
+        .into_par_iter()
+        .map(|slice| {
+            slice.and_then(|(position, data)| {
+                Record::from_bytes(data).map_err(|e| e.offset(position))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: &[u8] = include_bytes!("../tests/data/two_records.mrc");
+
+    #[test]
+    fn test_par_records() {
+        let mut data = DATA.repeat(100);
+        data[48 + 51] = b'x';
+        data.extend_from_slice(&DATA[..60]);
+
+        let expected = records(&data)
+            .map(|result| result.map_err(|e| e.to_string()))
+            .collect::<Vec<_>>();
+        let result = par_records(&data)
+            .into_iter()
+            .map(|result| result.map_err(|e| e.to_string()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(result.len(), 202);
+        assert_eq!(result, expected);
+        assert!(result[1].is_err());
+        assert_eq!(result[201], Err("truncated record".to_string()));
+        assert!(par_records(b"").is_empty());
+    }
+}
@@ -0,0 +1,205 @@
+use alloc::vec::Vec;
+
+use crate::{DataField, Record};
+
+/// The function of the entity named in a publication statement, as
+/// given by the second indicator of the `264` field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PublicationFunction {
+    /// Production (`264 _0`).
+    Production,
+    /// Publication (`264 _1` or `260`).
+    Publication,
+    /// Distribution (`264 _2`).
+    Distribution,
+    /// Manufacture (`264 _3`).
+    Manufacture,
+    /// Copyright notice date (`264 _4`).
+    CopyrightNotice,
+}
+
+/// The publication statement of a record, taken from a `264` or a
+/// `260` field.
+///
+/// The values are the raw subfield values, including any ISBD
+/// punctuation (e.g. `New York :`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Publication<'a> {
+    /// The function of the named entity.
+    pub function: PublicationFunction,
+    /// The places of publication (`$a`).
+    pub place: Vec<&'a [u8]>,
+    /// The names of the publishers (`$b`).
+    pub publisher: Vec<&'a [u8]>,
+    /// The date of publication (`$c`).
+    pub date: Option<&'a [u8]>,
+}
+
+impl<'a> Publication<'a> {
+    /// Creates the publication statement of a `260` or `264` field.
+    fn from_field(field: &'a DataField) -> Option<Self> {
+        let function = match (&field.tag, field.ind2) {
+            (b"260", _) | (b"264", b'1') => {
+                PublicationFunction::Publication
+            }
+            (b"264", b'0') => PublicationFunction::Production,
+            (b"264", b'2') => PublicationFunction::Distribution,
+            (b"264", b'3') => PublicationFunction::Manufacture,
+            (b"264", b'4') => PublicationFunction::CopyrightNotice,
+            _ => return None,
+        };
+
+        let values = |code| {
+            field
+                .subfields
+                .iter()
+                .filter(move |subfield| subfield.code == code)
+                .map(|subfield| subfield.value())
+        };
+
+        Some(Self {
+            function,
+            place: values(b'a').collect(),
+            publisher: values(b'b').collect(),
+            date: values(b'c').next(),
+        })
+    }
+}
+
+impl Record {
+    /// Returns the publication statement of the record.
+    ///
+    /// The first `264` field with the second indicator `1`
+    /// (publication) is preferred, followed by the first `260` field.
+    /// Otherwise, the first `264` field with another function (e.g.
+    /// production or distribution) is returned; its function is
+    /// given by [Publication::function].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{PublicationFunction, RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"264",
+    ///             b' ',
+    ///             b'1',
+    ///             [
+    ///                 Subfield::new(b'a', "New York :"),
+    ///                 Subfield::new(b'b', "Harcourt,"),
+    ///                 Subfield::new(b'c', "1993."),
+    ///             ],
+    ///         )
+    ///         .build();
+    ///
+    ///     let publication = record.publication().unwrap();
+    ///     assert_eq!(
+    ///         publication.function,
+    ///         PublicationFunction::Publication
+    ///     );
+    ///     assert_eq!(publication.place, [b"New York :"]);
+    ///     assert_eq!(publication.publisher, [b"Harcourt,"]);
+    ///     assert_eq!(publication.date, Some(&b"1993."[..]));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn publication(&self) -> Option<Publication<'_>> {
+        let find = |f: fn(&DataField) -> bool| {
+            self.data_fields.iter().find(|field| f(field))
+        };
+
+        find(|field| &field.tag == b"264" && field.ind2 == b'1')
+            .or_else(|| find(|field| &field.tag == b"260"))
+            .or_else(|| find(|field| &field.tag == b"264"))
+            .and_then(Publication::from_field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RecordBuilder, Subfield};
+
+    #[test]
+    fn test_publication() {
+        let mut record = RecordBuilder::new()
+            .data_field(
+                b"260",
+                b' ',
+                b' ',
+                [
+                    Subfield::new(b'a', "San Diego :"),
+                    Subfield::new(b'b', "Harcourt Brace Jovanovich,"),
+                    Subfield::new(b'c', "c1993."),
+                ],
+            )
+            .data_field(
+                b"264",
+                b' ',
+                b'4',
+                [Subfield::new(b'c', "©1993")],
+            )
+            .data_field(
+                b"264",
+                b' ',
+                b'1',
+                [
+                    Subfield::new(b'a', "San Diego :"),
+                    Subfield::new(b'a', "New York :"),
+                    Subfield::new(b'b', "Harcourt,"),
+                    Subfield::new(b'c', "[1993]"),
+                ],
+            )
+            .build();
+
+        assert_eq!(
+            record.publication(),
+            Some(Publication {
+                function: PublicationFunction::Publication,
+                place: vec![b"San Diego :", b"New York :"],
+                publisher: vec![b"Harcourt,"],
+                date: Some(b"[1993]"),
+            })
+        );
+
+        record.retain_fields(|field| field.tag() != b"264");
+        let publication = record.publication().unwrap();
+        assert_eq!(
+            publication.function,
+            PublicationFunction::Publication
+        );
+        assert_eq!(publication.date, Some(&b"c1993."[..]));
+    }
+
+    #[test]
+    fn test_publication_other_function() {
+        let record = RecordBuilder::new()
+            .data_field(
+                b"264",
+                b' ',
+                b'4',
+                [Subfield::new(b'c', "©2010")],
+            )
+            .data_field(
+                b"264",
+                b' ',
+                b'2',
+                [Subfield::new(b'b', "Distributor")],
+            )
+            .build();
+
+        let publication = record.publication().unwrap();
+        assert_eq!(
+            publication.function,
+            PublicationFunction::CopyrightNotice
+        );
+        assert!(publication.place.is_empty());
+        assert_eq!(publication.date, Some("©2010".as_bytes()));
+
+        assert_eq!(RecordBuilder::new().build().publication(), None);
+    }
+}
@@ -0,0 +1,1097 @@
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+use nom::Finish;
+
+use crate::error::ParseRecordError;
+#[cfg(feature = "std")]
+use crate::leader::peek_record_length;
+use crate::leader::{parse_record_len, LEADER_LEN};
+use crate::{
+    Leader, ParseLeaderError, ParseOptions, Record, RECORD_TERMINATOR,
+};
+
+/// The number of bytes/octets of the record length.
+pub(crate) const RECORD_LEN_LEN: usize = 5;
+
+/// The UTF-8 byte order mark, which some exporters write before the
+/// first record.
+const BOM: &[u8] = b"\xef\xbb\xbf";
+
+/// A reader that reads a sequence of records from an underlying
+/// [Read] one at a time.
+///
+/// A UTF-8 byte order mark at the very start of the input is skipped.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::Cursor;
+///
+/// use marc21::MarcReader;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let data = Cursor::new(
+///         b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d",
+///     );
+///
+///     let mut reader = MarcReader::new(data);
+///     let record = reader.next().unwrap()?;
+///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+///     assert!(reader.next().is_none());
+///
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct MarcReader<R: Read> {
+    inner: R,
+    position: usize,
+    started: bool,
+    options: ParseOptions,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> MarcReader<R> {
+    /// Creates a new reader over the given [Read].
+    pub fn new(inner: R) -> Self {
+        Self::with_options(inner, &ParseOptions::default())
+    }
+
+    /// Creates a new reader over the given [Read], which parses the
+    /// records with the given options (see [Record::from_bytes_with]);
+    /// the warnings of lenient mode are discarded.
+    ///
+    /// A record exceeding [ParseOptions::max_record_len] fails with
+    /// [ParseRecordError::RecordTooLarge]; its data is skipped without
+    /// being buffered, such that reading continues with the next
+    /// record.
+    ///
+    /// In lenient mode, a last record lacking only its record
+    /// terminator at the end of the input is accepted; see
+    /// [Record::well_terminated].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    ///
+    /// use marc21::{MarcReader, ParseOptions, ParseRecordError};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let data = Cursor::new(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     );
+    ///
+    ///     let options = ParseOptions {
+    ///         max_record_len: 40,
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let mut reader = MarcReader::with_options(data, &options);
+    ///     assert!(matches!(
+    ///         reader.next().unwrap(),
+    ///         Err(ParseRecordError::RecordTooLarge { declared: 48, .. })
+    ///     ));
+    ///     assert!(reader.next().is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn with_options(inner: R, options: &ParseOptions) -> Self {
+        Self {
+            inner,
+            position: 0,
+            started: false,
+            options: options.clone(),
+        }
+    }
+
+    /// Reads the raw bytes of the next record.
+    ///
+    /// Returns `Ok(None)` if the end of the input has been reached
+    /// before the first byte of a record.
+    fn read_record(
+        &mut self,
+    ) -> Result<Option<Vec<u8>>, ParseRecordError> {
+        let mut data = vec![0; RECORD_LEN_LEN];
+        let mut len = read_full(&mut self.inner, &mut data)?;
+
+        if !self.started {
+            self.started = true;
+            if data[..len].starts_with(BOM) {
+                data.copy_within(BOM.len().., 0);
+                len -= BOM.len();
+                len += read_full(&mut self.inner, &mut data[len..])?;
+                self.position += BOM.len();
+            }
+        }
+
+        match len {
+            0 => return Ok(None),
+            RECORD_LEN_LEN => (),
+            _ => return Err(ParseRecordError::Truncated),
+        }
+
+        let (_, record_len) = parse_record_len(&data)
+            .finish()
+            .map_err(|_| ParseRecordError::Leader {
+                source: ParseLeaderError::InvalidRecordLength,
+                position: self.position,
+            })?;
+
+        if record_len > self.options.max_record_len {
+            let position = self.position;
+            let rest = (record_len as usize).saturating_sub(len) as u64;
+            self.position += len
+                + io::copy(
+                    &mut (&mut self.inner).take(rest),
+                    &mut io::sink(),
+                )? as usize;
+
+            return Err(ParseRecordError::RecordTooLarge {
+                declared: record_len,
+                max: self.options.max_record_len,
+                position,
+            });
+        }
+
+        let record_len = record_len as usize;
+        if record_len > RECORD_LEN_LEN {
+            data.resize(record_len, 0);
+            let missing = record_len
+                - RECORD_LEN_LEN
+                - read_full(
+                    &mut self.inner,
+                    &mut data[RECORD_LEN_LEN..],
+                )?;
+
+            // The input ends right before the record terminator of
+            // the last record, which is tolerated in lenient mode.
+            match missing {
+                0 => (),
+                1 if self.options.lenient => {
+                    data.truncate(record_len - 1)
+                }
+                _ => return Err(ParseRecordError::Truncated),
+            }
+        }
+
+        self.position += data.len();
+        Ok(Some(data))
+    }
+}
+
+#[cfg(feature = "flate2")]
+impl<R: Read> MarcReader<flate2::read::GzDecoder<R>> {
+    /// Creates a new reader over gzip-compressed input, e.g. a
+    /// `.mrc.gz` file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::fs::File;
+    ///
+    /// use marc21::MarcReader;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let file = File::open("tests/data/arithmetic.mrc.gz")?;
+    ///     let mut reader = MarcReader::from_gzip(file);
+    ///
+    ///     let record = reader.next().unwrap()?;
+    ///     assert_eq!(record.control_number(), Some(&b"12883376"[..]));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_gzip(inner: R) -> Self {
+        Self::new(flate2::read::GzDecoder::new(inner))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read> Iterator for MarcReader<R> {
+    type Item = Result<Record, ParseRecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let position = self.position;
+
+        match self.read_record() {
+            Ok(Some(data)) => Some(
+                Record::from_bytes_with(&data, &self.options)
+                    .map(|(record, _)| record)
+                    .map_err(|e| e.offset(position)),
+            ),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// An iterator over the records of a byte slice.
+///
+/// The iterator is created by [records]; see its documentation for
+/// details.
+#[derive(Debug)]
+pub struct Records<'a> {
+    data: &'a [u8],
+    position: usize,
+    options: ParseOptions,
+}
+
+/// Returns an iterator over all records of a byte slice, e.g. the
+/// contents of a whole file.
+///
+/// The iterator advances by each record's declared length and stops
+/// at the end of the data. If the data ends within a record, a
+/// [ParseRecordError::Truncated] error is returned. A UTF-8 byte
+/// order mark at the very start of the data is skipped.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let data = b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d00048nam a2200037 c 4500001001000000\x1e\
+///         987654321\x1e\x1d";
+///
+///     let mut records = marc21::records(data);
+///     let record = records.next().unwrap()?;
+///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+///     let record = records.next().unwrap()?;
+///     assert_eq!(record.control_number(), Some(&b"987654321"[..]));
+///     assert!(records.next().is_none());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn records(data: &[u8]) -> Records<'_> {
+    match data.strip_prefix(BOM) {
+        Some(data) => Records {
+            data,
+            position: BOM.len(),
+            options: ParseOptions::default(),
+        },
+        None => Records {
+            data,
+            position: 0,
+            options: ParseOptions::default(),
+        },
+    }
+}
+
+/// Returns an iterator over all records of a byte slice like
+/// [records], which parses the records with the given options (see
+/// [Record::from_bytes_with]); the warnings are discarded.
+///
+/// A record exceeding [ParseOptions::max_record_len] fails with
+/// [ParseRecordError::RecordTooLarge] and is skipped, such that the
+/// iteration continues with the next record.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{ParseOptions, ParseRecordError};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let data = b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d";
+///
+///     let options = ParseOptions {
+///         max_record_len: 40,
+///         ..Default::default()
+///     };
+///
+///     let mut records = marc21::records_with(data, &options);
+///     assert!(matches!(
+///         records.next().unwrap(),
+///         Err(ParseRecordError::RecordTooLarge { declared: 48, .. })
+///     ));
+///     assert!(records.next().is_none());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn records_with<'a>(
+    data: &'a [u8],
+    options: &ParseOptions,
+) -> Records<'a> {
+    Records {
+        options: options.clone(),
+        ..records(data)
+    }
+}
+
+/// Returns an iterator over the raw bytes of all records of a byte
+/// slice, from the leader through the record terminator.
+///
+/// This is the zero-parse sibling of [records]: the records are split
+/// by their declared length only, without parsing the leader or the
+/// directory.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let data = b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d00048nam a2200037 c 4500001001000000\x1e\
+///         987654321\x1e\x1d";
+///
+///     let mut iter = marc21::raw_records(data);
+///     assert_eq!(iter.next().unwrap()?, &data[..48]);
+///     assert_eq!(iter.next().unwrap()?, &data[48..]);
+///     assert!(iter.next().is_none());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn raw_records(
+    data: &[u8],
+) -> impl Iterator<Item = Result<&[u8], ParseRecordError>> {
+    let mut iter = records(data);
+    core::iter::from_fn(move || {
+        Some(iter.next_slice()?.map(|(_, data)| data))
+    })
+}
+
+/// Returns an iterator over all records of a byte slice like
+/// [records], which doesn't stop at invalid records.
+///
+/// An error is returned together with the byte offset of the invalid
+/// record. The iterator then resynchronizes at the next plausible
+/// record boundary, i.e. a valid leader whose declared record length
+/// ends with a record terminator: the end of the invalid record
+/// according to its declared length, if it's such a boundary, or else
+/// the next one found by scanning forward.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let data = b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d0x048nam a2200037 c 4500001001000000\x1e\
+///         987654321\x1e\x1d00048nam a2200037 c 4500001001000000\x1e\
+///         192837465\x1e\x1d";
+///
+///     let mut records = marc21::records_lenient(data);
+///     assert!(records.next().unwrap().is_ok());
+///     assert_eq!(records.next().unwrap().unwrap_err().1, 48);
+///     let record = records.next().unwrap().unwrap();
+///     assert_eq!(record.control_number(), Some(&b"192837465"[..]));
+///     assert!(records.next().is_none());
+///
+///     Ok(())
+/// }
+/// ```
+pub fn records_lenient(
+    data: &[u8],
+) -> impl Iterator<Item = Result<Record, (ParseRecordError, usize)>> + '_
+{
+    let mut iter = records(data);
+
+    core::iter::from_fn(move || {
+        let (start, position) = (iter.data, iter.position);
+        match iter.next()? {
+            Ok(record) => Some(Ok(record)),
+            Err(e) => {
+                if !is_record_start(iter.data) {
+                    let skip = (1..start.len())
+                        .find(|i| is_record_start(&start[*i..]))
+                        .unwrap_or(start.len());
+                    iter.data = &start[skip..];
+                    iter.position = position + skip;
+                }
+
+                Some(Err((e, position)))
+            }
+        }
+    })
+}
+
+/// Returns `true` if the data is a plausible record boundary, i.e. it
+/// starts with a valid leader and the byte at the end of the declared
+/// record length is a record terminator.
+fn is_record_start(data: &[u8]) -> bool {
+    let Some(Ok(leader)) =
+        data.get(..LEADER_LEN).map(Leader::from_bytes)
+    else {
+        return false;
+    };
+
+    (leader.record_length() as usize)
+        .checked_sub(1)
+        .and_then(|end| data.get(end))
+        == Some(&RECORD_TERMINATOR)
+}
+
+/// Parses as many complete records as possible from the start of a
+/// byte slice and returns them together with the unconsumed rest,
+/// e.g. a partial record at the end of a network buffer.
+///
+/// Parsing stops at the first record which is incomplete; it doesn't
+/// fail but is left at the start of the returned rest, such that it
+/// can be completed with further input. A complete record which
+/// can't be parsed stops parsing as well and is left in the rest, so
+/// that [Record::from_bytes] reports the error. A UTF-8 byte order
+/// mark at the very start of the data is skipped.
+///
+/// # Example
+///
+/// ```rust
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let data = b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d00048nam a2200037";
+///
+///     let (records, rest) = marc21::parse_records_prefix(data);
+///     assert_eq!(records.len(), 1);
+///     assert_eq!(rest, b"00048nam a2200037");
+///
+///     Ok(())
+/// }
+/// ```
+pub fn parse_records_prefix(data: &[u8]) -> (Vec<Record>, &[u8]) {
+    let mut iter = records(data);
+    let mut result = vec![];
+
+    loop {
+        let rest = iter.data;
+        let Some(Ok((_, data))) = iter.next_slice() else {
+            return (result, rest);
+        };
+
+        match Record::from_bytes(data) {
+            Ok(record) => result.push(record),
+            Err(_) => return (result, rest),
+        }
+    }
+}
+
+impl<'a> Records<'a> {
+    /// Returns the raw bytes of the next record together with its
+    /// offset into the data, without parsing the record.
+    pub(crate) fn next_slice(
+        &mut self,
+    ) -> Option<Result<(usize, &'a [u8]), ParseRecordError>> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let position = self.position;
+        let record_len = match parse_record_len(self.data).finish() {
+            Ok((_, record_len)) => record_len as usize,
+            Err(_) if self.data.len() < RECORD_LEN_LEN => {
+                self.data = &[];
+                return Some(Err(ParseRecordError::Truncated));
+            }
+            Err(_) => {
+                self.data = &[];
+                return Some(Err(ParseRecordError::Leader {
+                    source: ParseLeaderError::InvalidRecordLength,
+                    position,
+                }));
+            }
+        };
+
+        if record_len > self.options.max_record_len as usize {
+            let skip = record_len.min(self.data.len());
+            self.data = &self.data[skip..];
+            self.position += skip;
+
+            return Some(Err(ParseRecordError::RecordTooLarge {
+                declared: record_len as u32,
+                max: self.options.max_record_len,
+                position,
+            }));
+        }
+
+        if record_len > self.data.len() {
+            self.data = &[];
+            return Some(Err(ParseRecordError::Truncated));
+        }
+
+        // A record length of zero would never advance the iterator.
+        let (data, rest) = self.data.split_at(record_len);
+        self.data = if record_len > 0 { rest } else { &[] };
+        self.position += record_len;
+
+        Some(Ok((position, data)))
+    }
+}
+
+impl Iterator for Records<'_> {
+    type Item = Result<Record, ParseRecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_slice()?.and_then(|(position, data)| {
+            Record::from_bytes_with(data, &self.options)
+                .map(|(record, _)| record)
+                .map_err(|e| e.offset(position))
+        }))
+    }
+}
+
+/// Counts the records of the given [Read] without parsing them.
+///
+/// Only the 5-byte record length of each record is read; the rest of
+/// the record is skipped. An [io::ErrorKind::InvalidData] error is
+/// returned if a record length isn't made up of five ASCII digits,
+/// and an [io::ErrorKind::UnexpectedEof] error if the input ends
+/// within a record.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::Cursor;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let data = Cursor::new(
+///         b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d",
+///     );
+///
+///     assert_eq!(marc21::count_records(data)?, 1);
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn count_records<R: Read>(mut reader: R) -> io::Result<usize> {
+    let mut count = 0;
+    let mut data = [0; RECORD_LEN_LEN];
+
+    loop {
+        match read_full(&mut reader, &mut data)? {
+            0 => return Ok(count),
+            RECORD_LEN_LEN => (),
+            _ => return Err(io::ErrorKind::UnexpectedEof.into()),
+        }
+
+        let record_len = match peek_record_length(&data) {
+            Ok(record_len) if record_len as usize >= RECORD_LEN_LEN => {
+                record_len as u64
+            }
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    ParseLeaderError::InvalidRecordLength,
+                ))
+            }
+        };
+
+        let skip = record_len - RECORD_LEN_LEN as u64;
+        if io::copy(&mut (&mut reader).take(skip), &mut io::sink())?
+            != skip
+        {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+
+        count += 1;
+    }
+}
+
+/// Reads from `reader` until `buf` is full or the end of the input is
+/// reached, returning the number of bytes read.
+#[cfg(feature = "std")]
+fn read_full<R: Read>(
+    reader: &mut R,
+    buf: &mut [u8],
+) -> io::Result<usize> {
+    let mut pos = 0;
+
+    while pos < buf.len() {
+        match reader.read(&mut buf[pos..]) {
+            Ok(0) => break,
+            Ok(n) => pos += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => (),
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(pos)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "std")]
+    use std::io::Cursor;
+
+    use super::*;
+
+    const DATA: &[u8] = include_bytes!("../tests/data/two_records.mrc");
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_marc_reader() -> anyhow::Result<()> {
+        let mut reader = MarcReader::new(Cursor::new(DATA));
+
+        let record = reader.next().unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+
+        let record = reader.next().unwrap()?;
+        assert_eq!(record.leader().record_length(), 53);
+        assert_eq!(record.fields(b"245").count(), 1);
+
+        assert!(reader.next().is_none());
+        assert!(reader.next().is_none());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_marc_reader_empty() {
+        let mut reader = MarcReader::new(Cursor::new(b""));
+        assert!(reader.next().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_marc_reader_truncated() {
+        let mut reader = MarcReader::new(Cursor::new(&DATA[..60]));
+        assert!(reader.next().unwrap().is_ok());
+        assert!(matches!(
+            reader.next().unwrap(),
+            Err(ParseRecordError::Truncated)
+        ));
+        assert!(reader.next().is_none());
+
+        let mut reader = MarcReader::new(Cursor::new(&DATA[..3]));
+        assert!(matches!(
+            reader.next().unwrap(),
+            Err(ParseRecordError::Truncated)
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_marc_reader_invalid_length() {
+        let mut reader = MarcReader::new(Cursor::new(b"0x048nam"));
+        assert!(matches!(
+            reader.next().unwrap(),
+            Err(ParseRecordError::Leader {
+                source: ParseLeaderError::InvalidRecordLength,
+                position: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn test_records() -> anyhow::Result<()> {
+        let data = [DATA, &DATA[..48]].concat();
+
+        let mut iter = records(&data);
+        let record = iter.next().unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        let record = iter.next().unwrap()?;
+        assert_eq!(record.fields(b"245").count(), 1);
+        let record = iter.next().unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+
+        assert!(records(b"").next().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_truncated() {
+        let mut iter = records(&DATA[..60]);
+        assert!(iter.next().unwrap().is_ok());
+        assert!(matches!(
+            iter.next().unwrap(),
+            Err(ParseRecordError::Truncated)
+        ));
+        assert!(iter.next().is_none());
+
+        let mut iter = records(&DATA[..3]);
+        assert!(matches!(
+            iter.next().unwrap(),
+            Err(ParseRecordError::Truncated)
+        ));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_records_invalid() {
+        let mut data = DATA.to_vec();
+        data[48 + 51] = b'x';
+
+        let mut iter = records(&data);
+        assert!(iter.next().unwrap().is_ok());
+        assert!(matches!(
+            iter.next().unwrap(),
+            Err(ParseRecordError::InvalidField { position: 85 })
+        ));
+        assert!(iter.next().is_none());
+
+        let mut iter = records(b"0x048nam");
+        assert!(matches!(
+            iter.next().unwrap(),
+            Err(ParseRecordError::Leader {
+                source: ParseLeaderError::InvalidRecordLength,
+                position: 0
+            })
+        ));
+        assert!(iter.next().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_marc_reader_missing_terminator() -> anyhow::Result<()> {
+        let data = &DATA[..DATA.len() - 1];
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+
+        let mut reader = MarcReader::new(Cursor::new(data));
+        assert!(reader.next().unwrap()?.well_terminated());
+        assert!(matches!(
+            reader.next().unwrap(),
+            Err(ParseRecordError::Truncated)
+        ));
+
+        let mut reader =
+            MarcReader::with_options(Cursor::new(data), &options);
+        assert!(reader.next().unwrap()?.well_terminated());
+        let record = reader.next().unwrap()?;
+        assert!(!record.well_terminated());
+        assert_eq!(
+            record.first_subfield_value(b"245", b'a'),
+            Some(&b"Arithmetic"[..])
+        );
+        assert!(reader.next().is_none());
+
+        // The declared length doesn't account for the terminator.
+        let mut data = data.to_vec();
+        data[48 + 4] = b'2';
+
+        let mut reader = MarcReader::new(Cursor::new(&data));
+        assert!(reader.next().unwrap().is_ok());
+        assert!(matches!(
+            reader.next().unwrap(),
+            Err(ParseRecordError::MissingTerminator { position: 100 })
+        ));
+
+        let mut reader =
+            MarcReader::with_options(Cursor::new(&data), &options);
+        assert!(reader.next().unwrap().is_ok());
+        assert!(!reader.next().unwrap()?.well_terminated());
+        assert!(reader.next().is_none());
+
+        // Only the record terminator may be missing.
+        let data = &DATA[..DATA.len() - 2];
+        let mut reader =
+            MarcReader::with_options(Cursor::new(data), &options);
+        assert!(reader.next().unwrap().is_ok());
+        assert!(matches!(
+            reader.next().unwrap(),
+            Err(ParseRecordError::Truncated)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with() -> anyhow::Result<()> {
+        let data = b"00047nam a2200037 c 4500001001000000\x1e\
+            123456789\x1e";
+        assert!(records(data).next().unwrap().is_err());
+
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let mut iter = records_with(data, &options);
+        let record = iter.next().unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        assert!(iter.next().is_none());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_max_record_len() -> anyhow::Result<()> {
+        let options = ParseOptions {
+            max_record_len: 50,
+            ..Default::default()
+        };
+
+        let mut reader =
+            MarcReader::with_options(Cursor::new(DATA), &options);
+        assert!(reader.next().unwrap().is_ok());
+        assert!(matches!(
+            reader.next().unwrap(),
+            Err(ParseRecordError::RecordTooLarge {
+                declared: 53,
+                max: 50,
+                position: 48,
+            })
+        ));
+        assert!(reader.next().is_none());
+
+        let data = [&DATA[48..], &DATA[..48]].concat();
+        let mut iter = records_with(&data, &options);
+        assert!(matches!(
+            iter.next().unwrap(),
+            Err(ParseRecordError::RecordTooLarge {
+                declared: 53,
+                max: 50,
+                position: 0,
+            })
+        ));
+        let record = iter.next().unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        assert!(iter.next().is_none());
+
+        let mut iter = records_with(b"99999nam", &options);
+        assert!(matches!(
+            iter.next().unwrap(),
+            Err(ParseRecordError::RecordTooLarge {
+                declared: 99999,
+                ..
+            })
+        ));
+        assert!(iter.next().is_none());
+        assert_eq!(records(&data).count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_lenient() -> anyhow::Result<()> {
+        let check = |data: &[u8]| -> anyhow::Result<()> {
+            let mut iter = records_lenient(data);
+            let record = iter.next().unwrap().map_err(|(e, _)| e)?;
+            assert_eq!(
+                record.control_number(),
+                Some(&b"123456789"[..])
+            );
+            let (_, position) = iter.next().unwrap().unwrap_err();
+            assert_eq!(position, 48);
+            let record = iter.next().unwrap().map_err(|(e, _)| e)?;
+            assert_eq!(
+                record.control_number(),
+                Some(&b"123456789"[..])
+            );
+            assert!(iter.next().is_none());
+            Ok(())
+        };
+
+        // invalid record length
+        let mut data = [DATA, &DATA[..48]].concat();
+        data[49] = b'x';
+        check(&data)?;
+
+        // declared record length exceeds the record
+        data[49] = b'0';
+        data[52] = b'9';
+        check(&data)?;
+
+        // invalid field, but correct record length
+        data[52] = b'3';
+        data[48 + 51] = b'x';
+        check(&data)?;
+
+        let mut iter = records_lenient(&data[..60]);
+        assert!(iter.next().unwrap().is_ok());
+        assert!(matches!(
+            iter.next().unwrap(),
+            Err((ParseRecordError::Truncated, 48))
+        ));
+        assert!(iter.next().is_none());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_marc_reader_position() {
+        let mut data = DATA.to_vec();
+        data[48 + 51] = b'x';
+
+        let mut reader = MarcReader::new(Cursor::new(data));
+        assert!(reader.next().unwrap().is_ok());
+
+        let err = reader.next().unwrap().unwrap_err();
+        assert!(matches!(
+            err,
+            ParseRecordError::InvalidField { position: 85 }
+        ));
+        assert_eq!(err.position(), Some(85));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_count_records() -> anyhow::Result<()> {
+        let data = [DATA, &DATA[..48]].concat();
+        assert_eq!(count_records(Cursor::new(&data))?, 3);
+        assert_eq!(count_records(Cursor::new(b""))?, 0);
+
+        // The records are skipped without being parsed.
+        let mut data = data.clone();
+        data[48 + 51] = b'x';
+        assert_eq!(count_records(Cursor::new(&data))?, 3);
+
+        let err = count_records(Cursor::new(&DATA[..60])).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        let err = count_records(Cursor::new(&DATA[..50])).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        let err = count_records(Cursor::new(b"0x048nam")).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bom() -> anyhow::Result<()> {
+        let data = [BOM, DATA].concat();
+
+        let mut reader = MarcReader::new(Cursor::new(&data));
+        let record = reader.next().unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().is_none());
+
+        let mut iter = records(&data);
+        let record = iter.next().unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().is_none());
+
+        // Positions are relative to the start of the input, including
+        // the byte order mark.
+        let mut data = data.clone();
+        data[3 + 48 + 51] = b'x';
+        let mut reader = MarcReader::new(Cursor::new(&data));
+        assert!(reader.next().unwrap().is_ok());
+        assert_eq!(
+            reader.next().unwrap().unwrap_err().position(),
+            Some(88)
+        );
+        let mut iter = records(&data);
+        assert!(iter.next().unwrap().is_ok());
+        assert_eq!(
+            iter.next().unwrap().unwrap_err().position(),
+            Some(88)
+        );
+
+        // Only a byte order mark at the very start is skipped.
+        let data = [&DATA[..48], BOM, &DATA[48..]].concat();
+        let mut reader = MarcReader::new(Cursor::new(&data));
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().unwrap().is_err());
+        let mut iter = records(&data);
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().unwrap().is_err());
+
+        let mut reader = MarcReader::new(Cursor::new(BOM));
+        assert!(reader.next().is_none());
+        assert!(records(BOM).next().is_none());
+
+        Ok(())
+    }
+
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn test_marc_reader_from_gzip() -> anyhow::Result<()> {
+        use std::io::Write;
+
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+
+        let mut encoder =
+            GzEncoder::new(vec![], Compression::default());
+        encoder.write_all(DATA)?;
+        let data = encoder.finish()?;
+
+        let mut reader = MarcReader::from_gzip(Cursor::new(data));
+        let record = reader.next().unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        let record = reader.next().unwrap()?;
+        assert_eq!(record.fields(b"245").count(), 1);
+        assert!(reader.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_raw_records() -> anyhow::Result<()> {
+        let data = DATA.repeat(3);
+        let slices =
+            raw_records(&data).collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(slices.len(), 6);
+        assert_eq!(slices.concat(), data);
+
+        // The records aren't parsed.
+        let mut data = DATA.to_vec();
+        data[48 + 51] = b'x';
+        assert_eq!(raw_records(&data).filter(Result::is_ok).count(), 2);
+
+        let mut iter = raw_records(&DATA[..60]);
+        assert_eq!(iter.next().unwrap()?, &DATA[..48]);
+        assert!(matches!(
+            iter.next().unwrap(),
+            Err(ParseRecordError::Truncated)
+        ));
+        assert!(iter.next().is_none());
+
+        assert!(raw_records(b"").next().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_records_prefix() {
+        let (records, rest) = parse_records_prefix(&DATA[..60]);
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].control_number(),
+            Some(&b"123456789"[..])
+        );
+        assert_eq!(rest, &DATA[48..60]);
+
+        let mut data = rest.to_vec();
+        data.extend_from_slice(&DATA[60..]);
+        let (records, rest) = parse_records_prefix(&data);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].fields(b"245").count(), 1);
+        assert!(rest.is_empty());
+
+        let (records, rest) = parse_records_prefix(DATA);
+        assert_eq!(records.len(), 2);
+        assert!(rest.is_empty());
+
+        let (records, rest) = parse_records_prefix(&DATA[..3]);
+        assert!(records.is_empty());
+        assert_eq!(rest, &DATA[..3]);
+
+        let (records, rest) = parse_records_prefix(b"");
+        assert!(records.is_empty());
+        assert!(rest.is_empty());
+
+        // An invalid record is left at the start of the rest.
+        let mut data = DATA.to_vec();
+        data[48 + 12] = b'x';
+        let (records, rest) = parse_records_prefix(&data);
+        assert_eq!(records.len(), 1);
+        assert_eq!(rest, &data[48..]);
+    }
+}
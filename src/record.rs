@@ -0,0 +1,4345 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+
+use nom::Finish;
+
+use crate::control_field::parse_control_field;
+use crate::data_field::parse_data_field;
+use crate::directory::{parse_directory, DirectoryEntry};
+use crate::error::{ParseRecordError, SerializeError};
+use crate::leader::{
+    max_number, parse_leader, parse_record_len, write_number,
+    write_record_len, ParseResult, LEADER_LEN, MAX_RECORD_LEN,
+};
+use crate::validation::{is_valid_indicator, is_valid_subfield_code};
+use crate::{
+    CharacterCodingScheme, ControlField, DataField, DataFieldError,
+    Field, Fields, Leader, ParseOptions, ParseWarning, RecordStatus,
+    Subfield, Utf8Validation, FIELD_TERMINATOR, RECORD_TERMINATOR,
+    SUBFIELD_DELIMITER,
+};
+
+/// A MARC 21 record.
+///
+/// The methods which look up fields by tag accept anything that can be
+/// viewed as bytes, e.g. `b"245"` or a [FieldTag](crate::FieldTag).
+/// An `X` in the tag matches any character, e.g. `6XX` matches all
+/// subject fields.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Record {
+    pub(crate) leader: Leader,
+    pub(crate) control_fields: Vec<ControlField>,
+    pub(crate) data_fields: Vec<DataField>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) length_overflowed: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) missing_terminator: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) original: Original<Vec<u8>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) directory: Original<Vec<DirectoryEntry>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) field_order: FieldOrder,
+}
+
+/// The order of the control and data fields (`true` for a control
+/// field) of a record whose directory interleaves them. The usual
+/// order, in which the control fields precede the data fields, is
+/// represented by an empty list.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub(crate) struct FieldOrder(pub(crate) Vec<bool>);
+
+impl FieldOrder {
+    /// Creates the order from the kinds of the fields in directory
+    /// order.
+    pub(crate) fn new(kinds: Vec<bool>) -> Self {
+        if kinds.windows(2).all(|pair| pair[0] || !pair[1]) {
+            Self::default()
+        } else {
+            Self(kinds)
+        }
+    }
+
+    /// Appends a field. In the usual order, appended fields are placed
+    /// after the other fields of their kind.
+    fn push(&mut self, control: bool) {
+        if !self.0.is_empty() {
+            self.0.push(control);
+        }
+    }
+}
+
+/// A part of the original layout of a parsed record, which is
+/// discarded on the first mutation: the original bytes, which are
+/// retained if [ParseOptions::preserve_layout] is enabled, and the
+/// directory entries, which are checked by [Record::validate].
+///
+/// The original layout doesn't take part in comparisons and hashing,
+/// as it's redundant with the contents of the record.
+#[derive(Debug, Clone)]
+pub(crate) struct Original<T>(pub(crate) Option<T>);
+
+impl<T> Default for Original<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> PartialEq for Original<T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for Original<T> {}
+
+impl<T> core::hash::Hash for Original<T> {
+    fn hash<H: core::hash::Hasher>(&self, _: &mut H) {}
+}
+
+impl Record {
+    /// Creates a record from a byte slice.
+    ///
+    /// The record length declared in the leader must match the actual
+    /// length of the record; otherwise a
+    /// [`ParseRecordError::LengthMismatch`] is returned. The slice must
+    /// end with the record terminator: any data following it results
+    /// in a [`ParseRecordError::TrailingData`] error. Use
+    /// [Record::from_bytes_prefix] to parse a record at the start of a
+    /// longer slice.
+    ///
+    /// The fields of a record always hold two indicators and one-byte
+    /// subfield codes. A record whose leader declares another
+    /// indicator count or subfield code count is normalized to these
+    /// counts, i.e. its leader is updated as if the record had been
+    /// modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     )?;
+    ///     assert_eq!(record.leader().record_length(), 48);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_bytes(data: &[u8]) -> Result<Self, ParseRecordError> {
+        let (rest, record) = parse_record(data).finish()?;
+        check_trailing_data(data, rest)?;
+        Ok(record)
+    }
+
+    /// Creates a record from the start of a byte slice and returns it
+    /// together with the number of bytes it occupies, i.e. its
+    /// declared record length. Any data following the record is
+    /// ignored.
+    ///
+    /// If the data is shorter than the declared length, a
+    /// [`ParseRecordError::Truncated`] error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let data = b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d00048nam a2200037";
+    ///
+    ///     let (record, len) = Record::from_bytes_prefix(data)?;
+    ///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+    ///     assert_eq!(len, 48);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_bytes_prefix(
+        data: &[u8],
+    ) -> Result<(Self, usize), ParseRecordError> {
+        let record_len = match parse_record_len(data).finish() {
+            Ok((_, record_len)) => record_len as usize,
+            Err(_) if data.len() < 5 => {
+                return Err(ParseRecordError::Truncated)
+            }
+            Err(source) => {
+                return Err(ParseRecordError::Leader {
+                    source,
+                    position: 0,
+                })
+            }
+        };
+
+        let data = data
+            .get(..record_len)
+            .ok_or(ParseRecordError::Truncated)?;
+        Ok((Self::from_bytes(data)?, record_len))
+    }
+
+    /// Creates a record from a byte slice using the given options.
+    ///
+    /// In lenient mode, a record length mismatch, invalid indicators,
+    /// a missing record terminator and doubled field terminators
+    /// between the fields are tolerated; the issues are returned
+    /// alongside the record. Nonstandard delimiter bytes are supported
+    /// by the corresponding options, and line feeds in place of the
+    /// terminators by [ParseOptions::accept_lf_terminators]. Data
+    /// following the record terminator is an error
+    /// ([`ParseRecordError::TrailingData`]), unless the options are
+    /// lenient, in which case it's ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{ParseOptions, ParseWarning, Record};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let (record, warnings) = Record::from_bytes_with(
+    ///         b"00827nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///         &ParseOptions {
+    ///             lenient: true,
+    ///             ..Default::default()
+    ///         },
+    ///     )?;
+    ///
+    ///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+    ///     assert_eq!(
+    ///         warnings,
+    ///         vec![ParseWarning::LengthMismatch {
+    ///             declared: 827,
+    ///             actual: 48
+    ///         }]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn from_bytes_with(
+        data: &[u8],
+        options: &ParseOptions,
+    ) -> Result<(Self, Vec<ParseWarning>), ParseRecordError> {
+        let mut warnings = vec![];
+
+        let translated;
+        let data = match options.delimiter_table() {
+            Some(table) => {
+                translated = translate(data, &table);
+                &translated
+            }
+            None => data,
+        };
+
+        let restored =
+            if options.lenient && options.accept_lf_terminators {
+                restore_lf_terminators(data)
+            } else {
+                None
+            };
+        let data = match restored {
+            Some(ref restored) => {
+                warnings.push(ParseWarning::LineFeedTerminators);
+                restored
+            }
+            None => data,
+        };
+
+        let (rest, mut record) =
+            parse_record_with(data, options, &mut warnings).finish()?;
+        if !options.lenient {
+            check_trailing_data(data, rest)?;
+        }
+
+        // The restored bytes aren't the original ones.
+        if restored.is_some() {
+            record.original = Original::default();
+        }
+
+        Ok((record, warnings))
+    }
+
+    /// Converts the record into its ISO 2709 representation.
+    ///
+    /// The record length and the base address of data are recomputed
+    /// from the actual directory and field sizes; the values stored in
+    /// the leader are ignored. Lengths and starting positions which
+    /// don't fit into the directory entry, as well as a record length
+    /// above `99999`, corrupt the record; see [Record::try_to_bytes].
+    /// Since each data field is written with
+    /// two indicators and one-byte subfield codes, the indicator
+    /// count and the subfield code count are always written as `2`,
+    /// as they are normalized on parsing.
+    /// Values containing a delimiter byte are
+    /// written as is and corrupt the record; see
+    /// [Record::try_to_bytes].
+    ///
+    /// A record parsed with [ParseOptions::preserve_layout] is emitted
+    /// with its original bytes, as long as it hasn't been modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let data = b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d";
+    ///
+    ///     let record = Record::from_bytes(data)?;
+    ///     assert_eq!(record.to_bytes(), data);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        if let Some(ref data) = self.original.0 {
+            return data.clone();
+        }
+
+        let entry_map = &self.leader.entry_map;
+        let mut directory = vec![];
+        let mut body = vec![];
+
+        let mut write_entry =
+            |tag: [u8; 3], start: usize, body: &[u8]| {
+                let entry = DirectoryEntry {
+                    tag,
+                    length: (body.len() - start) as u32,
+                    start: start as u32,
+                };
+
+                entry.write_to(&mut directory, entry_map);
+            };
+
+        for field in self.all_fields() {
+            let start = body.len();
+            match field {
+                Field::Control(field) => field.write_to(&mut body),
+                Field::Data(field) => field.write_to(&mut body),
+            }
+            write_entry(*field.tag(), start, &body);
+        }
+
+        let base_address = LEADER_LEN + directory.len() + 1;
+        let record_len = base_address + body.len() + 1;
+
+        let mut leader = self.leader.to_bytes();
+        write_record_len(&mut leader[0..5], record_len as u32);
+        write_number(&mut leader[12..17], base_address as u32);
+        leader[10] = b'2';
+        leader[11] = b'2';
+
+        let mut data = Vec::with_capacity(record_len);
+        data.extend_from_slice(&leader);
+        data.extend_from_slice(&directory);
+        data.push(FIELD_TERMINATOR);
+        data.extend_from_slice(&body);
+        data.push(RECORD_TERMINATOR);
+        data
+    }
+
+    /// Converts the record into its ISO 2709 representation, failing
+    /// if a value contains a reserved delimiter byte (the subfield
+    /// delimiter, the field terminator or the record terminator), or if
+    /// the record can't be represented in ISO 2709.
+    ///
+    /// The [SerializeError::EmbeddedDelimiter] error names the field
+    /// and, for data fields, the subfield code. Such bytes can be
+    /// removed with [Record::strip_delimiters]. A field whose length or
+    /// starting position exceeds the digits of the entry map (`9999`
+    /// and `99999` in MARC 21) fails with
+    /// [SerializeError::FieldTooLong]
+    /// or [SerializeError::StartTooLarge], and a record exceeding
+    /// `99999` bytes with [SerializeError::RecordTooLong]. Oversized
+    /// records can be divided with [Record::split_if_oversized].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, SerializeError, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title\x1e")],
+    ///         )
+    ///         .build();
+    ///
+    ///     assert_eq!(
+    ///         record.try_to_bytes(),
+    ///         Err(SerializeError::EmbeddedDelimiter {
+    ///             tag: *b"245",
+    ///             code: Some(b'a'),
+    ///         })
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn try_to_bytes(&self) -> Result<Vec<u8>, SerializeError> {
+        for field in self.control_fields.iter() {
+            if field.data.iter().copied().any(is_delimiter) {
+                return Err(SerializeError::EmbeddedDelimiter {
+                    tag: field.tag,
+                    code: None,
+                });
+            }
+        }
+
+        for field in self.data_fields.iter() {
+            for subfield in field.subfields.iter() {
+                if subfield.value.iter().copied().any(is_delimiter) {
+                    return Err(SerializeError::EmbeddedDelimiter {
+                        tag: field.tag,
+                        code: Some(subfield.code),
+                    });
+                }
+            }
+        }
+
+        let entry_map = &self.leader.entry_map;
+        let max_length = max_number(entry_map.length_of_field_portion);
+        let max_start =
+            max_number(entry_map.starting_character_position_portion);
+
+        let mut start = 0;
+        for field in self.all_fields() {
+            let tag = *field.tag();
+            let length = match field {
+                Field::Control(field) => field.encoded_len(),
+                Field::Data(field) => field.encoded_len(),
+            };
+
+            if length > max_length {
+                return Err(SerializeError::FieldTooLong {
+                    tag,
+                    length,
+                });
+            }
+            if start > max_start {
+                return Err(SerializeError::StartTooLarge {
+                    tag,
+                    start,
+                });
+            }
+            start += length;
+        }
+
+        let length = self.layout().1;
+        if length > MAX_RECORD_LEN as usize {
+            return Err(SerializeError::RecordTooLong { length });
+        }
+
+        Ok(self.to_bytes())
+    }
+
+    /// Removes all reserved delimiter bytes from the values of the
+    /// control fields and subfields, and returns the number of bytes
+    /// removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Ti\x1ftle\x1e")],
+    ///         )
+    ///         .build();
+    ///
+    ///     assert_eq!(record.strip_delimiters(), 2);
+    ///     assert_eq!(
+    ///         record.first_subfield_value(b"245", b'a'),
+    ///         Some(&b"Title"[..])
+    ///     );
+    ///     assert!(record.try_to_bytes().is_ok());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn strip_delimiters(&mut self) -> usize {
+        let mut removed = 0;
+        let mut strip = |value: &mut Vec<u8>| {
+            let len = value.len();
+            value.retain(|b| !is_delimiter(*b));
+            removed += len - value.len();
+        };
+
+        for field in self.control_fields.iter_mut() {
+            strip(&mut field.data);
+        }
+
+        for field in self.data_fields.iter_mut() {
+            for subfield in field.subfields.iter_mut() {
+                strip(&mut subfield.value);
+            }
+        }
+
+        self.update_leader();
+        removed
+    }
+
+    /// Replaces the placeholders `#`, `|` and `_`, which are used by
+    /// MARCMaker and some editors to represent blank indicators, by
+    /// a blank (`0x20`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"650",
+    ///             b'#',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Arithmetic")],
+    ///         )
+    ///         .build();
+    ///
+    ///     record.normalize_indicators();
+    ///     assert_eq!(record.fields(b"650").next().unwrap().ind1(), b' ');
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn normalize_indicators(&mut self) {
+        for field in self.data_fields.iter_mut() {
+            for ind in [&mut field.ind1, &mut field.ind2] {
+                if matches!(*ind, b'#' | b'|' | b'_') {
+                    *ind = b' ';
+                }
+            }
+        }
+
+        self.update_leader();
+    }
+
+    /// Converts the record into its ISO 2709 representation, using
+    /// the delimiter bytes of the given options.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{ParseOptions, Record};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let options = ParseOptions {
+    ///         subfield_delimiter: b'$',
+    ///         field_terminator: b'#',
+    ///         record_terminator: b'\n',
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let data = b"00048nam a2200037 c 4500001001000000#\
+    ///         123456789#\n";
+    ///
+    ///     let (record, _) = Record::from_bytes_with(data, &options)?;
+    ///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+    ///     assert_eq!(record.to_bytes_with(&options), data);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn to_bytes_with(&self, options: &ParseOptions) -> Vec<u8> {
+        let data = self.to_bytes();
+
+        match options.delimiter_table() {
+            Some(table) => {
+                let mut inverse = [0; 256];
+                for (from, to) in table.iter().enumerate() {
+                    inverse[*to as usize] = from as u8;
+                }
+
+                translate(&data, &inverse)
+            }
+            None => data,
+        }
+    }
+
+    /// Returns the leader of the record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Record, RecordStatus};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     )?;
+    ///     assert_eq!(record.leader().record_status(), RecordStatus::New);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn leader(&self) -> &Leader {
+        &self.leader
+    }
+
+    /// Returns a mutable reference to the leader of the record.
+    ///
+    /// The record is considered modified, i.e. its original bytes and
+    /// directory are discarded and the leader is updated from the
+    /// fields. The record length and the base address of data are
+    /// recomputed on serialization as well, see [Record::to_bytes].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Record, RecordStatus};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     )?;
+    ///
+    ///     record
+    ///         .leader_mut()
+    ///         .set_record_status(RecordStatus::Deleted)?;
+    ///     assert_eq!(&record.to_bytes()[5..6], b"d");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn leader_mut(&mut self) -> &mut Leader {
+        self.update_leader();
+        &mut self.leader
+    }
+
+    /// Returns `true` if the record exceeds the maximum length of
+    /// 99999 bytes, such that the leader declares a record length of
+    /// `00000`. Such records are only accepted in lenient mode.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{ParseOptions, Record, RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut builder = RecordBuilder::new();
+    ///     for _ in 0..17 {
+    ///         let subfield = Subfield::new(b'a', "x".repeat(6000));
+    ///         builder =
+    ///             builder.data_field(b"500", b' ', b' ', [subfield]);
+    ///     }
+    ///
+    ///     let data = builder.build().to_bytes();
+    ///     assert_eq!(&data[0..5], b"00000");
+    ///
+    ///     let options = ParseOptions {
+    ///         lenient: true,
+    ///         ..Default::default()
+    ///     };
+    ///     let (record, _) = Record::from_bytes_with(&data, &options)?;
+    ///     assert!(record.length_overflowed());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn length_overflowed(&self) -> bool {
+        self.length_overflowed
+    }
+
+    /// Returns `false` if the record was parsed from input without a
+    /// record terminator, which is only accepted in lenient mode.
+    ///
+    /// [Record::to_bytes] always emits the terminators, regardless of
+    /// this flag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{ParseOptions, Record};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let options = ParseOptions {
+    ///         lenient: true,
+    ///         ..Default::default()
+    ///     };
+    ///     let (record, _) = Record::from_bytes_with(
+    ///         b"00047nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e",
+    ///         &options,
+    ///     )?;
+    ///
+    ///     assert!(!record.well_terminated());
+    ///     assert_eq!(record.to_bytes().last(), Some(&0x1d));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn well_terminated(&self) -> bool {
+        !self.missing_terminator
+    }
+
+    /// Returns `true` if the record status of the leader is deleted
+    /// (`d`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048dam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     )?;
+    ///     assert!(record.is_deleted());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn is_deleted(&self) -> bool {
+        self.leader.record_status() == RecordStatus::Deleted
+    }
+
+    /// Returns the control number of the record, i.e. the data of the
+    /// first `001` field, or `None` if the record has no such field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     )?;
+    ///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn control_number(&self) -> Option<&[u8]> {
+        self.control_fields
+            .iter()
+            .find(|field| &field.tag == b"001")
+            .map(ControlField::data)
+    }
+
+    /// Returns an iterator over all data fields with the given tag, in
+    /// directory order. An `X` in the tag matches any character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500245001000000\x1e\
+    ///         00\x1faTitle\x1e\x1d",
+    ///     )?;
+    ///     assert_eq!(record.fields(b"245").count(), 1);
+    ///     assert_eq!(record.fields(b"2XX").count(), 1);
+    ///     assert_eq!(record.fields(b"650").count(), 0);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn fields<'a>(
+        &'a self,
+        tag: impl AsRef<[u8]> + 'a,
+    ) -> impl Iterator<Item = &'a DataField> + 'a {
+        self.data_fields
+            .iter()
+            .filter(move |field| tag_matches(tag.as_ref(), &field.tag))
+    }
+
+    /// Returns an iterator over all control fields, in directory
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     )?;
+    ///     assert_eq!(record.control_fields().count(), 1);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn control_fields(
+        &self,
+    ) -> impl Iterator<Item = &ControlField> + '_ {
+        self.control_fields.iter()
+    }
+
+    /// Returns an iterator over all data fields, in directory order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500245001000000\x1e\
+    ///         00\x1faTitle\x1e\x1d",
+    ///     )?;
+    ///     assert_eq!(record.data_fields().count(), 1);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn data_fields(&self) -> impl Iterator<Item = &DataField> + '_ {
+        self.data_fields.iter()
+    }
+
+    /// Returns an iterator over all (control and data) fields, in
+    /// directory order.
+    ///
+    /// The order is the one used by [Record::to_bytes]. For a parsed
+    /// record it's the order of the directory entries, even if data
+    /// fields precede control fields; otherwise the control fields
+    /// precede the data fields. Fields added later are appended after
+    /// the other fields, and [Record::sort_fields] restores the usual
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+    ///         123456789\x1e00\x1faTitle\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let tags: Vec<_> =
+    ///         record.all_fields().map(|field| field.tag()).collect();
+    ///     assert_eq!(tags, [b"001", b"245"]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn all_fields(&self) -> Fields<'_> {
+        Fields::new(self)
+    }
+
+    /// Returns the first (control or data) field with the given tag,
+    /// in directory order, or `None` if there is no such field. An `X`
+    /// in the tag matches any character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Field, Record};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+    ///         123456789\x1e00\x1faTitle\x1e\x1d",
+    ///     )?;
+    ///
+    ///     assert!(matches!(record.get(b"001"), Some(Field::Control(_))));
+    ///     assert!(matches!(record.get(b"245"), Some(Field::Data(_))));
+    ///     assert!(record.get(b"650").is_none());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get(&self, tag: impl AsRef<[u8]>) -> Option<Field<'_>> {
+        self.all_fields()
+            .find(|field| tag_matches(tag.as_ref(), field.tag()))
+    }
+
+    /// Returns the value of the first subfield with the given code of
+    /// the first data field with the given tag.
+    ///
+    /// Only the first field with the tag is considered, even if it
+    /// has no such subfield. An `X` in the tag matches any character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500245001000000\x1e\
+    ///         00\x1faTitle\x1e\x1d",
+    ///     )?;
+    ///
+    ///     assert_eq!(
+    ///         record.first_subfield_value(b"245", b'a'),
+    ///         Some(&b"Title"[..])
+    ///     );
+    ///     assert_eq!(record.first_subfield_value(b"245", b'b'), None);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn first_subfield_value(
+        &self,
+        tag: impl AsRef<[u8]>,
+        code: u8,
+    ) -> Option<&[u8]> {
+        self.data_fields
+            .iter()
+            .find(|field| tag_matches(tag.as_ref(), &field.tag))
+            .and_then(|field| field.subfield(code))
+            .map(Subfield::value)
+    }
+
+    /// Returns an iterator over the values of all subfields with the
+    /// given code of all data fields with the given tag, in directory
+    /// and field order. An `X` in the tag matches any character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"650",
+    ///             b' ',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Arithmetic")],
+    ///         )
+    ///         .data_field(
+    ///             b"650",
+    ///             b' ',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Poetry")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let values: Vec<_> =
+    ///         record.subfield_values(b"650", b'a').collect();
+    ///     assert_eq!(values, [&b"Arithmetic"[..], &b"Poetry"[..]]);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn subfield_values<'a>(
+        &'a self,
+        tag: impl AsRef<[u8]> + 'a,
+        code: u8,
+    ) -> impl Iterator<Item = &'a [u8]> + 'a {
+        self.fields(tag)
+            .flat_map(move |field| field.subfields_by_code(code))
+            .map(Subfield::value)
+    }
+
+    /// Returns an iterator over all control fields with the given tag,
+    /// in directory order. An `X` in the tag matches any character.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00048nam a2200037 c 4500001001000000\x1e\
+    ///         123456789\x1e\x1d",
+    ///     )?;
+    ///     assert_eq!(record.control_fields_by_tag(b"001").count(), 1);
+    ///     assert_eq!(record.control_fields_by_tag(b"008").count(), 0);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn control_fields_by_tag<'a>(
+        &'a self,
+        tag: impl AsRef<[u8]> + 'a,
+    ) -> impl Iterator<Item = &'a ControlField> + 'a {
+        self.control_fields
+            .iter()
+            .filter(move |field| tag_matches(tag.as_ref(), &field.tag))
+    }
+
+    /// Returns `true` if the record has a field with the given tag
+    /// containing a subfield with the given code and value.
+    ///
+    /// An `X` in the tag matches any character, and `None` matches
+    /// any subfield code or value. A control field matches if no
+    /// subfield code is given and its data equals the value (if any).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00059nam a2200037 c 4500650002100000\x1e\
+    ///         \x207\x1faArithmetic\x1f2fast\x1e\x1d",
+    ///     )?;
+    ///
+    ///     assert!(record.matches(b"650", Some(b'2'), Some(b"fast")));
+    ///     assert!(!record.matches(b"650", Some(b'2'), Some(b"gnd")));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn matches(
+        &self,
+        tag: impl AsRef<[u8]>,
+        code: Option<u8>,
+        value: Option<&[u8]>,
+    ) -> bool {
+        let tag = tag.as_ref();
+        let value_matches =
+            |data: &[u8]| value.is_none_or(|v| v == data);
+
+        if code.is_none()
+            && self.control_fields.iter().any(|field| {
+                tag_matches(tag, &field.tag)
+                    && value_matches(&field.data)
+            })
+        {
+            return true;
+        }
+
+        self.data_fields
+            .iter()
+            .filter(|field| tag_matches(tag, &field.tag))
+            .any(|field| {
+                (code.is_none() && value.is_none())
+                    || field.subfields.iter().any(|subfield| {
+                        code.is_none_or(|c| c == subfield.code)
+                            && value_matches(&subfield.value)
+                    })
+            })
+    }
+
+    /// Returns the number of fields (control and data fields) of the
+    /// record.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+    ///         123456789\x1e00\x1faTitle\x1e\x1d",
+    ///     )?;
+    ///     assert_eq!(record.field_count(), 2);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn field_count(&self) -> usize {
+        self.control_fields.len() + self.data_fields.len()
+    }
+
+    /// Returns the total number of subfields of all data fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .control_field(b"001", "123456789")
+    ///         .data_field(
+    ///             b"245",
+    ///             b'1',
+    ///             b'0',
+    ///             [
+    ///                 Subfield::new(b'a', "Title"),
+    ///                 Subfield::new(b'c', "Me"),
+    ///             ],
+    ///         )
+    ///         .build();
+    ///     assert_eq!(record.subfield_count(), 2);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn subfield_count(&self) -> usize {
+        self.data_fields
+            .iter()
+            .map(|field| field.subfields.len())
+            .sum()
+    }
+
+    /// Returns the length of the ISO 2709 representation of the
+    /// record, i.e. the length of [Record::to_bytes], without
+    /// serializing it.
+    ///
+    /// The length is computed from the fields, such that it can be
+    /// checked against the maximum record length of `99999` before
+    /// the record is written.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .control_field(b"001", "123456789")
+    ///         .data_field(
+    ///             b"245",
+    ///             b'1',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .build();
+    ///     assert_eq!(record.bytes_len(), 70);
+    ///     assert_eq!(
+    ///         record.bytes_len() as usize,
+    ///         record.to_bytes().len()
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn bytes_len(&self) -> u32 {
+        match self.original.0 {
+            Some(ref data) => data.len() as u32,
+            None => self.layout().1 as u32,
+        }
+    }
+
+    /// Returns the number of occurrences of each tag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Record;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = Record::from_bytes(
+    ///         b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+    ///         123456789\x1e00\x1faTitle\x1e\x1d",
+    ///     )?;
+    ///
+    ///     let histogram = record.tag_histogram();
+    ///     assert_eq!(histogram.get(b"245"), Some(&1));
+    ///     assert_eq!(histogram.get(b"650"), None);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn tag_histogram(&self) -> BTreeMap<[u8; 3], usize> {
+        let control_tags = self.control_fields.iter().map(|f| f.tag);
+        let data_tags = self.data_fields.iter().map(|f| f.tag);
+
+        control_tags.chain(data_tags).fold(
+            BTreeMap::new(),
+            |mut histogram, tag| {
+                *histogram.entry(tag).or_insert(0) += 1;
+                histogram
+            },
+        )
+    }
+
+    /// Appends a data field to the record.
+    ///
+    /// The record length and the base address of data of the leader
+    /// are updated accordingly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let other = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"650",
+    ///             b' ',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Topic")],
+    ///         )
+    ///         .build();
+    ///     record.add_field(other.fields(b"650").next().unwrap().clone());
+    ///
+    ///     assert_eq!(record.fields(b"650").count(), 1);
+    ///     assert_eq!(record.leader().record_length(), 70);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn add_field(&mut self, field: DataField) {
+        self.data_fields.push(field);
+        self.field_order.push(false);
+        self.update_leader();
+    }
+
+    /// Removes all fields (control and data fields) whose tag matches
+    /// `tag` and returns the number of removed fields. In `tag`, an
+    /// `X` matches any character, e.g. `9XX` matches all local
+    /// fields.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .data_field(
+    ///             b"910",
+    ///             b' ',
+    ///             b' ',
+    ///             [Subfield::new(b'a', "Local")],
+    ///         )
+    ///         .data_field(
+    ///             b"999",
+    ///             b' ',
+    ///             b' ',
+    ///             [Subfield::new(b'a', "Local")],
+    ///         )
+    ///         .build();
+    ///
+    ///     assert_eq!(record.remove_fields(b"9XX"), 2);
+    ///     assert_eq!(record.fields(b"245").count(), 1);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn remove_fields(&mut self, tag: impl AsRef<[u8]>) -> usize {
+        let len = self.field_count();
+        let tag = tag.as_ref();
+        self.retain_fields(|field| !tag_matches(tag, field.tag()));
+        len - self.field_count()
+    }
+
+    /// Removes all data fields without any subfields or whose
+    /// subfields all have empty values, and returns the number of
+    /// removed fields. If `control_fields` is set, control fields
+    /// with empty data are removed as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = RecordBuilder::new()
+    ///         .control_field(b"001", "")
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .data_field(b"650", b' ', b'0', [Subfield::new(b'a', "")])
+    ///         .build();
+    ///
+    ///     assert_eq!(record.remove_empty_fields(false), 1);
+    ///     assert_eq!(record.field_count(), 2);
+    ///     assert_eq!(record.remove_empty_fields(true), 1);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn remove_empty_fields(
+        &mut self,
+        control_fields: bool,
+    ) -> usize {
+        let len = self.field_count();
+        self.retain_fields(|field| match field {
+            Field::Control(field) => {
+                !control_fields || !field.data.is_empty()
+            }
+            Field::Data(field) => field
+                .subfields
+                .iter()
+                .any(|subfield| !subfield.value.is_empty()),
+        });
+        len - self.field_count()
+    }
+
+    /// Retains only the (control and data) fields for which the
+    /// predicate returns `true`, preserving their order, and updates
+    /// the leader accordingly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = RecordBuilder::new()
+    ///         .control_field(b"001", "123456789")
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .data_field(
+    ///             b"910",
+    ///             b' ',
+    ///             b' ',
+    ///             [Subfield::new(b'a', "Local")],
+    ///         )
+    ///         .build();
+    ///
+    ///     record.retain_fields(|field| field.tag()[0] != b'9');
+    ///     assert_eq!(record.field_count(), 2);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn retain_fields(&mut self, mut f: impl FnMut(&Field) -> bool) {
+        let mut kinds = vec![];
+        let mut keep_control = vec![];
+        let mut keep_data = vec![];
+
+        for field in self.all_fields() {
+            let keep = f(&field);
+            if keep {
+                kinds.push(field.is_control_field());
+            }
+
+            match field {
+                Field::Control(_) => keep_control.push(keep),
+                Field::Data(_) => keep_data.push(keep),
+            }
+        }
+
+        let mut keep = keep_control.into_iter();
+        self.control_fields.retain(|_| keep.next().unwrap_or(true));
+        let mut keep = keep_data.into_iter();
+        self.data_fields.retain(|_| keep.next().unwrap_or(true));
+
+        self.field_order = FieldOrder::new(kinds);
+        self.update_leader();
+    }
+
+    /// Returns a copy of the record containing only the fields whose
+    /// tag matches one of the given tags, where an `X` matches any
+    /// character. The order of the fields is preserved and the
+    /// leader is updated accordingly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .control_field(b"001", "123456789")
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .data_field(
+    ///             b"500",
+    ///             b' ',
+    ///             b' ',
+    ///             [Subfield::new(b'a', "Note")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let record = record.extract(&[b"001", b"245"]);
+    ///     assert_eq!(record.field_count(), 2);
+    ///     assert_eq!(record.fields(b"500").count(), 0);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn extract<T: AsRef<[u8]>>(&self, tags: &[T]) -> Record {
+        let keep = |tag: &[u8; 3]| {
+            tags.iter()
+                .any(|pattern| tag_matches(pattern.as_ref(), tag))
+        };
+
+        let mut record = Record {
+            length_overflowed: false,
+            missing_terminator: false,
+            ..self.clone()
+        };
+
+        record.retain_fields(|field| keep(field.tag()));
+        record
+    }
+
+    /// Returns a copy of the record without exact duplicates of data
+    /// fields, i.e. fields with the same tag, indicators and
+    /// subfields as an earlier field. The first occurrence of each
+    /// field keeps its position and the leader is updated accordingly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"650",
+    ///             b' ',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Poetry")],
+    ///         )
+    ///         .data_field(
+    ///             b"650",
+    ///             b' ',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Poetry")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let record = record.deduplicated();
+    ///     assert_eq!(record.fields(b"650").count(), 1);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn deduplicated(&self) -> Record {
+        let mut record = Record {
+            length_overflowed: false,
+            missing_terminator: false,
+            ..self.clone()
+        };
+
+        let mut data_fields: Vec<DataField> = vec![];
+        record.retain_fields(|field| match field {
+            Field::Control(_) => true,
+            Field::Data(field) if data_fields.contains(field) => false,
+            Field::Data(field) => {
+                data_fields.push((*field).clone());
+                true
+            }
+        });
+        record
+    }
+
+    /// Appends the fields of `other` to the record, keeping the leader
+    /// of the record. Fields whose tag matches one of `skip_tags`
+    /// (where an `X` matches any character) are skipped, as well as
+    /// control fields whose tag is already present in the record.
+    /// Afterwards the leader is updated accordingly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = RecordBuilder::new()
+    ///         .control_field(b"001", "123456789")
+    ///         .build();
+    ///
+    ///     let other = RecordBuilder::new()
+    ///         .control_field(b"001", "987654321")
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .build();
+    ///
+    ///     record.merge(&other, &[b"5XX"]);
+    ///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+    ///     assert_eq!(record.field_count(), 2);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn merge<T: AsRef<[u8]>>(
+        &mut self,
+        other: &Record,
+        skip_tags: &[T],
+    ) {
+        let skip = |tag: &[u8; 3]| {
+            skip_tags
+                .iter()
+                .any(|pattern| tag_matches(pattern.as_ref(), tag))
+        };
+
+        let control_fields: Vec<ControlField> = other
+            .control_fields
+            .iter()
+            .filter(|field| {
+                !skip(&field.tag)
+                    && !self
+                        .control_fields
+                        .iter()
+                        .any(|f| f.tag == field.tag)
+            })
+            .cloned()
+            .collect();
+
+        let data_fields: Vec<DataField> = other
+            .data_fields
+            .iter()
+            .filter(|field| !skip(&field.tag))
+            .cloned()
+            .collect();
+
+        for _ in 0..control_fields.len() {
+            self.field_order.push(true);
+        }
+        for _ in 0..data_fields.len() {
+            self.field_order.push(false);
+        }
+
+        self.control_fields.extend(control_fields);
+        self.data_fields.extend(data_fields);
+        self.update_leader();
+    }
+
+    /// Replaces the data field at position `index` (among all data
+    /// fields) and returns the replaced field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let other = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"245",
+    ///             b'1',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Other")],
+    ///         )
+    ///         .build();
+    ///     let field = other.fields(b"245").next().unwrap().clone();
+    ///
+    ///     let old = record.replace_field(0, field);
+    ///     assert_eq!(old.subfields()[0].value(), b"Title");
+    ///     assert_eq!(record.fields(b"245").next().unwrap().ind1(), b'1');
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn replace_field(
+        &mut self,
+        index: usize,
+        field: DataField,
+    ) -> DataField {
+        let old =
+            core::mem::replace(&mut self.data_fields[index], field);
+        self.update_leader();
+        old
+    }
+
+    /// Appends a subfield to the first data field with the given tag,
+    /// e.g. to add the source of a subject heading (`$2`). An `X` in
+    /// the tag matches any character.
+    ///
+    /// Fails with [DataFieldError::MissingField] if the record has no
+    /// data field with the tag, and with
+    /// [DataFieldError::InvalidSubfieldCode] if the code is neither a
+    /// lowercase letter nor a digit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"650",
+    ///             b' ',
+    ///             b'7',
+    ///             [Subfield::new(b'a', "Arithmetic")],
+    ///         )
+    ///         .build();
+    ///
+    ///     record.append_subfield(b"650", b'2', "fast")?;
+    ///     assert_eq!(
+    ///         record.first_subfield_value(b"650", b'2'),
+    ///         Some(&b"fast"[..])
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn append_subfield(
+        &mut self,
+        tag: impl AsRef<[u8]>,
+        code: u8,
+        value: impl Into<Vec<u8>>,
+    ) -> Result<(), DataFieldError> {
+        if !is_valid_subfield_code(code) {
+            return Err(DataFieldError::InvalidSubfieldCode(code));
+        }
+
+        let field = self
+            .data_fields
+            .iter_mut()
+            .find(|field| tag_matches(tag.as_ref(), &field.tag))
+            .ok_or(DataFieldError::MissingField)?;
+
+        field.subfields.push(Subfield::new(code, value));
+        self.update_leader();
+        Ok(())
+    }
+
+    /// Applies `f` in place to the value of every subfield of the data
+    /// fields with the given tag, e.g. to normalize the whitespace of
+    /// all subject headings (`6XX`), where an `X` in the tag matches
+    /// any character. The leader is updated afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"650",
+    ///             b' ',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Arithmetic  ")],
+    ///         )
+    ///         .build();
+    ///
+    ///     record.transcode_field(b"650", |value| {
+    ///         value.truncate(value.trim_ascii_end().len())
+    ///     });
+    ///     assert_eq!(
+    ///         record.first_subfield_value(b"650", b'a'),
+    ///         Some(&b"Arithmetic"[..])
+    ///     );
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn transcode_field(
+        &mut self,
+        tag: impl AsRef<[u8]>,
+        mut f: impl FnMut(&mut Vec<u8>),
+    ) {
+        let tag = tag.as_ref();
+        self.data_fields
+            .iter_mut()
+            .filter(|field| tag_matches(tag, &field.tag))
+            .flat_map(|field| field.subfields.iter_mut())
+            .for_each(|subfield| f(&mut subfield.value));
+        self.update_leader();
+    }
+
+    /// Sorts the fields in ascending order of their tags. The sort is
+    /// stable, so fields with the same tag keep their relative order.
+    /// Control fields always precede the data fields, as their tags
+    /// are lower.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let mut record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"500",
+    ///             b' ',
+    ///             b' ',
+    ///             [Subfield::new(b'a', "Note")],
+    ///         )
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .build();
+    ///
+    ///     record.sort_fields();
+    ///     let tags: Vec<_> =
+    ///         record.data_fields().map(|f| f.tag()).collect();
+    ///     assert_eq!(tags, [b"245", b"500"]);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn sort_fields(&mut self) {
+        self.control_fields.sort_by_key(|field| field.tag);
+        self.data_fields.sort_by_key(|field| field.tag);
+        self.field_order = FieldOrder::default();
+        self.update_leader();
+    }
+
+    /// Recomputes the record length and the base address of data of
+    /// the leader from the current fields.
+    pub(crate) fn update_leader(&mut self) {
+        self.original = Original::default();
+        self.directory = Original::default();
+
+        // The fields hold two indicators and one-byte subfield codes.
+        self.leader.set_standard_counts();
+
+        let (base_address, record_len) = self.layout();
+        self.leader.set_base_address(base_address as u32);
+        self.leader.set_record_len(record_len as u32);
+    }
+
+    /// Computes the base address of data and the record length of the
+    /// ISO 2709 representation from the current fields.
+    fn layout(&self) -> (usize, usize) {
+        let entry_len = self.leader.entry_map.entry_len();
+        let num_fields =
+            self.control_fields.len() + self.data_fields.len();
+        let base_address = LEADER_LEN + num_fields * entry_len + 1;
+
+        let control_len: usize = self
+            .control_fields
+            .iter()
+            .map(ControlField::encoded_len)
+            .sum();
+        let data_len: usize =
+            self.data_fields.iter().map(DataField::encoded_len).sum();
+
+        (base_address, base_address + control_len + data_len + 1)
+    }
+}
+
+/// A builder to construct a [Record] field by field.
+///
+/// The fields are emitted in the order they were added, unless
+/// sorting by tag is enabled. The record length and the base address
+/// of data of the leader are computed by [RecordBuilder::build].
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{RecordBuilder, Subfield};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let record = RecordBuilder::new()
+///         .control_field(b"001", "123456789")
+///         .data_field(
+///             b"245",
+///             b'0',
+///             b'0',
+///             [Subfield::new(b'a', "Title")],
+///         )
+///         .build();
+///
+///     assert_eq!(record.leader().record_length(), 70);
+///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RecordBuilder {
+    record: Record,
+    sort_by_tag: bool,
+}
+
+impl Default for RecordBuilder {
+    fn default() -> Self {
+        Self {
+            record: Record {
+                leader: Leader::default(),
+                control_fields: vec![],
+                data_fields: vec![],
+                length_overflowed: false,
+                missing_terminator: false,
+                original: Original::default(),
+                directory: Original::default(),
+                field_order: FieldOrder::default(),
+            },
+            sort_by_tag: false,
+        }
+    }
+}
+
+impl RecordBuilder {
+    /// Creates a new record builder with a default leader (see
+    /// [LeaderBuilder]) and no fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the leader of the record. The record length and the base
+    /// address of data are recomputed on [RecordBuilder::build].
+    pub fn leader(mut self, leader: Leader) -> Self {
+        self.record.leader = leader;
+        self
+    }
+
+    /// Adds a control field.
+    pub fn control_field(
+        mut self,
+        tag: impl Borrow<[u8; 3]>,
+        data: impl Into<Vec<u8>>,
+    ) -> Self {
+        self.record
+            .control_fields
+            .push(ControlField::new(tag, data));
+        self
+    }
+
+    /// Adds a data field.
+    pub fn data_field(
+        mut self,
+        tag: impl Borrow<[u8; 3]>,
+        ind1: u8,
+        ind2: u8,
+        subfields: impl IntoIterator<Item = Subfield>,
+    ) -> Self {
+        self.record.data_fields.push(DataField {
+            tag: *tag.borrow(),
+            ind1,
+            ind2,
+            subfields: subfields.into_iter().collect(),
+        });
+        self
+    }
+
+    /// Sets whether the fields are sorted by tag. Fields with the same
+    /// tag keep the order in which they were added.
+    pub fn sort_by_tag(mut self, value: bool) -> Self {
+        self.sort_by_tag = value;
+        self
+    }
+
+    /// Consumes the builder and returns the constructed record.
+    pub fn build(mut self) -> Record {
+        if self.sort_by_tag {
+            self.record.control_fields.sort_by_key(|field| field.tag);
+            self.record.data_fields.sort_by_key(|field| field.tag);
+        }
+
+        self.record.update_leader();
+        self.record
+    }
+}
+
+/// Returns `true` if the tag matches the pattern, where an `X` in the
+/// pattern matches any character.
+#[inline]
+pub(crate) fn tag_matches(pattern: &[u8], tag: &[u8; 3]) -> bool {
+    pattern.len() == tag.len()
+        && pattern
+            .iter()
+            .zip(tag.iter())
+            .all(|(p, t)| p == t || *p == b'X')
+}
+
+/// Returns `true` if the tag belongs to a control field (`00X`).
+#[inline]
+pub(crate) fn is_control_tag(tag: &[u8]) -> bool {
+    tag.starts_with(b"00")
+}
+
+/// Checks the subfield values of a data field for invalid UTF-8,
+/// which is either replaced (lossy) or reported (strict). Returns
+/// `true` if a value was replaced.
+fn validate_utf8(
+    field: &mut DataField,
+    mode: Utf8Validation,
+    position: usize,
+) -> Result<bool, ParseRecordError> {
+    let mut modified = false;
+
+    for subfield in field.subfields.iter_mut() {
+        if core::str::from_utf8(&subfield.value).is_ok() {
+            continue;
+        }
+
+        match mode {
+            Utf8Validation::Raw => (),
+            Utf8Validation::Lossy => {
+                subfield.value =
+                    String::from_utf8_lossy(&subfield.value)
+                        .into_owned()
+                        .into_bytes();
+                modified = true;
+            }
+            Utf8Validation::Strict => {
+                return Err(ParseRecordError::InvalidUtf8 {
+                    tag: field.tag,
+                    code: subfield.code,
+                    position,
+                });
+            }
+        }
+    }
+
+    Ok(modified)
+}
+
+/// Returns `true` if the byte is one of the reserved delimiters.
+#[inline]
+fn is_delimiter(b: u8) -> bool {
+    matches!(
+        b,
+        SUBFIELD_DELIMITER | FIELD_TERMINATOR | RECORD_TERMINATOR
+    )
+}
+
+/// Returns `true` if every directory entry refers to a slice of the
+/// data area which is terminated by its only field terminator.
+fn fits_directory(entries: &[DirectoryEntry], body: &[u8]) -> bool {
+    entries.iter().all(|entry| {
+        let start = entry.start as usize;
+        match body
+            .get(start..start + entry.length as usize)
+            .and_then(<[u8]>::split_last)
+        {
+            Some((&FIELD_TERMINATOR, data)) => {
+                !data.contains(&FIELD_TERMINATOR)
+            }
+            _ => false,
+        }
+    })
+}
+
+/// Collapses runs of field terminators in the data area (up to the
+/// record terminator) and re-derives the starting positions and
+/// lengths of the directory entries from the remaining fields, which
+/// are assigned in the order of the declared starting positions.
+///
+/// Returns the repaired data area and the number of removed bytes, or
+/// `None` if the data area has no doubled field terminators or the
+/// number of fields doesn't match the directory.
+fn repair_doubled_terminators(
+    entries: &mut [DirectoryEntry],
+    body: &[u8],
+) -> Option<(Vec<u8>, usize)> {
+    let end = body
+        .iter()
+        .position(|b| *b == RECORD_TERMINATOR)
+        .unwrap_or(body.len());
+    if !body[..end]
+        .windows(2)
+        .any(|w| w == [FIELD_TERMINATOR, FIELD_TERMINATOR])
+    {
+        return None;
+    }
+
+    let mut data = Vec::with_capacity(body.len());
+    for b in body[..end].iter().copied() {
+        if b != FIELD_TERMINATOR || data.last() != Some(&b) {
+            data.push(b);
+        }
+    }
+
+    let mut fields = vec![];
+    let mut start = 0;
+    for (pos, b) in data.iter().enumerate() {
+        if *b == FIELD_TERMINATOR {
+            fields.push((start, pos + 1 - start));
+            start = pos + 1;
+        }
+    }
+
+    if fields.len() != entries.len() || start != data.len() {
+        return None;
+    }
+
+    let mut order: Vec<usize> = (0..entries.len()).collect();
+    order.sort_by_key(|idx| entries[*idx].start);
+    for (idx, (start, length)) in order.into_iter().zip(fields) {
+        entries[idx].start = start as u32;
+        entries[idx].length = length as u32;
+    }
+
+    let removed = end - data.len();
+    data.extend_from_slice(&body[end..]);
+    Some((data, removed))
+}
+
+/// Maps every byte of `data` through the translation table.
+fn translate(data: &[u8], table: &[u8; 256]) -> Vec<u8> {
+    data.iter().map(|b| table[*b as usize]).collect()
+}
+
+/// Replaces the line feeds (and CRLF sequences) of a record without
+/// field terminators by field terminators, and a trailing pair of them
+/// by a field and a record terminator. Returns `None` if the record
+/// contains a field terminator or no line feed.
+fn restore_lf_terminators(data: &[u8]) -> Option<Vec<u8>> {
+    if data.contains(&FIELD_TERMINATOR) || !data.contains(&b'\n') {
+        return None;
+    }
+
+    let mut restored = Vec::with_capacity(data.len());
+    let mut iter = data.iter().peekable();
+    while let Some(b) = iter.next() {
+        match b {
+            b'\r' if iter.peek() == Some(&&b'\n') => (),
+            b'\n' => restored.push(FIELD_TERMINATOR),
+            _ => restored.push(*b),
+        }
+    }
+
+    if restored.ends_with(&[FIELD_TERMINATOR, FIELD_TERMINATOR]) {
+        if let Some(last) = restored.last_mut() {
+            *last = RECORD_TERMINATOR;
+        }
+    }
+
+    Some(restored)
+}
+
+/// Parse a whole record, consisting of the leader, the directory, the
+/// variable fields and the record terminator.
+///
+/// The variable fields are sliced out of the data area by the
+/// starting character position and the length of the corresponding
+/// directory entry.
+/// Fails with [`ParseRecordError::TrailingData`] if any data is left
+/// after the record.
+fn check_trailing_data(
+    data: &[u8],
+    rest: &[u8],
+) -> Result<(), ParseRecordError> {
+    if rest.is_empty() {
+        return Ok(());
+    }
+
+    Err(ParseRecordError::TrailingData {
+        position: data.len() - rest.len(),
+    })
+}
+
+pub(crate) fn parse_record(
+    i: &[u8],
+) -> ParseResult<'_, Record, ParseRecordError> {
+    parse_record_with(i, &ParseOptions::default(), &mut vec![])
+}
+
+pub(crate) fn parse_record_with<'a>(
+    i: &'a [u8],
+    options: &ParseOptions,
+    warnings: &mut Vec<ParseWarning>,
+) -> ParseResult<'a, Record, ParseRecordError> {
+    let num_warnings = warnings.len();
+
+    // Records a spec violation as a warning in lenient mode or fails
+    // with the corresponding error otherwise.
+    let mut tolerate = |warning: ParseWarning, position: usize| {
+        if options.lenient {
+            warnings.push(warning);
+            return Ok(());
+        }
+
+        Err(nom::Err::Error(match warning {
+            ParseWarning::LengthMismatch { declared, actual } => {
+                ParseRecordError::LengthMismatch { declared, actual }
+            }
+            ParseWarning::InvalidIndicator { tag, indicator } => {
+                ParseRecordError::InvalidIndicator {
+                    tag,
+                    indicator,
+                    position,
+                }
+            }
+            ParseWarning::InvalidSubfieldCode { tag, code } => {
+                ParseRecordError::InvalidSubfieldCode {
+                    tag,
+                    code,
+                    position,
+                }
+            }
+            ParseWarning::MissingTerminator => {
+                ParseRecordError::MissingTerminator { position }
+            }
+            ParseWarning::DoubledTerminator
+            | ParseWarning::LineFeedTerminators => {
+                ParseRecordError::InvalidField { position }
+            }
+        }))
+    };
+
+    let (rest, leader) =
+        parse_leader(i).map_err(|e| e.map(ParseRecordError::from))?;
+    let (_, mut directory) = parse_directory(rest, &leader)?;
+
+    let mut body = &i[leader.base_address as usize..];
+
+    // Some faulty generators separate the fields by doubled field
+    // terminators, which misaligns the fields with the directory.
+    let repaired;
+    let mut removed = 0;
+    if options.lenient && !fits_directory(&directory.entries, body) {
+        if let Some((data, n)) =
+            repair_doubled_terminators(&mut directory.entries, body)
+        {
+            tolerate(
+                ParseWarning::DoubledTerminator,
+                leader.base_address as usize,
+            )?;
+            repaired = data;
+            body = &repaired;
+            removed = n;
+        }
+    }
+    let utf8_validation = match leader.character_coding_scheme() {
+        CharacterCodingScheme::Utf8 => options.utf8_validation,
+        _ => Utf8Validation::Raw,
+    };
+
+    let mut control_fields = vec![];
+    let mut data_fields = vec![];
+    let mut kinds = vec![];
+    let mut body_len = 0;
+    let mut modified = false;
+
+    for entry in directory.entries.iter() {
+        let start = entry.start as usize;
+        let end = start + entry.length as usize;
+
+        // The position of the field as given by its directory entry.
+        let position = leader.base_address as usize + start;
+        let invalid = || ParseRecordError::InvalidField { position };
+
+        let data = body.get(start..end).ok_or(
+            ParseRecordError::DirectoryOutOfBounds {
+                tag: entry.tag,
+                start: entry.start,
+                length: entry.length,
+            },
+        )?;
+
+        if is_control_tag(&entry.tag) {
+            let (_, field) = parse_control_field(entry.tag)(data)
+                .map_err(|_| invalid())?;
+            control_fields.push(field);
+            kinds.push(true);
+        } else {
+            let (_, mut field) = parse_data_field(
+                entry.tag,
+                leader.indicator_count(),
+                leader.subfield_code_count(),
+            )(data)
+            .map_err(|_| invalid())?;
+
+            for indicator in [field.ind1, field.ind2] {
+                if !is_valid_indicator(indicator) {
+                    tolerate(
+                        ParseWarning::InvalidIndicator {
+                            tag: field.tag,
+                            indicator,
+                        },
+                        position,
+                    )?;
+                }
+            }
+
+            for subfield in field.subfields.iter() {
+                if !is_valid_subfield_code(subfield.code) {
+                    tolerate(
+                        ParseWarning::InvalidSubfieldCode {
+                            tag: field.tag,
+                            code: subfield.code,
+                        },
+                        position,
+                    )?;
+                }
+            }
+
+            if utf8_validation != Utf8Validation::Raw {
+                modified |= validate_utf8(
+                    &mut field,
+                    utf8_validation,
+                    position,
+                )?;
+            }
+
+            data_fields.push(field);
+            kinds.push(false);
+        }
+
+        body_len = body_len.max(end);
+    }
+
+    let mut record_len =
+        leader.base_address as usize + body_len + removed;
+    let missing_terminator =
+        body.get(body_len) != Some(&RECORD_TERMINATOR);
+    if missing_terminator {
+        tolerate(ParseWarning::MissingTerminator, record_len)?;
+    } else {
+        record_len += 1;
+    }
+
+    // Records exceeding the maximum length can't encode their length
+    // in the leader, which is then commonly set to `00000`.
+    let length_overflowed =
+        leader.record_len == 0 && record_len > MAX_RECORD_LEN as usize;
+
+    if length_overflowed && options.lenient {
+        // The record is accepted, see `Record::length_overflowed`.
+    } else if record_len as u32 != leader.record_len {
+        tolerate(
+            ParseWarning::LengthMismatch {
+                declared: leader.record_len,
+                actual: record_len as u32,
+            },
+            0,
+        )?;
+    }
+
+    // Records with recovered violations or replaced values don't
+    // match their original bytes.
+    let original = if options.preserve_layout
+        && warnings.len() == num_warnings
+        && !modified
+    {
+        Original(Some(i[..record_len].to_vec()))
+    } else {
+        Original::default()
+    };
+
+    let normalize =
+        (leader.indicator_count, leader.subfield_code_count) != (2, 2);
+    let mut record = Record {
+        leader,
+        control_fields,
+        data_fields,
+        length_overflowed,
+        missing_terminator,
+        original,
+        directory: Original(Some(directory.entries)),
+        field_order: FieldOrder::new(kinds),
+    };
+
+    // The fields are written with the standard counts, which changes
+    // the layout of the record.
+    if normalize {
+        record.update_leader();
+    }
+
+    Ok((&i[record_len..], record))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: &[u8] = include_bytes!("../tests/data/sandburg.mrc");
+
+    #[test]
+    fn test_parse_record() -> anyhow::Result<()> {
+        let data =
+            b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+            123456789\x1e00\x1faTitle\x1e\x1d";
+        let (rest, record) = parse_record(data).finish()?;
+        assert!(rest.is_empty());
+        assert_eq!(record.leader().record_length(), 70);
+        assert_eq!(
+            record.control_fields,
+            vec![ControlField::new(b"001", "123456789")]
+        );
+        assert_eq!(
+            record.data_fields,
+            vec![DataField {
+                tag: *b"245",
+                ind1: b'0',
+                ind2: b'0',
+                subfields: vec![Subfield::new(b'a', "Title")]
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_control_number() -> anyhow::Result<()> {
+        let record = Record::from_bytes(
+            b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+            123456789\x1e00\x1faTitle\x1e\x1d",
+        )?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+
+        let record = Record::from_bytes(
+            b"00068nam a2200049 c 4500003000700000001001100007\x1e\
+            DE-101\x1e 12345678 \x1e\x1d",
+        )?;
+        assert_eq!(record.control_number(), Some(&b" 12345678 "[..]));
+
+        let record = Record::from_bytes(
+            b"00048nam a2200037 c 4500245001000000\x1e\
+            00\x1faTitle\x1e\x1d",
+        )?;
+        assert_eq!(record.control_number(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fields() -> anyhow::Result<()> {
+        let record = Record::from_bytes(DATA)?;
+
+        let fields = record.fields(b"650").collect::<Vec<_>>();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(
+            fields[0].subfields()[0],
+            Subfield::new(b'a', "Arithmetic")
+        );
+        assert_eq!(
+            fields[1].subfields()[0],
+            Subfield::new(b'a', "Children's poetry, American.")
+        );
+
+        let fields = record.fields(b"245").collect::<Vec<_>>();
+        assert_eq!(fields.len(), 1);
+        assert_eq!(fields[0].tag(), b"245");
+
+        assert_eq!(record.fields(b"001").count(), 0);
+        assert_eq!(record.fields(b"100").count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_control_fields_by_tag() -> anyhow::Result<()> {
+        let record = Record::from_bytes(DATA)?;
+
+        let fields =
+            record.control_fields_by_tag(b"003").collect::<Vec<_>>();
+        assert_eq!(fields, vec![&ControlField::new(b"003", "DE-101")]);
+
+        assert_eq!(record.control_fields_by_tag(b"245").count(), 0);
+        assert_eq!(record.control_fields_by_tag(b"008").count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tag_lookup_wildcard() -> anyhow::Result<()> {
+        let record = Record::from_bytes(DATA)?;
+
+        assert_eq!(
+            record.fields(b"6XX").count(),
+            record.fields(b"650").count()
+        );
+        assert_eq!(
+            record.fields(b"XXX").count(),
+            record.data_fields().count()
+        );
+        assert_eq!(record.control_fields_by_tag(b"00X").count(), 2);
+        assert_eq!(record.get(b"00X").unwrap().tag(), b"001");
+        assert_eq!(record.get(b"X4X").unwrap().tag(), b"245");
+        assert!(record.get(b"9XX").is_none());
+        assert_eq!(
+            record.first_subfield_value(b"2XX", b'a'),
+            record.first_subfield_value(b"245", b'a')
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_bytes() -> anyhow::Result<()> {
+        let record = Record::from_bytes(DATA)?;
+        assert_eq!(record.to_bytes(), DATA);
+        assert_eq!(Record::from_bytes(&record.to_bytes())?, record);
+
+        let record = Record::from_bytes(include_bytes!(
+            "../tests/data/arithmetic.mrc"
+        ))?;
+        assert_eq!(Record::from_bytes(&record.to_bytes())?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_bytes_recompute() -> anyhow::Result<()> {
+        let mut record = Record::from_bytes(DATA)?;
+        record.data_fields.truncate(1);
+
+        let data = record.to_bytes();
+        let record = Record::from_bytes(&data)?;
+        assert_eq!(record.leader().record_length(), data.len() as u32);
+        assert_eq!(record.leader().base_address_of_data(), 61);
+        assert_eq!(record.data_fields.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_record_invalid() {
+        assert!(matches!(
+            Record::from_bytes(
+                b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+                123456789\x1e00\x1faTitle\x1e"
+            ),
+            Err(ParseRecordError::MissingTerminator { position: 69 })
+        ));
+
+        assert!(matches!(
+            Record::from_bytes(
+                b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+                123456789\x1e00\x1faTitl\x1d"
+            ),
+            Err(ParseRecordError::DirectoryOutOfBounds {
+                tag: [b'2', b'4', b'5'],
+                start: 10,
+                length: 10
+            })
+        ));
+
+        assert!(matches!(
+            Record::from_bytes(b"00070nam a2200049 c 4500"),
+            Err(ParseRecordError::InvalidDirectory { position: 24 })
+        ));
+
+        assert!(matches!(
+            Record::from_bytes(b"00072nam"),
+            Err(ParseRecordError::Leader { position: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_record_position() {
+        let mut data = DATA.to_vec();
+        data[101] = b'x';
+
+        let err = Record::from_bytes(&data).unwrap_err();
+        assert!(matches!(
+            err,
+            ParseRecordError::InvalidField { position: 95 }
+        ));
+        assert_eq!(err.position(), Some(95));
+    }
+
+    #[test]
+    fn test_parse_record_length_mismatch() {
+        assert!(matches!(
+            Record::from_bytes(
+                b"00827nam a2200049 c 4500001001000000245001000010\x1e\
+                123456789\x1e00\x1faTitle\x1e\x1d"
+            ),
+            Err(ParseRecordError::LengthMismatch {
+                declared: 827,
+                actual: 70
+            })
+        ));
+    }
+
+    #[test]
+    fn test_parse_record_trailing_data() -> anyhow::Result<()> {
+        let data = b"00048nam a2200037 c 4500001001000000\x1e\
+            123456789\x1e\x1d";
+        let trailing = [&data[..], b"\n"].concat();
+
+        let err = Record::from_bytes(&trailing).unwrap_err();
+        assert!(matches!(
+            err,
+            ParseRecordError::TrailingData { position: 48 }
+        ));
+        assert_eq!(err.position(), Some(48));
+
+        assert!(matches!(
+            Record::from_bytes_with(
+                &trailing,
+                &ParseOptions::default()
+            ),
+            Err(ParseRecordError::TrailingData { position: 48 })
+        ));
+
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let (record, warnings) =
+            Record::from_bytes_with(&trailing, &options)?;
+        assert_eq!(record, Record::from_bytes(data)?);
+        assert!(warnings.is_empty());
+
+        let (record, len) = Record::from_bytes_prefix(&trailing)?;
+        assert_eq!(record, Record::from_bytes(data)?);
+        assert_eq!(len, 48);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_accept_lf_terminators() -> anyhow::Result<()> {
+        let expected = Record::from_bytes(
+            b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+            123456789\x1e00\x1faTitle\x1e\x1d",
+        )?;
+        let options = ParseOptions {
+            lenient: true,
+            accept_lf_terminators: true,
+            preserve_layout: true,
+            ..Default::default()
+        };
+
+        let data =
+            b"00070nam a2200049 c 4500001001000000245001000010\n\
+            123456789\n00\x1faTitle\n\n";
+        let (record, warnings) =
+            Record::from_bytes_with(data, &options)?;
+        assert_eq!(warnings, vec![ParseWarning::LineFeedTerminators]);
+        assert_eq!(record, expected);
+        assert_eq!(record.to_bytes(), expected.to_bytes());
+
+        let data =
+            b"00070nam a2200049 c 4500001001000000245001000010\r\n\
+            123456789\r\n00\x1faTitle\r\n\x1d";
+        let (record, warnings) =
+            Record::from_bytes_with(data, &options)?;
+        assert_eq!(warnings, vec![ParseWarning::LineFeedTerminators]);
+        assert_eq!(record, expected);
+
+        // Canonical records are left alone, even if a value contains a
+        // line feed.
+        let data =
+            b"00071nam a2200049 c 4500001001000000245001100010\x1e\
+            123456789\x1e00\x1faTit\nle\x1e\x1d";
+        let (record, warnings) =
+            Record::from_bytes_with(data, &options)?;
+        assert!(warnings.is_empty());
+        assert_eq!(
+            record.first_subfield_value(b"245", b'a'),
+            Some(&b"Tit\nle"[..])
+        );
+
+        let data =
+            b"00070nam a2200049 c 4500001001000000245001000010\n\
+            123456789\n00\x1faTitle\n\n";
+        assert!(Record::from_bytes_with(
+            data,
+            &ParseOptions {
+                lenient: true,
+                ..Default::default()
+            }
+        )
+        .is_err());
+        assert!(Record::from_bytes_with(
+            data,
+            &ParseOptions {
+                accept_lf_terminators: true,
+                ..Default::default()
+            }
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_indicator_count() -> anyhow::Result<()> {
+        let data = b"00047nam a1200037 c 4500245000900000\x1e\
+            0\x1faTitle\x1e\x1d";
+
+        // The leader is normalized to the standard counts.
+        let record = Record::from_bytes(data)?;
+        assert_eq!(record.leader().indicator_count(), 2);
+        assert_eq!(record.leader().record_length(), 48);
+
+        let field = record.fields(b"245").next().unwrap();
+        assert_eq!((field.ind1(), field.ind2()), (b'0', b' '));
+        assert_eq!(field.subfields(), [Subfield::new(b'a', "Title")]);
+
+        // The record is written with the standard counts.
+        let bytes = record.to_bytes();
+        assert_eq!(
+            bytes,
+            b"00048nam a2200037 c 4500245001000000\x1e\
+            0 \x1faTitle\x1e\x1d"
+        );
+        assert_eq!(Record::from_bytes(&bytes)?, record);
+
+        let options = ParseOptions {
+            preserve_layout: true,
+            ..Default::default()
+        };
+        let (record, _) = Record::from_bytes_with(data, &options)?;
+        assert_eq!(record.to_bytes(), bytes);
+
+        // With the standard indicator count, the subfields are
+        // misaligned.
+        let mut data = data.to_vec();
+        data[10] = b'2';
+        assert!(Record::from_bytes(&data).is_err());
+
+        // A data field holds no more than two indicators.
+        assert!(Record::from_bytes(
+            b"00049nam a3200037 c 4500245001100000\x1e\
+            012\x1faTitle\x1e\x1d"
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_subfield_code_count() -> anyhow::Result<()> {
+        let data = b"00049nam a2300037 c 4500245001100000\x1e\
+            00\x1fa1Title\x1e\x1d";
+
+        let record = Record::from_bytes(data)?;
+        assert_eq!(record.leader().subfield_code_count(), 2);
+
+        // The second code byte is kept as part of the value.
+        let field = record.fields(b"245").next().unwrap();
+        assert_eq!(field.subfields(), [Subfield::new(b'a', "1Title")]);
+
+        let bytes = record.to_bytes();
+        assert_eq!(&bytes[..12], b"00049nam a22");
+        assert_eq!(&bytes[12..], &data[12..]);
+        assert_eq!(Record::from_bytes(&bytes)?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_doubled_terminator() -> anyhow::Result<()> {
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let expected = Record::from_bytes(
+            b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+            123456789\x1e00\x1faTitle\x1e\x1d",
+        )?;
+
+        let data =
+            b"00071nam a2200049 c 4500001001000000245001000010\x1e\
+            123456789\x1e\x1e00\x1faTitle\x1e\x1d";
+        assert!(matches!(
+            Record::from_bytes(data),
+            Err(ParseRecordError::InvalidField { position: 59 })
+        ));
+
+        let mut warnings = vec![];
+        let (rest, record) = parse_record_with(
+            &[&data[..], b"rest"].concat(),
+            &options,
+            &mut warnings,
+        )
+        .finish()
+        .map(|(rest, record)| (rest.to_vec(), record))?;
+        assert_eq!(rest, b"rest");
+        assert_eq!(warnings, vec![ParseWarning::DoubledTerminator]);
+        assert_eq!(record.leader().record_length(), 71);
+        assert_eq!(record.to_bytes(), expected.to_bytes());
+
+        // The directory accounts for the doubled terminators.
+        let data =
+            b"00072nam a2200049 c 4500001001100000245001100011\x1e\
+            123456789\x1e\x1e00\x1faTitle\x1e\x1e\x1d";
+        let (record, warnings) =
+            Record::from_bytes_with(data, &options)?;
+        assert_eq!(warnings, vec![ParseWarning::DoubledTerminator]);
+        assert_eq!(record.to_bytes(), expected.to_bytes());
+
+        // An empty control field isn't a doubled terminator.
+        let data = b"00083nam a2200061 c 4500001001000000003000100010\
+            245001000011\x1e123456789\x1e\x1e00\x1faTitle\x1e\x1d";
+        let (record, warnings) =
+            Record::from_bytes_with(data, &options)?;
+        assert_eq!(record.control_fields().count(), 2);
+        assert!(warnings.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_record_lenient() -> anyhow::Result<()> {
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+
+        let data =
+            b"00827nam a2200049 c 4500001001000000245001000010\x1e\
+            123456789\x1e00\x1faTitle\x1e\x1d";
+        let (record, warnings) =
+            Record::from_bytes_with(data, &options)?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        assert_eq!(
+            warnings,
+            vec![ParseWarning::LengthMismatch {
+                declared: 827,
+                actual: 70
+            }]
+        );
+
+        let data =
+            b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+            123456789\x1e0#\x1faTitle\x1e\x1d";
+        assert!(matches!(
+            Record::from_bytes(data),
+            Err(ParseRecordError::InvalidIndicator {
+                tag: [b'2', b'4', b'5'],
+                indicator: b'#',
+                position: 59
+            })
+        ));
+        let (record, warnings) =
+            Record::from_bytes_with(data, &options)?;
+        assert_eq!(record.data_fields[0].ind2(), b'#');
+        assert_eq!(
+            warnings,
+            vec![ParseWarning::InvalidIndicator {
+                tag: *b"245",
+                indicator: b'#'
+            }]
+        );
+
+        let data =
+            b"00069nam a2200049 c 4500001001000000245001000010\x1e\
+            123456789\x1e00\x1faTitle\x1e";
+        assert!(matches!(
+            Record::from_bytes(data),
+            Err(ParseRecordError::MissingTerminator { position: 69 })
+        ));
+        let (record, warnings) =
+            Record::from_bytes_with(data, &options)?;
+        assert_eq!(record.data_fields.len(), 1);
+        assert_eq!(warnings, vec![ParseWarning::MissingTerminator]);
+
+        let data =
+            b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+            123456789\x1e00\x1faTitle\x1e\x1d";
+        let (_, warnings) = Record::from_bytes_with(data, &options)?;
+        assert!(warnings.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_builder() -> anyhow::Result<()> {
+        let record = RecordBuilder::new()
+            .leader(Leader::from_bytes(b"00000nam a2200000 c 4500")?)
+            .control_field(b"001", "123456789")
+            .control_field(b"003", "DE-101")
+            .data_field(
+                b"245",
+                b'0',
+                b'0',
+                [
+                    Subfield::new(b'a', "Arithmetic /"),
+                    Subfield::new(b'c', "Carl Sandburg."),
+                ],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [
+                    Subfield::new(b'a', "Arithmetic"),
+                    Subfield::new(b'v', "Juvenile poetry."),
+                ],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Children's poetry, American.")],
+            )
+            .build();
+
+        assert_eq!(record.leader().record_length(), 202);
+        assert_eq!(record.leader().base_address_of_data(), 85);
+        assert_eq!(record.to_bytes(), DATA);
+        assert_eq!(Record::from_bytes(&record.to_bytes())?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_builder_sort_by_tag() -> anyhow::Result<()> {
+        let builder = RecordBuilder::new()
+            .data_field(b"650", b' ', b'0', [Subfield::new(b'a', "A")])
+            .data_field(b"245", b'0', b'0', [Subfield::new(b'a', "B")])
+            .data_field(b"650", b' ', b'0', [Subfield::new(b'a', "C")])
+            .control_field(b"003", "DE-101")
+            .control_field(b"001", "123456789");
+
+        let record = builder.sort_by_tag(true).build();
+        let tags = record
+            .data_fields
+            .iter()
+            .map(|field| &field.tag)
+            .collect::<Vec<_>>();
+        assert_eq!(tags, vec![b"245", b"650", b"650"]);
+        assert_eq!(record.data_fields[1].subfields()[0].value(), b"A");
+        assert_eq!(record.control_fields[0].tag(), b"001");
+        assert_eq!(Record::from_bytes(&record.to_bytes())?, record);
+
+        let record = RecordBuilder::new()
+            .data_field(b"650", b' ', b'0', [])
+            .data_field(b"245", b'0', b'0', [])
+            .build();
+        assert_eq!(record.data_fields[0].tag(), b"650");
+        assert_eq!(Record::from_bytes(&record.to_bytes())?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tag_matches() {
+        assert!(tag_matches(b"245", b"245"));
+        assert!(tag_matches(b"9XX", b"910"));
+        assert!(tag_matches(b"XXX", b"001"));
+        assert!(tag_matches(b"00X", b"008"));
+        assert!(!tag_matches(b"9XX", b"245"));
+        assert!(!tag_matches(b"24", b"245"));
+    }
+
+    #[test]
+    fn test_mutations() -> anyhow::Result<()> {
+        let local = RecordBuilder::new()
+            .data_field(
+                b"910",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "Local")],
+            )
+            .data_field(
+                b"999",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "Local")],
+            )
+            .build();
+
+        let mut record = Record::from_bytes(DATA)?;
+        for field in local.data_fields.iter() {
+            record.add_field(field.clone());
+        }
+
+        let data = record.to_bytes();
+        assert_eq!(record.leader().record_length(), data.len() as u32);
+        assert_eq!(Record::from_bytes(&data)?, record);
+        assert_eq!(record.fields(b"999").count(), 1);
+
+        assert_eq!(record.remove_fields(b"9XX"), 2);
+        assert_eq!(record.fields(b"910").count(), 0);
+        assert_eq!(record.fields(b"999").count(), 0);
+        assert_eq!(record.to_bytes(), DATA);
+        assert_eq!(record, Record::from_bytes(DATA)?);
+
+        let old = record.replace_field(0, local.data_fields[0].clone());
+        assert_eq!(old.tag(), b"245");
+        let data = record.to_bytes();
+        assert_eq!(record.leader().record_length(), data.len() as u32);
+        assert_eq!(Record::from_bytes(&data)?, record);
+        assert_eq!(record.fields(b"245").count(), 0);
+
+        assert_eq!(record.remove_fields(b"001"), 1);
+        assert_eq!(record.control_number(), None);
+        assert_eq!(Record::from_bytes(&record.to_bytes())?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_field_count() -> anyhow::Result<()> {
+        let mut record = Record::from_bytes(DATA)?;
+        assert_eq!(record.field_count(), 5);
+
+        record.remove_fields(b"650");
+        assert_eq!(record.field_count(), 3);
+
+        let record = RecordBuilder::new().build();
+        assert_eq!(record.field_count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_subfield_count() -> anyhow::Result<()> {
+        let mut record = Record::from_bytes(DATA)?;
+        assert_eq!(record.subfield_count(), 5);
+
+        record.remove_fields(b"245");
+        assert_eq!(record.subfield_count(), 3);
+
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(b"700", b'1', b' ', [])
+            .build();
+        assert_eq!(record.subfield_count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bytes_len() -> anyhow::Result<()> {
+        let mut record = Record::from_bytes(DATA)?;
+        assert_eq!(record.bytes_len(), 202);
+
+        record.remove_fields(b"650");
+        assert_eq!(record.bytes_len(), 112);
+        assert_eq!(
+            record.bytes_len() as usize,
+            record.to_bytes().len()
+        );
+
+        let options = ParseOptions {
+            preserve_layout: true,
+            ..Default::default()
+        };
+        let (record, _) = Record::from_bytes_with(DATA, &options)?;
+        assert_eq!(record.bytes_len(), 202);
+
+        let record = RecordBuilder::new().build();
+        assert_eq!(record.bytes_len(), 26);
+        assert_eq!(
+            record.bytes_len() as usize,
+            record.to_bytes().len()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_tag_histogram() -> anyhow::Result<()> {
+        let mut builder =
+            RecordBuilder::new().control_field(b"001", "1");
+        for _ in 0..40 {
+            builder = builder.data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "x")],
+            );
+        }
+
+        let histogram = builder.build().tag_histogram();
+        assert_eq!(
+            histogram.into_iter().collect::<Vec<_>>(),
+            vec![(*b"001", 1), (*b"650", 40)]
+        );
+
+        let histogram = Record::from_bytes(DATA)?.tag_histogram();
+        assert_eq!(histogram.len(), 4);
+        assert_eq!(histogram[b"001"], 1);
+        assert_eq!(histogram[b"003"], 1);
+        assert_eq!(histogram[b"245"], 1);
+        assert_eq!(histogram[b"650"], 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_length_overflowed() -> anyhow::Result<()> {
+        let mut builder =
+            RecordBuilder::new().control_field(b"001", "123456789");
+        for _ in 0..17 {
+            let subfield = Subfield::new(b'a', "x".repeat(6000));
+            builder =
+                builder.data_field(b"500", b' ', b' ', [subfield]);
+        }
+
+        let record = builder.build();
+        let data = record.to_bytes();
+        assert_eq!(data.len(), 102_337);
+        assert_eq!(&data[0..5], b"00000");
+        assert!(!record.length_overflowed());
+
+        assert!(matches!(
+            Record::from_bytes(&data),
+            Err(ParseRecordError::LengthMismatch {
+                declared: 0,
+                actual: 102_337
+            })
+        ));
+
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let (record, warnings) =
+            Record::from_bytes_with(&data, &options)?;
+        assert!(warnings.is_empty());
+        assert!(record.length_overflowed());
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        assert_eq!(record.to_bytes(), data);
+
+        let record = Record::from_bytes(DATA)?;
+        assert!(!record.length_overflowed());
+        Ok(())
+    }
+
+    #[test]
+    fn test_entry_map() -> anyhow::Result<()> {
+        let data = b"00077nam a2200051 c 5500001000100000024500015\
+            00010\x1e123456789\x1e00\x1faArithmetic\x1e\x1d";
+
+        let record = Record::from_bytes(data)?;
+        assert_eq!(
+            record.leader().entry_map().length_of_field_portion,
+            5
+        );
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        assert_eq!(
+            record.fields(b"245").next().unwrap().subfield(b'a'),
+            Some(&Subfield::new(b'a', "Arithmetic"))
+        );
+        assert_eq!(record.to_bytes(), data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches() -> anyhow::Result<()> {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(b"245", b'1', b'0', [])
+            .data_field(
+                b"650",
+                b' ',
+                b'7',
+                [
+                    Subfield::new(b'a', "Arithmetic"),
+                    Subfield::new(b'2', "fast"),
+                ],
+            )
+            .build();
+
+        assert!(record.matches(b"001", None, None));
+        assert!(record.matches(b"245", None, None));
+        assert!(record.matches(b"650", None, None));
+        assert!(record.matches(b"6XX", None, None));
+        assert!(!record.matches(b"100", None, None));
+
+        assert!(record.matches(b"650", Some(b'2'), None));
+        assert!(!record.matches(b"650", Some(b'x'), None));
+        assert!(!record.matches(b"245", Some(b'a'), None));
+        assert!(!record.matches(b"001", Some(b'a'), None));
+
+        assert!(record.matches(b"650", Some(b'2'), Some(b"fast")));
+        assert!(!record.matches(b"650", Some(b'2'), Some(b"gnd")));
+        assert!(!record.matches(b"650", Some(b'a'), Some(b"fast")));
+        assert!(record.matches(b"650", None, Some(b"Arithmetic")));
+        assert!(record.matches(b"001", None, Some(b"123456789")));
+        assert!(!record.matches(b"001", None, Some(b"987654321")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_subfield_code() -> anyhow::Result<()> {
+        let data =
+            b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+            123456789\x1e00\x1f\x1eTitle\x1e\x1d";
+        let err = Record::from_bytes(data).unwrap_err();
+        assert!(matches!(
+            err,
+            ParseRecordError::InvalidSubfieldCode {
+                tag: [b'2', b'4', b'5'],
+                code: 0x1e,
+                position: 59
+            }
+        ));
+        assert_eq!(err.position(), Some(59));
+
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let (record, warnings) =
+            Record::from_bytes_with(data, &options)?;
+        assert_eq!(record.data_fields[0].subfields()[0].code(), 0x1e);
+        assert_eq!(
+            warnings,
+            vec![ParseWarning::InvalidSubfieldCode {
+                tag: *b"245",
+                code: 0x1e
+            }]
+        );
+
+        let data =
+            b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+            123456789\x1e0A\x1faTitle\x1e\x1d";
+        assert_eq!(
+            Record::from_bytes(data)?.data_fields[0].ind2(),
+            b'A'
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_well_terminated() -> anyhow::Result<()> {
+        let record = Record::from_bytes(DATA)?;
+        assert!(record.well_terminated());
+
+        let data = &DATA[..DATA.len() - 1];
+        assert!(matches!(
+            Record::from_bytes(data),
+            Err(ParseRecordError::MissingTerminator { .. })
+        ));
+
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let (record, warnings) =
+            Record::from_bytes_with(data, &options)?;
+        assert!(!record.well_terminated());
+        assert!(warnings.contains(&ParseWarning::MissingTerminator));
+        assert_eq!(record.to_bytes().last(), Some(&RECORD_TERMINATOR));
+
+        assert!(RecordBuilder::new().build().well_terminated());
+        Ok(())
+    }
+
+    #[test]
+    fn test_deduplicated() -> anyhow::Result<()> {
+        let subject = |value| [Subfield::new(b'a', value)];
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(b"650", b' ', b'0', subject("Arithmetic"))
+            .data_field(b"245", b'0', b'0', subject("Title"))
+            .data_field(b"650", b' ', b'0', subject("Arithmetic"))
+            .data_field(b"650", b' ', b'7', subject("Arithmetic"))
+            .data_field(b"650", b' ', b'0', subject("Poetry"))
+            .build();
+
+        let deduplicated = record.deduplicated();
+        assert_eq!(deduplicated.field_count(), 5);
+        assert_eq!(
+            deduplicated
+                .data_fields()
+                .map(|field| (field.tag(), field.ind2()))
+                .collect::<Vec<_>>(),
+            [
+                (b"650", b'0'),
+                (b"245", b'0'),
+                (b"650", b'7'),
+                (b"650", b'0')
+            ]
+        );
+        assert_eq!(
+            deduplicated.leader().record_length() as usize,
+            deduplicated.to_bytes().len()
+        );
+        assert_eq!(deduplicated.deduplicated(), deduplicated);
+        assert_eq!(record.clone(), record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract() -> anyhow::Result<()> {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .control_field(b"003", "DE-101")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'7',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .data_field(
+                b"500",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "Note")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'7',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .build();
+
+        let extracted = record.extract(&[b"650", b"001"]);
+        assert_eq!(extracted.field_count(), 3);
+        assert_eq!(extracted.control_number(), Some(&b"123456789"[..]));
+        assert_eq!(
+            extracted
+                .fields(b"650")
+                .map(|field| field.subfield(b'a').unwrap().value())
+                .collect::<Vec<_>>(),
+            vec![&b"Poetry"[..], &b"Arithmetic"[..]]
+        );
+
+        let data = extracted.to_bytes();
+        assert_eq!(
+            extracted.leader().record_length() as usize,
+            data.len()
+        );
+        assert_eq!(Record::from_bytes(&data)?, extracted);
+
+        assert_eq!(record.extract::<&[u8]>(&[]).field_count(), 0);
+        assert_eq!(record.extract(&[b"00X"]).field_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge() -> anyhow::Result<()> {
+        let mut record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .build();
+
+        let other = RecordBuilder::new()
+            .control_field(b"001", "987654321")
+            .control_field(b"003", "DE-101")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic /")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'7',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .data_field(
+                b"500",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "Note")],
+            )
+            .build();
+
+        record.merge(&other, &[b"245", b"5XX"]);
+        let histogram = record.tag_histogram();
+        assert_eq!(histogram.get(b"001"), Some(&1));
+        assert_eq!(histogram.get(b"003"), Some(&1));
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        assert_eq!(record.fields(b"245").count(), 1);
+        assert_eq!(record.fields(b"650").count(), 1);
+        assert_eq!(record.fields(b"500").count(), 0);
+
+        let data = record.to_bytes();
+        assert_eq!(
+            record.leader().record_length() as usize,
+            data.len()
+        );
+        assert_eq!(Record::from_bytes(&data)?, record);
+        Ok(())
+    }
+
+    #[test]
+    fn test_first_subfield_value() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [
+                    Subfield::new(b'a', "Arithmetic /"),
+                    Subfield::new(b'c', "Carl Sandburg"),
+                    Subfield::new(b'a', "Other"),
+                ],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'v', "Juvenile poetry.")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .build();
+
+        assert_eq!(
+            record.first_subfield_value(b"245", b'a'),
+            Some(&b"Arithmetic /"[..])
+        );
+        assert_eq!(
+            record.first_subfield_value(b"245", b'c'),
+            Some(&b"Carl Sandburg"[..])
+        );
+        assert_eq!(record.first_subfield_value(b"245", b'b'), None);
+        assert_eq!(record.first_subfield_value(b"650", b'a'), None);
+        assert_eq!(record.first_subfield_value(b"100", b'a'), None);
+        assert_eq!(record.first_subfield_value(b"001", b'a'), None);
+    }
+
+    #[test]
+    fn test_custom_delimiters() -> anyhow::Result<()> {
+        let options = ParseOptions {
+            subfield_delimiter: b'$',
+            field_terminator: b'#',
+            record_terminator: b'\n',
+            ..Default::default()
+        };
+
+        let data = b"00078nam a2200049 c 4500001001000000245001800010#\
+            123456789#10$aArithmetic$c\x1f#\n";
+
+        let (record, warnings) =
+            Record::from_bytes_with(data, &options)?;
+        assert!(warnings.is_empty());
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+
+        let field = record.fields(b"245").next().unwrap();
+        assert_eq!(field.ind1(), b'1');
+        assert_eq!(
+            field.subfield(b'a').unwrap().value(),
+            b"Arithmetic"
+        );
+        assert_eq!(field.subfield(b'c').unwrap().value(), b"$");
+        assert_eq!(record.to_bytes_with(&options), data);
+
+        assert!(Record::from_bytes(data).is_err());
+        assert_eq!(Record::from_bytes(&record.to_bytes())?, record);
+        assert_eq!(
+            record.to_bytes_with(&ParseOptions::default()),
+            record.to_bytes()
+        );
+
+        // Delimiters may be permuted among the standard bytes.
+        let options = ParseOptions {
+            subfield_delimiter: 0x1e,
+            field_terminator: 0x1d,
+            record_terminator: 0x1f,
+            ..Default::default()
+        };
+        let data = record.to_bytes_with(&options);
+        assert_eq!(data.last(), Some(&0x1f));
+        assert_eq!(Record::from_bytes_with(&data, &options)?.0, record);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_hash() -> anyhow::Result<()> {
+        use std::collections::HashSet;
+
+        let data = b"00048nam a2200037 c 4500001001000000\x1e\
+            123456789\x1e\x1d";
+        let record = Record::from_bytes(data)?;
+
+        let mut set = HashSet::new();
+        assert!(set.insert(record.clone()));
+        assert!(!set.insert(Record::from_bytes(data)?));
+        assert_eq!(set.len(), 1);
+
+        // A different leader position makes the records unequal.
+        let mut other = record.clone();
+        other.leader.raw[5] = b'c';
+        assert!(set.insert(other));
+
+        // The order of the fields is significant.
+        let first = RecordBuilder::new()
+            .data_field(b"650", b' ', b'0', [Subfield::new(b'a', "A")])
+            .data_field(b"650", b' ', b'0', [Subfield::new(b'a', "B")])
+            .build();
+        let second = RecordBuilder::new()
+            .data_field(b"650", b' ', b'0', [Subfield::new(b'a', "B")])
+            .data_field(b"650", b' ', b'0', [Subfield::new(b'a', "A")])
+            .build();
+        assert!(set.insert(first.clone()));
+        assert!(set.insert(second));
+        assert!(!set.insert(first));
+        assert_eq!(set.len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_fields() -> anyhow::Result<()> {
+        let data =
+            b"00054nam a2200049 c 4500001000100000650000300001\x1e\
+            \x1e 0\x1e\x1d";
+
+        let record = Record::from_bytes(data)?;
+        assert_eq!(record.control_number(), Some(&b""[..]));
+
+        let field = record.fields(b"650").next().unwrap();
+        assert_eq!(field.ind1(), b' ');
+        assert_eq!(field.ind2(), b'0');
+        assert!(field.subfields().is_empty());
+        assert_eq!(record.to_bytes(), data);
+
+        let record = RecordBuilder::new()
+            .control_field(b"001", "")
+            .data_field(b"650", b' ', b'0', [])
+            .build();
+        assert_eq!(record.to_bytes()[24..], data[24..]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_directory_out_of_bounds() {
+        // The entry of the 245 field declares a start of 99999.
+        let data =
+            b"00070nam a2200049 c 4500001001000000245001099999\x1e\
+            123456789\x1e00\x1faTitle\x1e\x1d";
+
+        let options = ParseOptions {
+            lenient: true,
+            ..Default::default()
+        };
+
+        for result in [
+            Record::from_bytes(data),
+            Record::from_bytes_with(data, &options).map(|(r, _)| r),
+            crate::RecordRef::from_bytes(data)
+                .and_then(|r| r.to_record()),
+        ] {
+            assert!(matches!(
+                result,
+                Err(ParseRecordError::DirectoryOutOfBounds {
+                    tag: [b'2', b'4', b'5'],
+                    start: 99999,
+                    length: 10
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_truncated_input() {
+        // Every prefix of a valid record must fail without panicking.
+        // The zero-copy view doesn't require the record terminator.
+        for len in 0..DATA.len() {
+            assert!(Record::from_bytes(&DATA[..len]).is_err());
+            if len < DATA.len() - 1 {
+                assert!(
+                    crate::RecordRef::from_bytes(&DATA[..len]).is_err()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_leader_mut() -> anyhow::Result<()> {
+        use crate::{
+            CharacterCodingScheme, EncodingLevel, ParseLeaderError,
+            RecordStatus, TypeOfRecord,
+        };
+
+        let mut record = Record::from_bytes(DATA)?;
+        let leader = record.leader_mut();
+        leader.set_record_status(RecordStatus::Deleted)?;
+        leader.set_type_of_record(TypeOfRecord::NotatedMusic)?;
+        leader.set_character_coding_scheme(
+            CharacterCodingScheme::Marc8,
+        )?;
+        leader.set_encoding_level(EncodingLevel::Minimal)?;
+
+        assert!(matches!(
+            leader.set_record_status(RecordStatus::Other('\x1e')),
+            Err(ParseLeaderError::InvalidRecordStatus)
+        ));
+        assert!(matches!(
+            leader.set_type_of_record(TypeOfRecord::Other('ü')),
+            Err(ParseLeaderError::InvalidTypeOfRecord)
+        ));
+        assert!(matches!(
+            leader.set_encoding_level(EncodingLevel::Other('\0')),
+            Err(ParseLeaderError::InvalidEncodingLevel)
+        ));
+
+        let data = record.to_bytes();
+        assert_eq!(&data[..24], b"00202dcm  22000857c 4500");
+        assert_eq!(data[24..], DATA[24..]);
+
+        let record = Record::from_bytes(&data)?;
+        assert_eq!(
+            record.leader().record_status(),
+            RecordStatus::Deleted
+        );
+        assert_eq!(
+            record.leader().encoding_level(),
+            EncodingLevel::Minimal
+        );
+
+        // The directory of the parsed record is discarded as well.
+        let mut record = Record::from_bytes(
+            b"00060nam a2200049 c 4500001001000000003000500005\x1e\
+            123456789\x1e\x1d",
+        )?;
+        assert!(!record.validate().is_empty());
+        record.leader_mut();
+        assert!(record.directory.0.is_none());
+        assert!(record.original.0.is_none());
+        assert!(record.validate().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_fields_directory_order() -> anyhow::Result<()> {
+        let data =
+            b"00067nam a2200049 c 4500245001000000008000700010\x1e\
+            00\x1faTitle\x1e740101\x1e\x1d";
+
+        let mut record = Record::from_bytes(data)?;
+        let tags = |record: &Record| {
+            record
+                .all_fields()
+                .map(|field| *field.tag())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(tags(&record), [*b"245", *b"008"]);
+        assert_eq!(record.all_fields().len(), 2);
+        assert_eq!(record.to_bytes(), data);
+        assert_eq!(record.try_to_bytes()?, data);
+
+        record.add_field(
+            DataField::new(b"500", b' ', b' ')?
+                .with_subfield(b'a', "Note")?,
+        );
+        assert_eq!(tags(&record), [*b"245", *b"008", *b"500"]);
+        assert_eq!(
+            tags(&Record::from_bytes(&record.to_bytes())?),
+            tags(&record)
+        );
+        assert_eq!(
+            tags(&record.extract(&[b"008", b"500"])),
+            [*b"008", *b"500"]
+        );
+
+        let mut other = record.clone();
+        other.sort_fields();
+        assert_eq!(tags(&other), [*b"008", *b"245", *b"500"]);
+        assert_ne!(other, record);
+
+        // Without the leading data field the order is the usual one.
+        assert_eq!(record.remove_fields(b"245"), 1);
+        assert_eq!(record.field_order, FieldOrder::default());
+        other.remove_fields(b"245");
+        assert_eq!(other, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_fields() -> anyhow::Result<()> {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .control_field(
+                b"008",
+                "751101s1975    nyu           000 0 eng  ",
+            )
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .build();
+
+        assert_eq!(
+            record
+                .control_fields()
+                .map(ControlField::tag)
+                .collect::<Vec<_>>(),
+            [b"001", b"008"]
+        );
+        assert_eq!(
+            record
+                .data_fields()
+                .map(DataField::tag)
+                .collect::<Vec<_>>(),
+            [b"245", b"650", b"650"]
+        );
+
+        let fields = record.all_fields().collect::<Vec<_>>();
+        assert_eq!(
+            fields.iter().map(Field::tag).collect::<Vec<_>>(),
+            [b"001", b"008", b"245", b"650", b"650"]
+        );
+        assert_eq!(
+            fields
+                .iter()
+                .map(Field::is_control_field)
+                .collect::<Vec<_>>(),
+            [true, true, false, false, false]
+        );
+        assert_eq!(
+            fields[4].as_data_field().unwrap().subfields()[0].value(),
+            b"Arithmetic"
+        );
+        assert_eq!(fields.len(), record.field_count());
+
+        // The order matches the serialized directory.
+        let record = Record::from_bytes(&record.to_bytes())?;
+        assert_eq!(
+            record
+                .all_fields()
+                .map(|field| field.tag())
+                .collect::<Vec<_>>(),
+            [b"001", b"008", b"245", b"650", b"650"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_subfield() -> anyhow::Result<()> {
+        let mut record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"650",
+                b' ',
+                b'7',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'7',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .build();
+
+        let len = record.leader().record_length();
+        record.append_subfield(b"650", b'2', &b"fast"[..])?;
+        assert_eq!(record.leader().record_length(), len + 6);
+
+        let fields: Vec<_> = record.fields(b"650").collect();
+        assert_eq!(
+            fields[0].subfields(),
+            [
+                Subfield::new(b'a', "Arithmetic"),
+                Subfield::new(b'2', "fast")
+            ]
+        );
+        assert_eq!(fields[1].subfields().len(), 1);
+        assert_eq!(Record::from_bytes(&record.to_bytes())?, record);
+
+        assert_eq!(
+            record.append_subfield(b"655", b'2', "fast"),
+            Err(DataFieldError::MissingField)
+        );
+        assert_eq!(
+            record.append_subfield(b"001", b'a', "fast"),
+            Err(DataFieldError::MissingField)
+        );
+        assert_eq!(
+            record.append_subfield(b"650", b'A', "fast"),
+            Err(DataFieldError::InvalidSubfieldCode(b'A'))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transcode_field() -> anyhow::Result<()> {
+        let mut record = RecordBuilder::new()
+            .data_field(
+                b"245",
+                b'0',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .build();
+
+        record.transcode_field(b"650", |value| {
+            value.make_ascii_uppercase()
+        });
+        assert_eq!(
+            record.subfield_values(b"650", b'a').collect::<Vec<_>>(),
+            [&b"ARITHMETIC"[..], b"POETRY"]
+        );
+        assert_eq!(
+            record.first_subfield_value(b"245", b'a'),
+            Some(&b"Arithmetic"[..])
+        );
+
+        let len = record.leader().record_length();
+        record.transcode_field(b"6XX", |value| value.push(b'.'));
+        assert_eq!(record.leader().record_length(), len + 2);
+        assert_eq!(Record::from_bytes(&record.to_bytes())?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_fields() -> anyhow::Result<()> {
+        let mut record = RecordBuilder::new()
+            .control_field(b"008", "751101s1975    nyu")
+            .control_field(b"001", "123456789")
+            .data_field(b"650", b' ', b'0', [Subfield::new(b'a', "A")])
+            .data_field(b"245", b'1', b'0', [Subfield::new(b'a', "T")])
+            .data_field(b"650", b' ', b'0', [Subfield::new(b'a', "B")])
+            .data_field(b"100", b'1', b' ', [Subfield::new(b'a', "N")])
+            .build();
+
+        record.sort_fields();
+
+        let tags: Vec<_> =
+            record.all_fields().map(|field| *field.tag()).collect();
+        assert_eq!(
+            tags,
+            [*b"001", *b"008", *b"100", *b"245", *b"650", *b"650"]
+        );
+
+        let subjects: Vec<_> = record
+            .fields(b"650")
+            .map(|field| field.subfields()[0].value())
+            .collect();
+        assert_eq!(subjects, [b"A", b"B"]);
+
+        let data = record.to_bytes();
+        assert_eq!(
+            &data[24..60],
+            b"001001000000008001900010100000600029"
+        );
+        assert_eq!(Record::from_bytes(&data)?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .control_field(b"008", "751101s1975    nyu")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "First")],
+            )
+            .data_field(
+                b"245",
+                b'0',
+                b'0',
+                [Subfield::new(b'a', "Second")],
+            )
+            .build();
+
+        let field = record.get(b"008").unwrap();
+        assert!(field.is_control_field());
+        assert_eq!(
+            field.as_control_field().unwrap().data(),
+            b"751101s1975    nyu"
+        );
+
+        let field =
+            record.get(b"245").unwrap().as_data_field().unwrap();
+        assert_eq!(field.ind1(), b'1');
+        assert_eq!(field.subfields()[0].value(), b"First");
+
+        assert_eq!(record.get(b"650"), None);
+        assert_eq!(record.get(b"24"), None);
+    }
+
+    #[test]
+    fn test_try_to_bytes() {
+        let mut record = RecordBuilder::new()
+            .control_field(b"001", "123\x1d456")
+            .data_field(
+                b"245",
+                b'0',
+                b'0',
+                [
+                    Subfield::new(b'a', "Title"),
+                    Subfield::new(b'b', "Sub\x1etitle"),
+                ],
+            )
+            .build();
+
+        assert_eq!(
+            record.try_to_bytes(),
+            Err(SerializeError::EmbeddedDelimiter {
+                tag: *b"001",
+                code: None
+            })
+        );
+
+        record.control_fields[0].data = b"123456".to_vec();
+        assert_eq!(
+            record.try_to_bytes(),
+            Err(SerializeError::EmbeddedDelimiter {
+                tag: *b"245",
+                code: Some(b'b')
+            })
+        );
+
+        assert_eq!(record.strip_delimiters(), 1);
+        assert_eq!(record.try_to_bytes(), Ok(record.to_bytes()));
+        assert_eq!(
+            record.first_subfield_value(b"245", b'b'),
+            Some(&b"Subtitle"[..])
+        );
+    }
+
+    #[test]
+    fn test_try_to_bytes_oversized() {
+        let note = |len| {
+            DataField::new(b"500", b' ', b' ')
+                .unwrap()
+                .with_subfield(b'a', vec![b'x'; len])
+                .unwrap()
+        };
+
+        let mut record = RecordBuilder::new().build();
+        record.add_field(note(10_000));
+        assert_eq!(
+            record.try_to_bytes(),
+            Err(SerializeError::FieldTooLong {
+                tag: *b"500",
+                length: 10_005
+            })
+        );
+
+        let mut record = RecordBuilder::new().build();
+        for _ in 0..11 {
+            record.add_field(note(9_500));
+        }
+        assert_eq!(
+            record.try_to_bytes(),
+            Err(SerializeError::RecordTooLong { length: 104_713 })
+        );
+
+        record.add_field(note(9_500));
+        assert_eq!(
+            record.try_to_bytes(),
+            Err(SerializeError::StartTooLarge {
+                tag: *b"500",
+                start: 104_555
+            })
+        );
+
+        let records = record.split_if_oversized(u32::MAX);
+        assert_eq!(records.len(), 2);
+        assert!(records
+            .iter()
+            .all(|record| record.try_to_bytes().is_ok()));
+    }
+
+    #[test]
+    fn test_subfield_values() {
+        let record = RecordBuilder::new()
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [
+                    Subfield::new(b'a', "Arithmetic"),
+                    Subfield::new(b'v', "Juvenile poetry"),
+                ],
+            )
+            .data_field(
+                b"600",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Name")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [
+                    Subfield::new(b'a', "Children's poetry"),
+                    Subfield::new(b'a', "American"),
+                ],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'7',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .build();
+
+        let values: Vec<_> =
+            record.subfield_values(b"650", b'a').collect();
+        assert_eq!(
+            values,
+            [
+                &b"Arithmetic"[..],
+                &b"Children's poetry"[..],
+                &b"American"[..],
+                &b"Poetry"[..],
+            ]
+        );
+
+        assert_eq!(record.subfield_values(b"650", b'v').count(), 1);
+        assert_eq!(record.subfield_values(b"650", b'x').count(), 0);
+        assert_eq!(record.subfield_values(b"651", b'a').count(), 0);
+    }
+
+    #[test]
+    fn test_is_deleted() -> anyhow::Result<()> {
+        let data = b"00048nam a2200037 c 4500001001000000\x1e\
+            123456789\x1e\x1d";
+
+        let mut record = Record::from_bytes(data)?;
+        assert!(!record.is_deleted());
+
+        record
+            .leader_mut()
+            .set_record_status(RecordStatus::Deleted)?;
+        assert!(record.is_deleted());
+
+        let record = Record::from_bytes(
+            &[&data[..5], b"c", &data[6..]].concat(),
+        )?;
+        assert!(!record.is_deleted());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_utf8_validation() -> anyhow::Result<()> {
+        let data =
+            b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+            123\xff56789\x1e00\x1faTit\xffe\x1e\x1d";
+        let parse = |utf8_validation| {
+            let options = ParseOptions {
+                utf8_validation,
+                ..Default::default()
+            };
+            Record::from_bytes_with(data, &options)
+                .map(|(record, _)| record)
+        };
+
+        let record = parse(Utf8Validation::Raw)?;
+        assert_eq!(
+            record.first_subfield_value(b"245", b'a'),
+            Some(&b"Tit\xffe"[..])
+        );
+        assert_eq!(record, Record::from_bytes(data)?);
+
+        let record = parse(Utf8Validation::Lossy)?;
+        assert_eq!(
+            record.first_subfield_value(b"245", b'a'),
+            Some("Tit\u{fffd}e".as_bytes())
+        );
+        assert_eq!(record.control_number(), Some(&b"123\xff56789"[..]));
+
+        assert!(matches!(
+            parse(Utf8Validation::Strict),
+            Err(ParseRecordError::InvalidUtf8 {
+                tag: [b'2', b'4', b'5'],
+                code: b'a',
+                position: 59,
+            })
+        ));
+
+        // MARC-8 records aren't checked.
+        let data = [&data[..9], b" ", &data[10..]].concat();
+        let options = ParseOptions {
+            utf8_validation: Utf8Validation::Strict,
+            ..Default::default()
+        };
+        assert!(Record::from_bytes_with(&data, &options).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_empty_fields() -> anyhow::Result<()> {
+        let mut record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .control_field(b"003", "")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Title")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', ""), Subfield::new(b'x', "")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Arithmetic")],
+            )
+            .data_field(b"700", b'1', b' ', [])
+            .build();
+
+        assert_eq!(record.remove_empty_fields(false), 2);
+
+        let subjects: Vec<_> =
+            record.subfield_values(b"650", b'a').collect();
+        assert_eq!(subjects, [&b"Poetry"[..], &b"Arithmetic"[..]]);
+        assert_eq!(record.fields(b"700").count(), 0);
+        assert_eq!(record.control_fields().count(), 2);
+
+        assert_eq!(record.remove_empty_fields(true), 1);
+        assert_eq!(record.control_fields().count(), 1);
+        assert_eq!(record.remove_empty_fields(true), 0);
+
+        let data = record.to_bytes();
+        assert_eq!(
+            record.leader().record_length() as usize,
+            data.len()
+        );
+        assert_eq!(Record::from_bytes(&data)?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_retain_fields() -> anyhow::Result<()> {
+        let mut record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .control_field(b"008", "020805s2002")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Title")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Poetry")],
+            )
+            .data_field(
+                b"500",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', "Note")],
+            )
+            .data_field(
+                b"700",
+                b'1',
+                b' ',
+                [Subfield::new(b'a', "Author")],
+            )
+            .build();
+
+        record.retain_fields(|field| field.tag() < b"600");
+
+        let tags: Vec<_> =
+            record.all_fields().map(|field| *field.tag()).collect();
+        assert_eq!(tags, [*b"001", *b"008", *b"245", *b"500"]);
+
+        let data = record.to_bytes();
+        assert_eq!(
+            record.leader().record_length() as usize,
+            data.len()
+        );
+        assert_eq!(Record::from_bytes(&data)?, record);
+
+        record.retain_fields(|field| matches!(field, Field::Data(_)));
+        assert_eq!(record.control_fields().count(), 0);
+        assert_eq!(record.data_fields().count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_preserve_layout() -> anyhow::Result<()> {
+        // The data field precedes the control field in the directory
+        // and is followed by a padding byte.
+        let data =
+            b"00071nam a2200049 c 4500245001000000001001000011\x1e\
+            00\x1faTitle\x1e 123456789\x1e\x1d";
+        let options = ParseOptions {
+            preserve_layout: true,
+            ..Default::default()
+        };
+
+        let (mut record, _) = Record::from_bytes_with(data, &options)?;
+        assert_eq!(record.to_bytes(), data);
+        assert_eq!(record.clone().to_bytes(), data);
+
+        let (other, _) =
+            Record::from_bytes_with(data, &Default::default())?;
+        assert_eq!(record, other);
+        assert_ne!(other.to_bytes(), data);
+
+        record
+            .leader_mut()
+            .set_record_status(RecordStatus::Corrected)?;
+        assert_eq!(
+            record.to_bytes(),
+            b"00070cam a2200049 c 4500245001000000001001000010\x1e\
+            00\x1faTitle\x1e123456789\x1e\x1d"
+        );
+
+        // Records with recovered violations aren't preserved.
+        let options = ParseOptions {
+            lenient: true,
+            ..options
+        };
+        let (record, warnings) =
+            Record::from_bytes_with(&data[..70], &options)?;
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(record.to_bytes().last(), Some(&RECORD_TERMINATOR));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_bytes_prefix() -> anyhow::Result<()> {
+        let first = Record::from_bytes(DATA)?;
+        let second = b"00048nam a2200037 c 4500001001000000\x1e\
+            987654321\x1e\x1d";
+        let data = [DATA, second].concat();
+
+        let (record, len) = Record::from_bytes_prefix(&data)?;
+        assert_eq!(record, first);
+        assert_eq!(len, record.leader().record_length() as usize);
+        assert_eq!(len, DATA.len());
+
+        let (record, len) = Record::from_bytes_prefix(&data[len..])?;
+        assert_eq!(record.control_number(), Some(&b"987654321"[..]));
+        assert_eq!(len, 48);
+
+        assert!(matches!(
+            Record::from_bytes_prefix(&DATA[..100]),
+            Err(ParseRecordError::Truncated)
+        ));
+        assert!(matches!(
+            Record::from_bytes_prefix(b"002"),
+            Err(ParseRecordError::Truncated)
+        ));
+        assert!(matches!(
+            Record::from_bytes_prefix(b"00x02nam"),
+            Err(ParseRecordError::Leader { position: 0, .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_indicators() {
+        let mut record = RecordBuilder::new()
+            .data_field(
+                b"245",
+                b'#',
+                b'#',
+                [Subfield::new(b'a', "Title #1")],
+            )
+            .data_field(
+                b"500",
+                b'|',
+                b'_',
+                [Subfield::new(b'a', "Note")],
+            )
+            .data_field(
+                b"650",
+                b' ',
+                b'0',
+                [Subfield::new(b'a', "Topic")],
+            )
+            .build();
+
+        record.normalize_indicators();
+
+        let indicators: Vec<_> = record
+            .data_fields()
+            .map(|field| (field.ind1(), field.ind2()))
+            .collect();
+        assert_eq!(
+            indicators,
+            [(b' ', b' '), (b' ', b' '), (b' ', b'0')]
+        );
+        assert_eq!(
+            record.first_subfield_value(b"245", b'a'),
+            Some(&b"Title #1"[..])
+        );
+    }
+
+    mod proptests {
+        use proptest::prelude::*;
+
+        use crate::{Record, RecordBuilder, Subfield};
+
+        const INDICATORS: &[u8] =
+            b" 0123456789abcdefghijklmnopqrstuvwxyz";
+        const CODES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+        /// Values of any bytes except the delimiters.
+        fn value() -> impl Strategy<Value = Vec<u8>> {
+            prop::collection::vec(0x20u8..=0xff, 0..200)
+        }
+
+        fn control_field() -> impl Strategy<Value = ([u8; 3], Vec<u8>)>
+        {
+            (b'1'..=b'9', value())
+                .prop_map(|(digit, data)| ([b'0', b'0', digit], data))
+        }
+
+        fn data_field(
+        ) -> impl Strategy<Value = ([u8; 3], u8, u8, Vec<Subfield>)>
+        {
+            let subfield = (prop::sample::select(CODES), value())
+                .prop_map(|(code, value)| Subfield::new(code, value));
+
+            (
+                10u16..1000,
+                prop::sample::select(INDICATORS),
+                prop::sample::select(INDICATORS),
+                prop::collection::vec(subfield, 0..6),
+            )
+                .prop_map(
+                    |(tag, ind1, ind2, subfields)| {
+                        let tag = [
+                            b'0' + (tag / 100) as u8,
+                            b'0' + (tag / 10 % 10) as u8,
+                            b'0' + (tag % 10) as u8,
+                        ];
+
+                        (tag, ind1, ind2, subfields)
+                    },
+                )
+        }
+
+        fn record() -> impl Strategy<Value = Record> {
+            (
+                prop::collection::vec(control_field(), 0..4),
+                prop::collection::vec(data_field(), 0..16),
+            )
+                .prop_map(
+                    |(control_fields, data_fields)| {
+                        let mut builder = RecordBuilder::new();
+                        for (tag, data) in control_fields {
+                            builder = builder.control_field(tag, data);
+                        }
+
+                        for (tag, ind1, ind2, subfields) in data_fields
+                        {
+                            builder = builder
+                                .data_field(tag, ind1, ind2, subfields);
+                        }
+
+                        builder.build()
+                    },
+                )
+        }
+
+        proptest! {
+            #![proptest_config(ProptestConfig::with_cases(64))]
+
+            #[test]
+            fn test_roundtrip(record in record()) {
+                let data = record.to_bytes();
+                prop_assert_eq!(
+                    data.len(),
+                    record.leader().record_length() as usize
+                );
+                prop_assert_eq!(data.len() as u32, record.bytes_len());
+                prop_assert_eq!(Record::from_bytes(&data).unwrap(), record);
+            }
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use crate::directory::parse_directory_entry;
+use crate::error::ParseRecordError;
+use crate::leader::LEADER_LEN;
+use crate::record::{is_control_tag, tag_matches};
+use crate::{
+    DirectoryEntry, Leader, Record, FIELD_TERMINATOR,
+    SUBFIELD_DELIMITER,
+};
+
+/// A zero-copy view of a record, which borrows the underlying data.
+///
+/// Only the leader and the directory are validated on construction;
+/// the fields are sliced out of the data lazily on iteration, without
+/// any allocation.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::RecordRef;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let record = RecordRef::from_bytes(
+///         b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+///         123456789\x1e00\x1faTitle\x1e\x1d",
+///     )?;
+///
+///     assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+///
+///     let field = record.fields().nth(1).unwrap();
+///     assert_eq!(field.tag(), b"245");
+///     assert_eq!(
+///         field.subfields().next(),
+///         Some((b'a', &b"Title"[..]))
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RecordRef<'a> {
+    data: &'a [u8],
+    leader: Leader,
+}
+
+impl<'a> RecordRef<'a> {
+    /// Creates a record view from a byte slice.
+    ///
+    /// Only the leader and the directory are parsed; each directory
+    /// entry must lie within the bounds of the data.
+    pub fn from_bytes(
+        data: &'a [u8],
+    ) -> Result<Self, ParseRecordError> {
+        let leader = Leader::from_bytes(data)?;
+        let base_address = leader.base_address as usize;
+        let entry_len = leader.entry_map.entry_len();
+
+        match data.get(LEADER_LEN..base_address) {
+            Some([directory @ .., FIELD_TERMINATOR])
+                if directory.len() % entry_len == 0 => {}
+            _ => {
+                return Err(ParseRecordError::InvalidDirectory {
+                    position: LEADER_LEN,
+                })
+            }
+        }
+
+        let body_len = data.len() - base_address;
+        for (idx, chunk) in data[LEADER_LEN..base_address - 1]
+            .chunks_exact(entry_len)
+            .enumerate()
+        {
+            let (_, entry) =
+                parse_directory_entry(&leader.entry_map)(chunk)
+                    .map_err(|_| {
+                        ParseRecordError::InvalidDirectory {
+                            position: LEADER_LEN + idx * entry_len,
+                        }
+                    })?;
+
+            if (entry.start + entry.length) as usize > body_len {
+                return Err(ParseRecordError::DirectoryOutOfBounds {
+                    tag: entry.tag,
+                    start: entry.start,
+                    length: entry.length,
+                });
+            }
+        }
+
+        Ok(Self { data, leader })
+    }
+
+    /// Returns the leader of the record.
+    pub fn leader(&self) -> &Leader {
+        &self.leader
+    }
+
+    /// Returns an iterator over the directory entries.
+    pub fn entries(&self) -> impl Iterator<Item = DirectoryEntry> + 'a {
+        let entry_map = self.leader.entry_map;
+        let base_address = self.leader.base_address as usize;
+
+        self.data[LEADER_LEN..base_address - 1]
+            .chunks_exact(entry_map.entry_len())
+            .filter_map(move |chunk| {
+                parse_directory_entry(&entry_map)(chunk)
+                    .ok()
+                    .map(|(_, entry)| entry)
+            })
+    }
+
+    /// Returns an iterator over all (control and data) fields, in
+    /// directory order.
+    pub fn fields(&self) -> impl Iterator<Item = FieldRef<'a>> + 'a {
+        let body = &self.data[self.leader.base_address as usize..];
+
+        // The bounds of all entries are checked on construction.
+        self.entries().filter_map(move |entry| {
+            let start = entry.start as usize;
+            let data =
+                body.get(start..start + entry.length as usize)?;
+
+            Some(FieldRef {
+                tag: entry.tag,
+                data: data
+                    .strip_suffix(&[FIELD_TERMINATOR])
+                    .unwrap_or(data),
+            })
+        })
+    }
+
+    /// Returns an iterator over all fields with the given tag, in
+    /// directory order. An `X` in the tag matches any character.
+    pub fn fields_by_tag(
+        &self,
+        tag: impl AsRef<[u8]> + 'a,
+    ) -> impl Iterator<Item = FieldRef<'a>> + 'a {
+        self.fields()
+            .filter(move |field| tag_matches(tag.as_ref(), &field.tag))
+    }
+
+    /// Returns the control number of the record, i.e. the data of the
+    /// first `001` field, or `None` if the record has no such field.
+    pub fn control_number(&self) -> Option<&'a [u8]> {
+        self.fields_by_tag(b"001").next().map(|field| field.data)
+    }
+
+    /// Parses the whole record into an owned [Record].
+    pub fn to_record(&self) -> Result<Record, ParseRecordError> {
+        Record::from_bytes(self.data)
+    }
+}
+
+/// A zero-copy view of a (control or data) field of a [RecordRef].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldRef<'a> {
+    tag: [u8; 3],
+    data: &'a [u8],
+}
+
+impl<'a> FieldRef<'a> {
+    /// Returns the tag of the field.
+    pub fn tag(&self) -> &[u8; 3] {
+        &self.tag
+    }
+
+    /// Returns `true` if the field is a control field (`00X`).
+    pub fn is_control_field(&self) -> bool {
+        is_control_tag(&self.tag)
+    }
+
+    /// Returns the raw data of the field, without the field
+    /// terminator. For control fields, this is the field data.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns the indicators of a data field, or `None` for control
+    /// fields or if the field is too short.
+    pub fn indicators(&self) -> Option<(u8, u8)> {
+        match self.data {
+            [ind1, ind2, ..] if !self.is_control_field() => {
+                Some((*ind1, *ind2))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the subfields of a data field as
+    /// pairs of subfield code and value. For control fields, the
+    /// iterator is empty.
+    pub fn subfields(
+        &self,
+    ) -> impl Iterator<Item = (u8, &'a [u8])> + 'a {
+        let data = match (self.indicators(), self.data.get(2..)) {
+            (Some(_), Some(data)) => data,
+            _ => &[],
+        };
+
+        data.split(|&byte| byte == SUBFIELD_DELIMITER)
+            .skip(1)
+            .filter_map(|subfield| match subfield {
+                [code, value @ ..] => Some((*code, value)),
+                [] => None,
+            })
+    }
+
+    /// Returns an iterator over the values of all subfields with the
+    /// given code.
+    pub fn subfield_values(
+        &self,
+        code: u8,
+    ) -> impl Iterator<Item = &'a [u8]> + 'a {
+        self.subfields()
+            .filter(move |(c, _)| *c == code)
+            .map(|(_, value)| value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: &[u8] = include_bytes!("../tests/data/sandburg.mrc");
+
+    #[test]
+    fn test_record_ref() -> anyhow::Result<()> {
+        let record = RecordRef::from_bytes(DATA)?;
+        assert_eq!(record.leader().record_length(), 202);
+        assert_eq!(record.entries().count(), 5);
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+
+        let tags = record.fields().map(|field| *field.tag());
+        assert_eq!(
+            tags.collect::<Vec<_>>(),
+            vec![*b"001", *b"003", *b"245", *b"650", *b"650"]
+        );
+
+        let field = record.fields_by_tag(b"003").next().unwrap();
+        assert!(field.is_control_field());
+        assert_eq!(field.data(), b"DE-101");
+        assert_eq!(field.indicators(), None);
+        assert_eq!(field.subfields().count(), 0);
+
+        let field = record.fields_by_tag(b"245").next().unwrap();
+        assert!(!field.is_control_field());
+        assert_eq!(field.indicators(), Some((b'0', b'0')));
+        assert_eq!(
+            field.subfields().collect::<Vec<_>>(),
+            vec![
+                (b'a', &b"Arithmetic /"[..]),
+                (b'c', &b"Carl Sandburg."[..])
+            ]
+        );
+
+        let values = record
+            .fields_by_tag(b"650")
+            .flat_map(|field| field.subfield_values(b'a'))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![
+                &b"Arithmetic"[..],
+                &b"Children's poetry, American."[..]
+            ]
+        );
+
+        assert_eq!(record.to_record()?, Record::from_bytes(DATA)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_ref_invalid() {
+        assert!(matches!(
+            RecordRef::from_bytes(b"00070nam"),
+            Err(ParseRecordError::Leader { .. })
+        ));
+        assert!(matches!(
+            RecordRef::from_bytes(b"00070nam a2200049 c 4500"),
+            Err(ParseRecordError::InvalidDirectory { position: 24 })
+        ));
+        assert!(matches!(
+            RecordRef::from_bytes(
+                b"00070nam a2200049 c 4500001001000000245001000x10\x1e\
+                123456789\x1e00\x1faTitle\x1e\x1d"
+            ),
+            Err(ParseRecordError::InvalidDirectory { position: 36 })
+        ));
+        assert!(matches!(
+            RecordRef::from_bytes(
+                b"00070nam a2200049 c 4500001001000000245001000010\x1e\
+                123456789\x1e00\x1faTi"
+            ),
+            Err(ParseRecordError::DirectoryOutOfBounds {
+                tag: [b'2', b'4', b'5'],
+                start: 10,
+                length: 10
+            })
+        ));
+    }
+}
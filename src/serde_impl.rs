@@ -0,0 +1,145 @@
+//! Helpers to (de-)serialize records with serde.
+//!
+//! Tags, indicators and subfield codes are known to be ASCII and are
+//! represented as strings; field data and subfield values are
+//! represented as byte arrays.
+
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::{Serialize, Serializer};
+
+use crate::Leader;
+
+impl Serialize for Leader {
+    fn serialize<S: Serializer>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Leader {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        struct LeaderVisitor;
+
+        impl Visitor<'_> for LeaderVisitor {
+            type Value = Leader;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a 24-character leader string")
+            }
+
+            fn visit_str<E: de::Error>(
+                self,
+                value: &str,
+            ) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(LeaderVisitor)
+    }
+}
+
+/// (De-)serializes a tag as a string of three ASCII characters.
+pub(crate) mod tag {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        tag: &[u8; 3],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match std::str::from_utf8(tag) {
+            Ok(tag) => serializer.serialize_str(tag),
+            Err(_) => Err(serde::ser::Error::custom("non-ASCII tag")),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; 3], D::Error> {
+        let tag = String::deserialize(deserializer)?;
+        match tag.as_bytes() {
+            [a, b, c] if tag.is_ascii() => Ok([*a, *b, *c]),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Str(&tag),
+                &"a tag of three ASCII characters",
+            )),
+        }
+    }
+}
+
+/// (De-)serializes an indicator or a subfield code as a single ASCII
+/// character.
+pub(crate) mod code {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        code: &u8,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_char(*code as char)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<u8, D::Error> {
+        let code = char::deserialize(deserializer)?;
+        if !code.is_ascii() {
+            return Err(de::Error::invalid_value(
+                de::Unexpected::Char(code),
+                &"an ASCII character",
+            ));
+        }
+
+        Ok(code as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{ControlField, Record, Subfield};
+
+    #[test]
+    fn test_serde_roundtrip() -> anyhow::Result<()> {
+        let record = Record::from_bytes(include_bytes!(
+            "../tests/data/arithmetic.mrc"
+        ))?;
+
+        let value = serde_json::to_value(&record)?;
+        assert_eq!(serde_json::from_value::<Record>(value)?, record);
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_representation() -> anyhow::Result<()> {
+        let field = ControlField::new(b"001", "12");
+        assert_eq!(
+            serde_json::to_value(&field)?,
+            json!({ "tag": "001", "data": [49, 50] })
+        );
+
+        let subfield = Subfield::new(b'a', "A");
+        assert_eq!(
+            serde_json::to_value(&subfield)?,
+            json!({ "code": "a", "value": [65] })
+        );
+
+        assert!(serde_json::from_value::<ControlField>(
+            json!({ "tag": "0001", "data": [] })
+        )
+        .is_err());
+        assert!(serde_json::from_value::<Subfield>(
+            json!({ "code": "ä", "value": [] })
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
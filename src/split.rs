@@ -0,0 +1,253 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::leader::{LEADER_LEN, MAX_RECORD_LEN};
+use crate::record::{FieldOrder, Original};
+use crate::tags::is_repeatable;
+use crate::{ControlField, DataField, Record};
+
+impl Record {
+    /// Splits the record into records whose ISO 2709 representation
+    /// doesn't exceed `max` bytes, e.g. to stay within the maximum
+    /// record length of `99999`.
+    ///
+    /// A record which doesn't exceed the limit is returned as is.
+    /// Otherwise, the first record keeps all control fields and the
+    /// non-repeatable data fields (see [tags::is_repeatable]), and the
+    /// repeatable data fields are distributed in order across the
+    /// first and the following continuation records. Each continuation
+    /// record shares the leader and the control number (`001`) of the
+    /// record. A single field which exceeds the limit on its own gets a
+    /// continuation record of its own; if it exceeds the directory
+    /// limits as well, that record still fails [Record::try_to_bytes].
+    ///
+    /// A limit above the maximum record length of `99999` is lowered
+    /// to it.
+    ///
+    /// [tags::is_repeatable]: crate::tags::is_repeatable
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .control_field(b"001", "123456789")
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .data_field(b"500", b' ', b' ', [Subfield::new(b'a', "A")])
+    ///         .data_field(b"500", b' ', b' ', [Subfield::new(b'a', "B")])
+    ///         .build();
+    ///
+    ///     let records = record.split_if_oversized(100);
+    ///     assert_eq!(records.len(), 2);
+    ///     assert_eq!(records[0].fields(b"500").count(), 1);
+    ///     assert_eq!(
+    ///         records[1].control_number(),
+    ///         Some(&b"123456789"[..])
+    ///     );
+    ///     assert!(records.iter().all(|record| record.bytes_len() <= 100));
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn split_if_oversized(&self, max: u32) -> Vec<Record> {
+        let max = max.min(MAX_RECORD_LEN);
+        if self.bytes_len() <= max {
+            return vec![self.clone()];
+        }
+
+        let max = max as usize;
+        let entry_len = self.leader.entry_map.entry_len();
+        let empty_len = LEADER_LEN + 2;
+
+        let control_number: Vec<ControlField> = self
+            .control_fields
+            .iter()
+            .filter(|field| &field.tag == b"001")
+            .cloned()
+            .collect();
+        let continuation_len = empty_len
+            + control_number
+                .iter()
+                .map(|field| entry_len + field.encoded_len())
+                .sum::<usize>();
+
+        // The first record holds the control fields and the
+        // non-repeatable data fields in any case.
+        let mut len = empty_len
+            + self
+                .control_fields
+                .iter()
+                .map(|field| entry_len + field.encoded_len())
+                .sum::<usize>()
+            + self
+                .data_fields
+                .iter()
+                .filter(|field| !is_repeatable(field.tag))
+                .map(|field| entry_len + field.encoded_len())
+                .sum::<usize>();
+
+        let mut parts: Vec<Vec<DataField>> = vec![vec![]];
+        for field in self.data_fields.iter() {
+            if !is_repeatable(field.tag) {
+                parts[0].push(field.clone());
+                continue;
+            }
+
+            let field_len = entry_len + field.encoded_len();
+            let current = parts.last().unwrap();
+            if len + field_len > max
+                && (parts.len() == 1 || !current.is_empty())
+            {
+                parts.push(vec![]);
+                len = continuation_len;
+            }
+
+            len += field_len;
+            parts.last_mut().unwrap().push(field.clone());
+        }
+
+        parts
+            .into_iter()
+            .enumerate()
+            .map(|(idx, data_fields)| {
+                let control_fields = match idx {
+                    0 => self.control_fields.clone(),
+                    _ => control_number.clone(),
+                };
+
+                let mut record = Record {
+                    leader: self.leader,
+                    control_fields,
+                    data_fields,
+                    length_overflowed: false,
+                    missing_terminator: false,
+                    original: Original::default(),
+                    directory: Original::default(),
+                    field_order: FieldOrder::default(),
+                };
+
+                record.update_leader();
+                record
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use crate::{Record, RecordBuilder, Subfield};
+
+    #[test]
+    fn test_split_if_oversized() -> anyhow::Result<()> {
+        let note = String::from("x").repeat(1000);
+        let mut builder = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .control_field(b"008", "850423s1985")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Title")],
+            );
+        for _ in 0..250 {
+            builder = builder.data_field(
+                b"500",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', note.as_str())],
+            );
+        }
+        let record = builder
+            .data_field(
+                b"100",
+                b'1',
+                b' ',
+                [Subfield::new(b'a', "Author")],
+            )
+            .build();
+        assert!(record.bytes_len() > 99999);
+
+        let records = record.split_if_oversized(99999);
+        assert_eq!(records.len(), 3);
+
+        for record in records.iter() {
+            assert!(record.bytes_len() <= 99999);
+            assert_eq!(
+                record.control_number(),
+                Some(&b"123456789"[..])
+            );
+
+            let data = record.to_bytes();
+            assert_eq!(
+                record.leader().record_length() as usize,
+                data.len()
+            );
+            assert_eq!(&Record::from_bytes(&data)?, record);
+        }
+
+        let first = &records[0];
+        assert_eq!(first.control_fields().count(), 2);
+        assert_eq!(first.fields(b"245").count(), 1);
+        assert_eq!(first.fields(b"100").count(), 1);
+        assert_eq!(records[1].control_fields().count(), 1);
+        assert_eq!(records[1].fields(b"245").count(), 0);
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| record.fields(b"500").count())
+                .sum::<usize>(),
+            250
+        );
+
+        // The limit can't exceed the maximum record length.
+        assert!(record.bytes_len() > 99_999);
+        let records = record.split_if_oversized(record.bytes_len());
+        assert_eq!(records, record.split_if_oversized(99_999));
+
+        let first = &records[0];
+        let records = first.split_if_oversized(first.bytes_len());
+        assert_eq!(records.len(), 1);
+        assert_eq!(&records[0], first);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_if_oversized_large_field() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'1',
+                b'0',
+                [Subfield::new(b'a', "Title")],
+            )
+            .data_field(b"500", b' ', b' ', [Subfield::new(b'a', "A")])
+            .data_field(
+                b"500",
+                b' ',
+                b' ',
+                [Subfield::new(b'a', String::from("x").repeat(200))],
+            )
+            .data_field(b"500", b' ', b' ', [Subfield::new(b'a', "B")])
+            .build();
+
+        let records = record.split_if_oversized(150);
+        let counts: Vec<_> = records
+            .iter()
+            .map(|record| record.fields(b"500").count())
+            .collect();
+        assert_eq!(counts, [1, 1, 1]);
+        assert!(records[1].bytes_len() > 150);
+    }
+}
@@ -0,0 +1,218 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use nom::bytes::complete::{tag, take, take_till};
+use nom::combinator::map;
+use nom::sequence::{preceded, tuple};
+
+use crate::error::ParseRecordError;
+use crate::leader::ParseResult;
+use crate::{FIELD_TERMINATOR, SUBFIELD_DELIMITER};
+
+/// A subfield of a data field.
+///
+/// A subfield consists of a single subfield code and the subfield's
+/// data (value).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Subfield {
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serde_impl::code")
+    )]
+    pub(crate) code: u8,
+    pub(crate) value: Vec<u8>,
+}
+
+impl Subfield {
+    /// Creates a new subfield.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Subfield;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let subfield = Subfield::new(b'a', "Arithmetic /");
+    ///     assert_eq!(subfield.code(), b'a');
+    ///     assert_eq!(subfield.value(), b"Arithmetic /");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn new(code: u8, value: impl Into<Vec<u8>>) -> Self {
+        Self {
+            code,
+            value: value.into(),
+        }
+    }
+
+    /// Returns the code of the subfield.
+    pub fn code(&self) -> u8 {
+        self.code
+    }
+
+    /// Returns the value of the subfield.
+    pub fn value(&self) -> &[u8] {
+        &self.value
+    }
+
+    /// Returns the value of the subfield without trailing ISBD
+    /// punctuation (`/`, `:`, `;`, `,` and `.`) and without leading
+    /// or trailing blanks. The stored value is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Subfield;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let subfield = Subfield::new(b'a', "Arithmetic /");
+    ///     assert_eq!(subfield.trimmed_value(), b"Arithmetic");
+    ///     assert_eq!(subfield.value(), b"Arithmetic /");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn trimmed_value(&self) -> &[u8] {
+        let value = self.value.trim_ascii_start();
+        let end = value
+            .iter()
+            .rposition(|b| {
+                !matches!(b, b'/' | b':' | b';' | b',' | b'.' | b' ')
+            })
+            .map_or(0, |pos| pos + 1);
+
+        &value[..end]
+    }
+
+    /// Writes the subfield delimiter, the code and the value.
+    pub(crate) fn write_to(&self, out: &mut Vec<u8>) {
+        out.push(SUBFIELD_DELIMITER);
+        out.push(self.code);
+        out.extend_from_slice(&self.value);
+    }
+}
+
+impl fmt::Display for Subfield {
+    /// Formats the subfield for humans as `$` followed by the code, a
+    /// blank and the value, which is decoded lossily as UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Subfield;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let subfield = Subfield::new(b'a', "Arithmetic /");
+    ///     assert_eq!(subfield.to_string(), "$a Arithmetic /");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "${} {}",
+            self.code as char,
+            String::from_utf8_lossy(&self.value)
+        )
+    }
+}
+
+/// Parse a subfield, which is introduced by the subfield delimiter,
+/// followed by the subfield code and the value.
+///
+/// The subfield code consists of `code_len` bytes (`1` in MARC 21).
+/// The first byte is the code, further code bytes are kept as the
+/// beginning of the value, so no byte is lost. A subfield without a
+/// code gets a blank code.
+pub(crate) fn parse_subfield<'a>(
+    code_len: usize,
+) -> impl FnMut(&'a [u8]) -> ParseResult<'a, Subfield, ParseRecordError>
+{
+    map(
+        preceded(
+            tag(&[SUBFIELD_DELIMITER][..]),
+            tuple((
+                take(code_len.min(1)),
+                take_till(|b| {
+                    b == SUBFIELD_DELIMITER || b == FIELD_TERMINATOR
+                }),
+            )),
+        ),
+        |(code, value): (&[u8], &[u8])| {
+            Subfield::new(code.first().copied().unwrap_or(b' '), value)
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use nom_test_helpers::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_subfield() {
+        assert_finished_and_eq!(
+            parse_subfield(1)(b"\x1faArithmetic /"),
+            Subfield::new(b'a', "Arithmetic /")
+        );
+        assert_done_and_eq!(
+            parse_subfield(1)(b"\x1faSan Diego :\x1fbHarcourt Brace,"),
+            Subfield::new(b'a', "San Diego :")
+        );
+        assert_finished_and_eq!(
+            parse_subfield(1)(b"\x1fa"),
+            Subfield::new(b'a', "")
+        );
+
+        assert_error!(parse_subfield(1)(b"aArithmetic /"));
+        assert_error!(parse_subfield(1)(b"\x1f"));
+    }
+
+    #[test]
+    fn test_trimmed_value() {
+        assert_eq!(
+            Subfield::new(b'a', "Title :").trimmed_value(),
+            b"Title"
+        );
+        assert_eq!(
+            Subfield::new(b'a', "Author,").trimmed_value(),
+            b"Author"
+        );
+        assert_eq!(
+            Subfield::new(b'c', " Carl Sandburg ; ").trimmed_value(),
+            b"Carl Sandburg"
+        );
+        assert_eq!(
+            Subfield::new(b'a', "A.B. : c/d").trimmed_value(),
+            b"A.B. : c/d"
+        );
+        assert_eq!(Subfield::new(b'a', " / ").trimmed_value(), b"");
+        assert_eq!(Subfield::new(b'a', "").trimmed_value(), b"");
+    }
+
+    #[test]
+    fn test_display() {
+        let subfield = Subfield::new(b'a', "Arithmetic /");
+        assert_eq!(subfield.to_string(), "$a Arithmetic /");
+
+        let subfield = Subfield::new(b'4', "aut");
+        assert_eq!(format!("{subfield}"), "$4 aut");
+
+        let subfield = Subfield::new(b'a', &b"Tit\xffe"[..]);
+        assert_eq!(subfield.to_string(), "$a Tit\u{fffd}e");
+
+        let subfield = Subfield::new(b'z', "");
+        assert_eq!(subfield.to_string(), "$z ");
+    }
+}
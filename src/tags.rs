@@ -0,0 +1,203 @@
+//! Names of the fields of the MARC 21 Format for Bibliographic Data.
+
+/// Returns the name of the field with the given tag, as defined by
+/// the MARC 21 Format for Bibliographic Data, or `None` if the tag is
+/// unknown.
+///
+/// Only the common fields are covered; local (`9XX`) and obsolete
+/// fields return `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::tags::field_name;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     assert_eq!(field_name(b"245"), Some("Title Statement"));
+///     assert_eq!(field_name(b"999"), None);
+///
+///     Ok(())
+/// }
+/// ```
+pub fn field_name(tag: impl AsRef<[u8]>) -> Option<&'static str> {
+    let name = match tag.as_ref() {
+        b"001" => "Control Number",
+        b"003" => "Control Number Identifier",
+        b"005" => "Date and Time of Latest Transaction",
+        b"006" => {
+            "Fixed-Length Data Elements-Additional Material \
+            Characteristics"
+        }
+        b"007" => "Physical Description Fixed Field",
+        b"008" => "Fixed-Length Data Elements",
+        b"010" => "Library of Congress Control Number",
+        b"015" => "National Bibliography Number",
+        b"016" => "National Bibliographic Agency Control Number",
+        b"020" => "International Standard Book Number",
+        b"022" => "International Standard Serial Number",
+        b"024" => "Other Standard Identifier",
+        b"035" => "System Control Number",
+        b"040" => "Cataloging Source",
+        b"041" => "Language Code",
+        b"043" => "Geographic Area Code",
+        b"050" => "Library of Congress Call Number",
+        b"082" => "Dewey Decimal Classification Number",
+        b"084" => "Other Classification Number",
+        b"100" => "Main Entry-Personal Name",
+        b"110" => "Main Entry-Corporate Name",
+        b"111" => "Main Entry-Meeting Name",
+        b"130" => "Main Entry-Uniform Title",
+        b"240" => "Uniform Title",
+        b"245" => "Title Statement",
+        b"246" => "Varying Form of Title",
+        b"250" => "Edition Statement",
+        b"260" => "Publication, Distribution, etc. (Imprint)",
+        b"264" => {
+            "Production, Publication, Distribution, Manufacture, \
+            and Copyright Notice"
+        }
+        b"300" => "Physical Description",
+        b"336" => "Content Type",
+        b"337" => "Media Type",
+        b"338" => "Carrier Type",
+        b"490" => "Series Statement",
+        b"500" => "General Note",
+        b"504" => "Bibliography, Etc. Note",
+        b"505" => "Formatted Contents Note",
+        b"520" => "Summary, Etc.",
+        b"546" => "Language Note",
+        b"600" => "Subject Added Entry-Personal Name",
+        b"610" => "Subject Added Entry-Corporate Name",
+        b"611" => "Subject Added Entry-Meeting Name",
+        b"630" => "Subject Added Entry-Uniform Title",
+        b"650" => "Subject Added Entry-Topical Term",
+        b"651" => "Subject Added Entry-Geographic Name",
+        b"655" => "Index Term-Genre/Form",
+        b"700" => "Added Entry-Personal Name",
+        b"710" => "Added Entry-Corporate Name",
+        b"711" => "Added Entry-Meeting Name",
+        b"730" => "Added Entry-Uniform Title",
+        b"773" => "Host Item Entry",
+        b"776" => "Additional Physical Form Entry",
+        b"800" => "Series Added Entry-Personal Name",
+        b"830" => "Series Added Entry-Uniform Title",
+        b"856" => "Electronic Location and Access",
+        b"880" => "Alternate Graphic Representation",
+        _ => return None,
+    };
+
+    Some(name)
+}
+
+/// Returns `false` if the field with the given tag isn't repeatable
+/// according to the MARC 21 Format for Bibliographic Data, and `true`
+/// otherwise.
+///
+/// Unknown and local (`9XX`) fields are assumed to be repeatable.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::tags::is_repeatable;
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     assert!(!is_repeatable(b"245"));
+///     assert!(is_repeatable(b"650"));
+///     assert!(is_repeatable(b"999"));
+///
+///     Ok(())
+/// }
+/// ```
+pub fn is_repeatable(tag: impl AsRef<[u8]>) -> bool {
+    !matches!(
+        tag.as_ref(),
+        b"001"
+            | b"003"
+            | b"005"
+            | b"008"
+            | b"010"
+            | b"018"
+            | b"036"
+            | b"038"
+            | b"040"
+            | b"042"
+            | b"043"
+            | b"044"
+            | b"045"
+            | b"066"
+            | b"100"
+            | b"110"
+            | b"111"
+            | b"130"
+            | b"240"
+            | b"243"
+            | b"245"
+            | b"254"
+            | b"256"
+            | b"263"
+            | b"306"
+            | b"310"
+            | b"357"
+            | b"507"
+            | b"514"
+            | b"841"
+            | b"842"
+            | b"844"
+            | b"882"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_name() {
+        assert_eq!(field_name(b"001"), Some("Control Number"));
+        assert_eq!(
+            field_name(b"008"),
+            Some("Fixed-Length Data Elements")
+        );
+        assert_eq!(
+            field_name(b"020"),
+            Some("International Standard Book Number")
+        );
+        assert_eq!(
+            field_name(b"100"),
+            Some("Main Entry-Personal Name")
+        );
+        assert_eq!(field_name(b"245"), Some("Title Statement"));
+        assert_eq!(
+            field_name(b"264"),
+            Some(
+                "Production, Publication, Distribution, Manufacture, and \
+                 Copyright Notice"
+            )
+        );
+        assert_eq!(field_name(b"300"), Some("Physical Description"));
+        assert_eq!(
+            field_name(b"650"),
+            Some("Subject Added Entry-Topical Term")
+        );
+        assert_eq!(
+            field_name(b"700"),
+            Some("Added Entry-Personal Name")
+        );
+
+        assert_eq!(field_name(b"999"), None);
+        assert_eq!(field_name(b"24"), None);
+    }
+
+    #[test]
+    fn test_is_repeatable() {
+        for tag in [b"001", b"008", b"100", b"245", b"310"] {
+            assert!(!is_repeatable(tag));
+        }
+
+        for tag in [b"006", b"020", b"250", b"500", b"650", b"999"] {
+            assert!(is_repeatable(tag));
+        }
+    }
+}
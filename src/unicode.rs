@@ -0,0 +1,126 @@
+use alloc::string::String;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{DataField, Subfield};
+
+impl Subfield {
+    /// Returns `true` if both subfields have the same code and their
+    /// values are equal after Unicode normalization (NFC).
+    ///
+    /// The values are decoded as UTF-8; invalid sequences are
+    /// replaced by `U+FFFD`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::Subfield;
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let nfc = Subfield::new(b'a', "caf\u{e9}");
+    ///     let nfd = Subfield::new(b'a', "cafe\u{301}");
+    ///
+    ///     assert_ne!(nfc, nfd);
+    ///     assert!(nfc.eq_normalized(&nfd));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn eq_normalized(&self, other: &Subfield) -> bool {
+        self.code == other.code
+            && String::from_utf8_lossy(&self.value)
+                .nfc()
+                .eq(String::from_utf8_lossy(&other.value).nfc())
+    }
+}
+
+impl DataField {
+    /// Returns `true` if both fields have the same tag, indicators and
+    /// subfield codes, and all subfield values are equal after
+    /// Unicode normalization (NFC).
+    ///
+    /// See [Subfield::eq_normalized] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "caf\u{e9}")],
+    ///         )
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'0',
+    ///             [Subfield::new(b'a', "cafe\u{301}")],
+    ///         )
+    ///         .build();
+    ///
+    ///     let mut fields = record.fields(b"245");
+    ///     let (nfc, nfd) =
+    ///         (fields.next().unwrap(), fields.next().unwrap());
+    ///     assert!(nfc.eq_normalized(nfd));
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn eq_normalized(&self, other: &DataField) -> bool {
+        self.tag == other.tag
+            && self.ind1 == other.ind1
+            && self.ind2 == other.ind2
+            && self.subfields.len() == other.subfields.len()
+            && self
+                .subfields
+                .iter()
+                .zip(other.subfields.iter())
+                .all(|(a, b)| a.eq_normalized(b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    fn field(ind2: u8, values: &[&str]) -> DataField {
+        DataField {
+            tag: *b"245",
+            ind1: b'1',
+            ind2,
+            subfields: values
+                .iter()
+                .zip([b'a', b'b'])
+                .map(|(value, code)| Subfield::new(code, *value))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_eq_normalized() {
+        let nfc = field(b'0', &["Caf\u{e9} :", "r\u{e9}sum\u{e9}"]);
+        let nfd =
+            field(b'0', &["Cafe\u{301} :", "re\u{301}sume\u{301}"]);
+        assert_ne!(nfc, nfd);
+        assert!(nfc.eq_normalized(&nfd));
+        assert!(nfd.eq_normalized(&nfc));
+
+        assert!(!nfc.eq_normalized(&field(b'4', &["Caf\u{e9} :"])));
+        assert!(!nfc.eq_normalized(&field(b'0', &["Caf\u{e9} :"])));
+        assert!(!nfc.eq_normalized(&field(b'0', &["Cafe :", "resume"])));
+
+        let subfield = Subfield::new(b'a', vec![0xff]);
+        assert!(
+            subfield.eq_normalized(&Subfield::new(b'a', "\u{fffd}"))
+        );
+        assert!(
+            !subfield.eq_normalized(&Subfield::new(b'b', vec![0xff]))
+        );
+    }
+}
@@ -0,0 +1,656 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{Directory, DirectoryEntry, Field, Leader, Record};
+
+/// A structural spec violation found by [Record::validate] or
+/// [Directory::validate].
+///
+/// Each issue names the tag of the offending field and its position,
+/// i.e. the index of the field in directory order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationIssue {
+    InvalidIndicator {
+        tag: [u8; 3],
+        position: usize,
+        indicator: u8,
+    },
+    InvalidSubfieldCode {
+        tag: [u8; 3],
+        position: usize,
+        code: u8,
+    },
+    InvalidControlFieldTag {
+        tag: [u8; 3],
+        position: usize,
+    },
+    InvalidDataFieldTag {
+        tag: [u8; 3],
+        position: usize,
+    },
+    OverlappingField {
+        tag: [u8; 3],
+        position: usize,
+    },
+    UnorderedField {
+        tag: [u8; 3],
+        position: usize,
+    },
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidIndicator {
+                tag,
+                position,
+                indicator,
+            } => write!(
+                f,
+                "invalid indicator {indicator:#04x} in field {} at \
+                 position {position}",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::InvalidSubfieldCode {
+                tag,
+                position,
+                code,
+            } => write!(
+                f,
+                "invalid subfield code {code:#04x} in field {} at \
+                 position {position}",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::InvalidControlFieldTag { tag, position } => write!(
+                f,
+                "invalid control field tag {} at position {position}",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::InvalidDataFieldTag { tag, position } => write!(
+                f,
+                "invalid data field tag {} at position {position}",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::OverlappingField { tag, position } => write!(
+                f,
+                "field {} at position {position} overlaps a preceding \
+                 field",
+                String::from_utf8_lossy(tag)
+            ),
+            Self::UnorderedField { tag, position } => write!(
+                f,
+                "field {} at position {position} is out of tag order",
+                String::from_utf8_lossy(tag)
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ValidationIssue {}
+
+/// A leader position which deviates from the constant value required
+/// by MARC 21, found by [Leader::validate_positions].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaderIssue {
+    /// The position within the leader.
+    pub position: usize,
+    /// The value required by MARC 21.
+    pub expected: u8,
+    /// The actual value.
+    pub found: u8,
+}
+
+impl fmt::Display for LeaderIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "leader position {:02} is {:?}, expected {:?}",
+            self.position, self.found as char, self.expected as char
+        )
+    }
+}
+
+impl core::error::Error for LeaderIssue {}
+
+/// The leader positions with constant values in MARC 21: the
+/// indicator count, the subfield code count and the entry map.
+const LEADER_CONSTANTS: [(usize, u8); 6] = [
+    (10, b'2'),
+    (11, b'2'),
+    (20, b'4'),
+    (21, b'5'),
+    (22, b'0'),
+    (23, b'0'),
+];
+
+/// Returns `true` if the indicator is a blank or an ASCII
+/// alphanumeric character.
+#[inline]
+pub(crate) fn is_valid_indicator(indicator: u8) -> bool {
+    indicator == b' ' || indicator.is_ascii_alphanumeric()
+}
+
+/// Returns `true` if the subfield code is a lowercase letter or a
+/// digit.
+#[inline]
+pub(crate) fn is_valid_subfield_code(code: u8) -> bool {
+    code.is_ascii_lowercase() || code.is_ascii_digit()
+}
+
+/// Returns `true` if the tag is a control field tag (`001`-`009`).
+#[inline]
+fn is_valid_control_field_tag(tag: &[u8; 3]) -> bool {
+    matches!(tag, [b'0', b'0', b'1'..=b'9'])
+}
+
+/// Returns `true` if the tag is a data field tag (`010`-`999`).
+#[inline]
+pub(crate) fn is_valid_data_field_tag(tag: &[u8; 3]) -> bool {
+    tag.iter().all(u8::is_ascii_digit) && !tag.starts_with(b"00")
+}
+
+/// Reports every directory entry whose byte range overlaps the range
+/// of a preceding entry.
+fn check_overlaps(
+    entries: &[DirectoryEntry],
+    issues: &mut Vec<ValidationIssue>,
+) {
+    for (position, entry) in entries.iter().enumerate() {
+        let (start, end) = (entry.start, entry.start + entry.length);
+
+        let overlaps = entries[..position].iter().any(|other| {
+            start < other.start + other.length && other.start < end
+        });
+
+        if overlaps {
+            issues.push(ValidationIssue::OverlappingField {
+                tag: entry.tag,
+                position,
+            });
+        }
+    }
+}
+
+/// Reports every tag which is less than the tag of the preceding
+/// field, such that the tags aren't in ascending order.
+fn check_tag_order<I>(tags: I, issues: &mut Vec<ValidationIssue>)
+where
+    I: IntoIterator<Item = [u8; 3]>,
+{
+    let mut prev = None;
+
+    for (position, tag) in tags.into_iter().enumerate() {
+        if prev.is_some_and(|prev| tag < prev) {
+            issues.push(ValidationIssue::UnorderedField {
+                tag,
+                position,
+            });
+        }
+
+        prev = Some(tag);
+    }
+}
+
+impl Record {
+    /// Checks the record for structural spec violations and returns
+    /// all issues found.
+    ///
+    /// The checks cover the tags of control fields (`001`-`009`) and
+    /// data fields (`010`-`999`), the indicators (blank or ASCII
+    /// alphanumeric) and the subfield codes (lowercase letter or
+    /// digit). For a parsed record which hasn't been modified,
+    /// directory entries overlapping a preceding entry are reported
+    /// as well. Finally, fields whose tag is less than the tag of
+    /// the preceding field in directory order are reported, since
+    /// the directory must be in ascending tag order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{RecordBuilder, Subfield, ValidationIssue};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let record = RecordBuilder::new()
+    ///         .control_field(b"001", "123456789")
+    ///         .data_field(
+    ///             b"245",
+    ///             b'0',
+    ///             b'#',
+    ///             [Subfield::new(b'a', "Title")],
+    ///         )
+    ///         .build();
+    ///
+    ///     assert_eq!(
+    ///         record.validate(),
+    ///         vec![ValidationIssue::InvalidIndicator {
+    ///             tag: *b"245",
+    ///             position: 1,
+    ///             indicator: b'#',
+    ///         }]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = vec![];
+
+        for (position, field) in self.all_fields().enumerate() {
+            let field = match field {
+                Field::Control(field) => {
+                    if !is_valid_control_field_tag(&field.tag) {
+                        issues.push(
+                            ValidationIssue::InvalidControlFieldTag {
+                                tag: field.tag,
+                                position,
+                            },
+                        );
+                    }
+                    continue;
+                }
+                Field::Data(field) => field,
+            };
+
+            let tag = field.tag;
+            if !is_valid_data_field_tag(&tag) {
+                issues.push(ValidationIssue::InvalidDataFieldTag {
+                    tag,
+                    position,
+                });
+            }
+
+            for indicator in [field.ind1, field.ind2] {
+                if !is_valid_indicator(indicator) {
+                    issues.push(ValidationIssue::InvalidIndicator {
+                        tag,
+                        position,
+                        indicator,
+                    });
+                }
+            }
+
+            for subfield in field.subfields.iter() {
+                if !is_valid_subfield_code(subfield.code) {
+                    issues.push(ValidationIssue::InvalidSubfieldCode {
+                        tag,
+                        position,
+                        code: subfield.code,
+                    });
+                }
+            }
+        }
+
+        if let Some(ref entries) = self.directory.0 {
+            check_overlaps(entries, &mut issues);
+        }
+
+        let tags = self.all_fields().map(|field| *field.tag());
+        check_tag_order(tags, &mut issues);
+
+        issues
+    }
+}
+
+impl Leader {
+    /// Checks the positions whose values are constant in MARC 21,
+    /// i.e. the indicator count and the subfield code count (`2`) and
+    /// the entry map (`4500`), and reports every deviation.
+    ///
+    /// Such leaders are accepted by the parsers as long as the counts
+    /// and the entry map are numeric, which lets importers flag them
+    /// while still processing the records.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Leader, LeaderIssue};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+    ///     assert!(leader.validate_positions().is_empty());
+    ///
+    ///     let leader = Leader::from_bytes(b"00827nam a3200241 c 4500")?;
+    ///     assert_eq!(
+    ///         leader.validate_positions(),
+    ///         vec![LeaderIssue {
+    ///             position: 10,
+    ///             expected: b'2',
+    ///             found: b'3',
+    ///         }]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn validate_positions(&self) -> Vec<LeaderIssue> {
+        LEADER_CONSTANTS
+            .iter()
+            .filter(|(position, expected)| {
+                self.raw[*position] != *expected
+            })
+            .map(|(position, expected)| LeaderIssue {
+                position: *position,
+                expected: *expected,
+                found: self.raw[*position],
+            })
+            .collect()
+    }
+}
+
+impl Directory {
+    /// Checks the directory for entries whose byte ranges overlap the
+    /// range of a preceding entry, and for entries which aren't in
+    /// ascending tag order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use marc21::{Directory, Leader, ValidationIssue};
+    ///
+    /// # fn main() { example().unwrap(); }
+    /// fn example() -> anyhow::Result<()> {
+    ///     let leader = Leader::from_bytes(b"00000nam a2200049 c 4500")?;
+    ///     let directory = Directory::from_bytes(
+    ///         b"001001000000245001000005\x1e",
+    ///         &leader,
+    ///     )?;
+    ///
+    ///     assert_eq!(
+    ///         directory.validate(),
+    ///         vec![ValidationIssue::OverlappingField {
+    ///             tag: *b"245",
+    ///             position: 1,
+    ///         }]
+    ///     );
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = vec![];
+        check_overlaps(&self.entries, &mut issues);
+        check_tag_order(
+            self.entries.iter().map(|entry| entry.tag),
+            &mut issues,
+        );
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ControlField, Leader, RecordBuilder, Subfield};
+
+    const DATA: &[u8] = include_bytes!("../tests/data/sandburg.mrc");
+
+    #[test]
+    fn test_validate() -> anyhow::Result<()> {
+        assert!(Record::from_bytes(DATA)?.validate().is_empty());
+        assert!(RecordBuilder::new().build().validate().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_indicators() {
+        let record = RecordBuilder::new()
+            .data_field(b"245", b'\x01', b'A', [])
+            .data_field(b"650", b' ', b'-', [])
+            .build();
+
+        assert_eq!(
+            record.validate(),
+            vec![
+                ValidationIssue::InvalidIndicator {
+                    tag: *b"245",
+                    position: 0,
+                    indicator: 0x01
+                },
+                ValidationIssue::InvalidIndicator {
+                    tag: *b"650",
+                    position: 1,
+                    indicator: b'-'
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_subfield_codes() {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(
+                b"245",
+                b'0',
+                b'0',
+                [
+                    Subfield::new(b'a', "Title"),
+                    Subfield::new(b'A', "Title"),
+                    Subfield::new(b'6', "Linkage"),
+                    Subfield::new(b'$', "Title"),
+                ],
+            )
+            .build();
+
+        assert_eq!(
+            record.validate(),
+            vec![
+                ValidationIssue::InvalidSubfieldCode {
+                    tag: *b"245",
+                    position: 1,
+                    code: b'A'
+                },
+                ValidationIssue::InvalidSubfieldCode {
+                    tag: *b"245",
+                    position: 1,
+                    code: b'$'
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_tags() {
+        let mut record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .control_field(b"000", "0")
+            .data_field(b"245", b'0', b'0', [])
+            .data_field(b"009", b'0', b'0', [])
+            .data_field(b"24A", b'0', b'0', [])
+            .build();
+        record.control_fields.push(ControlField::new(b"245", "x"));
+
+        assert_eq!(
+            record.validate(),
+            vec![
+                ValidationIssue::InvalidControlFieldTag {
+                    tag: *b"000",
+                    position: 1
+                },
+                ValidationIssue::InvalidControlFieldTag {
+                    tag: *b"245",
+                    position: 2
+                },
+                ValidationIssue::InvalidDataFieldTag {
+                    tag: *b"009",
+                    position: 4
+                },
+                ValidationIssue::InvalidDataFieldTag {
+                    tag: *b"24A",
+                    position: 5
+                },
+                ValidationIssue::UnorderedField {
+                    tag: *b"000",
+                    position: 1
+                },
+                ValidationIssue::UnorderedField {
+                    tag: *b"009",
+                    position: 4
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_directory() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(DATA)?;
+        let directory = Directory::from_bytes(&DATA[24..], &leader)?;
+        assert!(directory.validate().is_empty());
+
+        let leader = Leader::from_bytes(b"00000nam a2200061 c 4500")?;
+        let directory = Directory::from_bytes(
+            b"001001000000245001000010650001000005\x1e",
+            &leader,
+        )?;
+        assert_eq!(
+            directory.validate(),
+            vec![ValidationIssue::OverlappingField {
+                tag: *b"650",
+                position: 2
+            }]
+        );
+
+        let directory = Directory::from_bytes(
+            b"001001000000245001000000650000000010\x1e",
+            &leader,
+        )?;
+        assert_eq!(
+            directory.validate(),
+            vec![ValidationIssue::OverlappingField {
+                tag: *b"245",
+                position: 1
+            }]
+        );
+
+        // The directory of a parsed record is checked as well, until
+        // the record is modified.
+        let mut record = Record::from_bytes(
+            b"00060nam a2200049 c 4500001001000000003000500005\x1e\
+            123456789\x1e\x1d",
+        )?;
+        let field = record.get(b"003").unwrap();
+        assert_eq!(field.as_control_field().unwrap().data(), b"6789");
+        assert_eq!(
+            record.validate(),
+            vec![ValidationIssue::OverlappingField {
+                tag: *b"003",
+                position: 1
+            }]
+        );
+
+        record.remove_fields(b"5XX");
+        assert!(record.validate().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_tag_order() -> anyhow::Result<()> {
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123456789")
+            .data_field(b"650", b' ', b'0', [])
+            .data_field(b"245", b'0', b'0', [])
+            .data_field(b"650", b' ', b'0', [])
+            .data_field(b"650", b' ', b'0', [])
+            .build();
+
+        assert_eq!(
+            record.validate(),
+            vec![ValidationIssue::UnorderedField {
+                tag: *b"245",
+                position: 2
+            }]
+        );
+
+        // An out-of-order directory with disjoint byte ranges.
+        let leader = Leader::from_bytes(b"00000nam a2200061 c 4500")?;
+        let directory = Directory::from_bytes(
+            b"001001000000650001000010245001000020\x1e",
+            &leader,
+        )?;
+        assert_eq!(
+            directory.validate(),
+            vec![ValidationIssue::UnorderedField {
+                tag: *b"245",
+                position: 2
+            }]
+        );
+
+        // An ordered directory with overlapping byte ranges.
+        let directory = Directory::from_bytes(
+            b"001001000000245001000005650001000020\x1e",
+            &leader,
+        )?;
+        assert_eq!(
+            directory.validate(),
+            vec![ValidationIssue::OverlappingField {
+                tag: *b"245",
+                position: 1
+            }]
+        );
+
+        // A data field preceding a control field in the directory of a
+        // parsed record.
+        let mut record = Record::from_bytes(
+            b"00067nam a2200049 c 4500245001000000008000700010\x1e\
+            00\x1faTitle\x1e740101\x1e\x1d",
+        )?;
+        assert_eq!(
+            record.validate(),
+            vec![ValidationIssue::UnorderedField {
+                tag: *b"008",
+                position: 1
+            }]
+        );
+
+        record.sort_fields();
+        assert!(record.validate().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_positions() -> anyhow::Result<()> {
+        let leader = Leader::from_bytes(b"00827nam a2200241 c 4500")?;
+        assert!(leader.validate_positions().is_empty());
+
+        let leader = Leader::from_bytes(b"00827nam a2200241 c 4501")?;
+        assert_eq!(
+            leader.validate_positions(),
+            vec![LeaderIssue {
+                position: 23,
+                expected: b'0',
+                found: b'1',
+            }]
+        );
+
+        let leader = Leader::from_bytes(b"00827nam a3300241 c 3600")?;
+        let positions: Vec<_> = leader
+            .validate_positions()
+            .iter()
+            .map(|issue| issue.position)
+            .collect();
+        assert_eq!(positions, [10, 11, 20, 21]);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_leader_issue_display() {
+        let issue = LeaderIssue {
+            position: 23,
+            expected: b'0',
+            found: b'1',
+        };
+        assert_eq!(
+            issue.to_string(),
+            "leader position 23 is '1', expected '0'"
+        );
+    }
+}
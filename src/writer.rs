@@ -0,0 +1,129 @@
+use std::io::{self, Write};
+
+use crate::Record;
+
+/// A writer that streams records in their ISO 2709 representation
+/// into an underlying [Write], the counterpart of
+/// [MarcReader](crate::MarcReader).
+///
+/// Each record is serialized with [Record::to_bytes], i.e. the record
+/// length, the base address and the directory are recomputed, and the
+/// underlying writer is flushed after every record. The memory usage
+/// doesn't grow with the number of records.
+///
+/// # Example
+///
+/// ```rust
+/// use marc21::{MarcWriter, Record};
+///
+/// # fn main() { example().unwrap(); }
+/// fn example() -> anyhow::Result<()> {
+///     let data = b"00048nam a2200037 c 4500001001000000\x1e\
+///         123456789\x1e\x1d";
+///     let record = Record::from_bytes(data)?;
+///
+///     let mut writer = MarcWriter::new(Vec::new());
+///     writer.write_record(&record)?;
+///     assert_eq!(writer.into_inner(), data);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct MarcWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> MarcWriter<W> {
+    /// Creates a new writer over the given [Write].
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Writes the ISO 2709 representation of the record and flushes
+    /// the underlying writer.
+    ///
+    /// A record which can't be serialized (see [Record::try_to_bytes])
+    /// isn't written and fails with [io::ErrorKind::InvalidData].
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        let data = record.try_to_bytes().map_err(|e| {
+            io::Error::new(io::ErrorKind::InvalidData, e)
+        })?;
+        self.inner.write_all(&data)?;
+        self.inner.flush()
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Unwraps the writer, returning the underlying [Write].
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::{DataField, MarcReader, RecordBuilder};
+
+    const DATA: &[u8] = include_bytes!("../tests/data/two_records.mrc");
+
+    #[test]
+    fn test_marc_writer() -> anyhow::Result<()> {
+        let mut writer = MarcWriter::new(Vec::new());
+        for record in MarcReader::new(Cursor::new(DATA)) {
+            writer.write_record(&record?)?;
+        }
+        assert_eq!(writer.get_ref().as_slice(), DATA);
+
+        let records = MarcReader::new(Cursor::new(writer.into_inner()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let expected = MarcReader::new(Cursor::new(DATA))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(records, expected);
+
+        // Modified records are written with a recomputed leader and
+        // directory.
+        let mut writer = MarcWriter::new(Vec::new());
+        for record in MarcReader::new(Cursor::new(DATA)) {
+            let mut record = record?;
+            record.add_field(
+                DataField::new(b"500", b' ', b' ')?
+                    .with_subfield(b'a', "Note")?,
+            );
+            writer.write_record(&record)?;
+        }
+
+        let mut reader =
+            MarcReader::new(Cursor::new(writer.into_inner()));
+        let record = reader.next().unwrap()?;
+        assert_eq!(record.control_number(), Some(&b"123456789"[..]));
+        assert_eq!(record.fields(b"500").count(), 1);
+        assert_eq!(record.leader().record_length(), 69);
+        assert_eq!(reader.next().unwrap()?.fields(b"500").count(), 1);
+        assert!(reader.next().is_none());
+
+        let expected = RecordBuilder::new().build();
+        let mut writer = MarcWriter::new(Vec::new());
+        writer.write_record(&expected)?;
+        let mut reader =
+            MarcReader::new(Cursor::new(writer.into_inner()));
+        assert_eq!(reader.next().unwrap()?, expected);
+
+        // Records which can't be serialized aren't written.
+        let record = RecordBuilder::new()
+            .control_field(b"001", "123\x1e456")
+            .build();
+        let mut writer = MarcWriter::new(Vec::new());
+        let err = writer.write_record(&record).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(writer.get_ref().is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use marc21::{
+    BibliographicLevel, CharacterCodingScheme, ParseOptions, Record,
+    RecordStatus, TypeOfRecord,
+};
+
+const DATA: &[u8] = include_bytes!("data/arithmetic.mrc");
+
+#[test]
+fn test_record_from_bytes() -> anyhow::Result<()> {
+    let record = Record::from_bytes(DATA)?;
+
+    let leader = record.leader();
+    assert_eq!(leader.record_length(), 599);
+    assert_eq!(leader.record_status(), RecordStatus::Corrected);
+    assert_eq!(leader.type_of_record(), TypeOfRecord::LanguageMaterial);
+    assert_eq!(
+        leader.bibliographic_level(),
+        BibliographicLevel::Monograph
+    );
+    assert_eq!(
+        leader.character_coding_scheme(),
+        CharacterCodingScheme::Utf8
+    );
+    assert_eq!(leader.base_address_of_data(), 181);
+
+    assert_eq!(record.control_number(), Some(&b"12883376"[..]));
+
+    Ok(())
+}
+
+#[test]
+fn test_record_to_bytes() -> anyhow::Result<()> {
+    let record = Record::from_bytes(DATA)?;
+    assert_eq!(record.to_bytes(), DATA);
+
+    Ok(())
+}
+
+#[test]
+fn test_record_preserve_layout() -> anyhow::Result<()> {
+    let options = ParseOptions {
+        preserve_layout: true,
+        ..Default::default()
+    };
+
+    let (record, _) = Record::from_bytes_with(DATA, &options)?;
+    assert_eq!(record.to_bytes(), DATA);
+
+    // Swap the directory entries of the `001` and `005` fields.
+    let data = [&DATA[..24], &DATA[36..48], &DATA[24..36], &DATA[48..]]
+        .concat();
+
+    let record = Record::from_bytes(&data)?;
+    assert_ne!(record.to_bytes(), data);
+
+    let (mut record, _) = Record::from_bytes_with(&data, &options)?;
+    assert_eq!(record.to_bytes(), data);
+    assert_eq!(record, Record::from_bytes(&data)?);
+
+    record.remove_fields(b"005");
+    assert_eq!(record.to_bytes().len(), data.len() - 29);
+
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_record_to_marcxml() -> anyhow::Result<()> {
+    let record = Record::from_bytes(DATA)?;
+    assert_eq!(
+        record.to_marcxml(),
+        include_str!("data/arithmetic.xml")
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_record_to_marc_json() -> anyhow::Result<()> {
+    let record = Record::from_bytes(DATA)?;
+    let expected: serde_json::Value =
+        serde_json::from_str(include_str!("data/arithmetic.json"))?;
+    assert_eq!(record.to_marc_json(), expected);
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use marc21::RecordRef;
+
+/// An allocator that counts the allocations of the current thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const DATA: &[u8] = include_bytes!("data/arithmetic.mrc");
+
+#[test]
+fn test_record_ref_no_allocations() -> anyhow::Result<()> {
+    let before = ALLOCATIONS.with(Cell::get);
+
+    let record = RecordRef::from_bytes(DATA)?;
+    let mut len = 0;
+    for field in record.fields() {
+        len += field.data().len();
+        for (_, value) in field.subfields() {
+            len += value.len();
+        }
+    }
+
+    let control_number = record.control_number();
+    assert_eq!(ALLOCATIONS.with(Cell::get), before);
+
+    assert!(len > 0);
+    assert_eq!(control_number, Some(&b"12883376"[..]));
+    Ok(())
+}